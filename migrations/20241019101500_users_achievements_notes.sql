@@ -0,0 +1,45 @@
+CREATE TABLE IF NOT EXISTS users_achievements_notes (
+    username text NOT NULL,
+    id integer NOT NULL,
+    note text NOT NULL
+);
+
+ALTER TABLE ONLY users_achievements_notes
+    ADD CONSTRAINT users_achievements_notes_pkey PRIMARY KEY (username, id);
+
+ALTER TABLE ONLY users_achievements_notes
+    ADD CONSTRAINT users_achievements_notes_id_fkey FOREIGN KEY (id) REFERENCES achievements (id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY users_achievements_notes
+    ADD CONSTRAINT users_achievements_notes_username_fkey FOREIGN KEY (username) REFERENCES users (username) ON UPDATE CASCADE ON DELETE CASCADE;
+
+CREATE TABLE IF NOT EXISTS zzz_users_achievements_notes (
+    username text NOT NULL,
+    id integer NOT NULL,
+    note text NOT NULL
+);
+
+ALTER TABLE ONLY zzz_users_achievements_notes
+    ADD CONSTRAINT zzz_users_achievements_notes_pkey PRIMARY KEY (username, id);
+
+ALTER TABLE ONLY zzz_users_achievements_notes
+    ADD CONSTRAINT zzz_users_achievements_notes_id_fkey FOREIGN KEY (id) REFERENCES zzz_achievements (id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY zzz_users_achievements_notes
+    ADD CONSTRAINT zzz_users_achievements_notes_username_fkey FOREIGN KEY (username) REFERENCES users (username) ON UPDATE CASCADE ON DELETE CASCADE;
+
+CREATE TABLE IF NOT EXISTS gi_users_achievements_notes (
+    username text NOT NULL,
+    id integer NOT NULL,
+    note text NOT NULL
+);
+
+ALTER TABLE ONLY gi_users_achievements_notes
+    ADD CONSTRAINT gi_users_achievements_notes_pkey PRIMARY KEY (username, id);
+
+ALTER TABLE ONLY gi_users_achievements_notes
+    ADD CONSTRAINT gi_users_achievements_notes_id_fkey FOREIGN KEY (id) REFERENCES gi_achievements (id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY gi_users_achievements_notes
+    ADD CONSTRAINT gi_users_achievements_notes_username_fkey FOREIGN KEY (username) REFERENCES users (username) ON UPDATE CASCADE ON DELETE CASCADE;
+
@@ -0,0 +1,89 @@
+CREATE TABLE IF NOT EXISTS users_achievement_lists (
+    id serial NOT NULL,
+    username text NOT NULL,
+    name text NOT NULL,
+    position integer NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS users_achievement_lists_entries (
+    list integer NOT NULL,
+    id integer NOT NULL,
+    position integer NOT NULL
+);
+
+ALTER TABLE ONLY users_achievement_lists
+    ADD CONSTRAINT users_achievement_lists_pkey PRIMARY KEY (id);
+
+ALTER TABLE ONLY users_achievement_lists
+    ADD CONSTRAINT users_achievement_lists_username_fkey FOREIGN KEY (username) REFERENCES users (username) ON UPDATE CASCADE ON DELETE CASCADE;
+
+ALTER TABLE ONLY users_achievement_lists_entries
+    ADD CONSTRAINT users_achievement_lists_entries_pkey PRIMARY KEY (list, id);
+
+ALTER TABLE ONLY users_achievement_lists_entries
+    ADD CONSTRAINT users_achievement_lists_entries_list_fkey FOREIGN KEY (list) REFERENCES users_achievement_lists (id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY users_achievement_lists_entries
+    ADD CONSTRAINT users_achievement_lists_entries_id_fkey FOREIGN KEY (id) REFERENCES achievements (id) ON DELETE CASCADE;
+
+CREATE INDEX users_achievement_lists_username_index ON users_achievement_lists (username);
+
+CREATE TABLE IF NOT EXISTS zzz_users_achievement_lists (
+    id serial NOT NULL,
+    username text NOT NULL,
+    name text NOT NULL,
+    position integer NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS zzz_users_achievement_lists_entries (
+    list integer NOT NULL,
+    id integer NOT NULL,
+    position integer NOT NULL
+);
+
+ALTER TABLE ONLY zzz_users_achievement_lists
+    ADD CONSTRAINT zzz_users_achievement_lists_pkey PRIMARY KEY (id);
+
+ALTER TABLE ONLY zzz_users_achievement_lists
+    ADD CONSTRAINT zzz_users_achievement_lists_username_fkey FOREIGN KEY (username) REFERENCES users (username) ON UPDATE CASCADE ON DELETE CASCADE;
+
+ALTER TABLE ONLY zzz_users_achievement_lists_entries
+    ADD CONSTRAINT zzz_users_achievement_lists_entries_pkey PRIMARY KEY (list, id);
+
+ALTER TABLE ONLY zzz_users_achievement_lists_entries
+    ADD CONSTRAINT zzz_users_achievement_lists_entries_list_fkey FOREIGN KEY (list) REFERENCES zzz_users_achievement_lists (id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY zzz_users_achievement_lists_entries
+    ADD CONSTRAINT zzz_users_achievement_lists_entries_id_fkey FOREIGN KEY (id) REFERENCES zzz_achievements (id) ON DELETE CASCADE;
+
+CREATE INDEX zzz_users_achievement_lists_username_index ON zzz_users_achievement_lists (username);
+
+CREATE TABLE IF NOT EXISTS gi_users_achievement_lists (
+    id serial NOT NULL,
+    username text NOT NULL,
+    name text NOT NULL,
+    position integer NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS gi_users_achievement_lists_entries (
+    list integer NOT NULL,
+    id integer NOT NULL,
+    position integer NOT NULL
+);
+
+ALTER TABLE ONLY gi_users_achievement_lists
+    ADD CONSTRAINT gi_users_achievement_lists_pkey PRIMARY KEY (id);
+
+ALTER TABLE ONLY gi_users_achievement_lists
+    ADD CONSTRAINT gi_users_achievement_lists_username_fkey FOREIGN KEY (username) REFERENCES users (username) ON UPDATE CASCADE ON DELETE CASCADE;
+
+ALTER TABLE ONLY gi_users_achievement_lists_entries
+    ADD CONSTRAINT gi_users_achievement_lists_entries_pkey PRIMARY KEY (list, id);
+
+ALTER TABLE ONLY gi_users_achievement_lists_entries
+    ADD CONSTRAINT gi_users_achievement_lists_entries_list_fkey FOREIGN KEY (list) REFERENCES gi_users_achievement_lists (id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY gi_users_achievement_lists_entries
+    ADD CONSTRAINT gi_users_achievement_lists_entries_id_fkey FOREIGN KEY (id) REFERENCES gi_achievements (id) ON DELETE CASCADE;
+
+CREATE INDEX gi_users_achievement_lists_username_index ON gi_users_achievement_lists (username);
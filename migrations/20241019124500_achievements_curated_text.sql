@@ -0,0 +1,98 @@
+CREATE TABLE IF NOT EXISTS achievements_curated_text (
+    id integer NOT NULL,
+    language text NOT NULL,
+    comment text,
+    reference text,
+    video text
+);
+
+ALTER TABLE ONLY achievements_curated_text
+    ADD CONSTRAINT achievements_curated_text_pkey PRIMARY KEY (id, LANGUAGE);
+
+ALTER TABLE ONLY achievements_curated_text
+    ADD CONSTRAINT achievements_curated_text_id_fkey FOREIGN KEY (id) REFERENCES achievements (id) ON DELETE CASCADE;
+
+INSERT INTO achievements_curated_text (id, language, comment, reference, video)
+SELECT
+    id,
+    'en',
+    comment,
+    reference,
+    video
+FROM
+    achievements
+WHERE
+    comment IS NOT NULL
+    OR reference IS NOT NULL
+    OR video IS NOT NULL;
+
+ALTER TABLE ONLY achievements
+    DROP COLUMN comment,
+    DROP COLUMN reference,
+    DROP COLUMN video;
+
+CREATE TABLE IF NOT EXISTS zzz_achievements_curated_text (
+    id integer NOT NULL,
+    language text NOT NULL,
+    comment text,
+    reference text,
+    video text
+);
+
+ALTER TABLE ONLY zzz_achievements_curated_text
+    ADD CONSTRAINT zzz_achievements_curated_text_pkey PRIMARY KEY (id, LANGUAGE);
+
+ALTER TABLE ONLY zzz_achievements_curated_text
+    ADD CONSTRAINT zzz_achievements_curated_text_id_fkey FOREIGN KEY (id) REFERENCES zzz_achievements (id) ON DELETE CASCADE;
+
+INSERT INTO zzz_achievements_curated_text (id, language, comment, reference, video)
+SELECT
+    id,
+    'en',
+    comment,
+    reference,
+    video
+FROM
+    zzz_achievements
+WHERE
+    comment IS NOT NULL
+    OR reference IS NOT NULL
+    OR video IS NOT NULL;
+
+ALTER TABLE ONLY zzz_achievements
+    DROP COLUMN comment,
+    DROP COLUMN reference,
+    DROP COLUMN video;
+
+CREATE TABLE IF NOT EXISTS gi_achievements_curated_text (
+    id integer NOT NULL,
+    language text NOT NULL,
+    comment text,
+    reference text,
+    video text
+);
+
+ALTER TABLE ONLY gi_achievements_curated_text
+    ADD CONSTRAINT gi_achievements_curated_text_pkey PRIMARY KEY (id, LANGUAGE);
+
+ALTER TABLE ONLY gi_achievements_curated_text
+    ADD CONSTRAINT gi_achievements_curated_text_id_fkey FOREIGN KEY (id) REFERENCES gi_achievements (id) ON DELETE CASCADE;
+
+INSERT INTO gi_achievements_curated_text (id, language, comment, reference, video)
+SELECT
+    id,
+    'en',
+    comment,
+    reference,
+    video
+FROM
+    gi_achievements
+WHERE
+    comment IS NOT NULL
+    OR reference IS NOT NULL
+    OR video IS NOT NULL;
+
+ALTER TABLE ONLY gi_achievements
+    DROP COLUMN comment,
+    DROP COLUMN reference,
+    DROP COLUMN video;
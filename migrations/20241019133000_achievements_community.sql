@@ -0,0 +1,43 @@
+CREATE TABLE IF NOT EXISTS achievements_difficulty_votes (
+    username text NOT NULL,
+    id integer NOT NULL,
+    difficulty text NOT NULL
+);
+
+ALTER TABLE ONLY achievements_difficulty_votes
+    ADD CONSTRAINT achievements_difficulty_votes_pkey PRIMARY KEY (username, id);
+
+ALTER TABLE ONLY achievements_difficulty_votes
+    ADD CONSTRAINT achievements_difficulty_votes_id_fkey FOREIGN KEY (id) REFERENCES achievements (id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY achievements_difficulty_votes
+    ADD CONSTRAINT achievements_difficulty_votes_username_fkey FOREIGN KEY (username) REFERENCES users (username) ON UPDATE CASCADE ON DELETE CASCADE;
+
+CREATE INDEX achievements_difficulty_votes_id_index ON achievements_difficulty_votes (id);
+
+CREATE TABLE IF NOT EXISTS achievements_feedback (
+    id serial NOT NULL,
+    achievement integer NOT NULL,
+    username text NOT NULL,
+    language text NOT NULL,
+    field text NOT NULL,
+    content text NOT NULL,
+    status text DEFAULT 'pending' NOT NULL,
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    moderated_by text,
+    moderated_at timestamp with time zone
+);
+
+ALTER TABLE ONLY achievements_feedback
+    ADD CONSTRAINT achievements_feedback_pkey PRIMARY KEY (id);
+
+ALTER TABLE ONLY achievements_feedback
+    ADD CONSTRAINT achievements_feedback_achievement_fkey FOREIGN KEY (achievement) REFERENCES achievements (id) ON DELETE CASCADE;
+
+ALTER TABLE ONLY achievements_feedback
+    ADD CONSTRAINT achievements_feedback_username_fkey FOREIGN KEY (username) REFERENCES users (username) ON UPDATE CASCADE ON DELETE CASCADE;
+
+ALTER TABLE ONLY achievements_feedback
+    ADD CONSTRAINT achievements_feedback_moderated_by_fkey FOREIGN KEY (moderated_by) REFERENCES users (username) ON UPDATE CASCADE ON DELETE SET NULL;
+
+CREATE INDEX achievements_feedback_status_index ON achievements_feedback (status);
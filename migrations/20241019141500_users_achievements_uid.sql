@@ -0,0 +1,54 @@
+-- uid 0 is the default profile that isn't bound to any game account
+ALTER TABLE users_achievements_completed
+    ADD COLUMN IF NOT EXISTS uid integer NOT NULL DEFAULT 0;
+
+ALTER TABLE ONLY users_achievements_completed
+    DROP CONSTRAINT users_achievements_completed_pkey;
+
+ALTER TABLE ONLY users_achievements_completed
+    ADD CONSTRAINT users_achievements_completed_pkey PRIMARY KEY (username, uid, id);
+
+ALTER TABLE users_achievements_favorites
+    ADD COLUMN IF NOT EXISTS uid integer NOT NULL DEFAULT 0;
+
+ALTER TABLE ONLY users_achievements_favorites
+    DROP CONSTRAINT users_achievements_favorites_pkey;
+
+ALTER TABLE ONLY users_achievements_favorites
+    ADD CONSTRAINT users_achievements_favorites_pkey PRIMARY KEY (username, uid, id);
+
+ALTER TABLE zzz_users_achievements_completed
+    ADD COLUMN IF NOT EXISTS uid integer NOT NULL DEFAULT 0;
+
+ALTER TABLE ONLY zzz_users_achievements_completed
+    DROP CONSTRAINT zzz_users_achievements_completed_pkey;
+
+ALTER TABLE ONLY zzz_users_achievements_completed
+    ADD CONSTRAINT zzz_users_achievements_completed_pkey PRIMARY KEY (username, uid, id);
+
+ALTER TABLE zzz_users_achievements_favorites
+    ADD COLUMN IF NOT EXISTS uid integer NOT NULL DEFAULT 0;
+
+ALTER TABLE ONLY zzz_users_achievements_favorites
+    DROP CONSTRAINT zzz_users_achievements_favorites_pkey;
+
+ALTER TABLE ONLY zzz_users_achievements_favorites
+    ADD CONSTRAINT zzz_users_achievements_favorites_pkey PRIMARY KEY (username, uid, id);
+
+ALTER TABLE gi_users_achievements_completed
+    ADD COLUMN IF NOT EXISTS uid integer NOT NULL DEFAULT 0;
+
+ALTER TABLE ONLY gi_users_achievements_completed
+    DROP CONSTRAINT gi_users_achievements_completed_pkey;
+
+ALTER TABLE ONLY gi_users_achievements_completed
+    ADD CONSTRAINT gi_users_achievements_completed_pkey PRIMARY KEY (username, uid, id);
+
+ALTER TABLE gi_users_achievements_favorites
+    ADD COLUMN IF NOT EXISTS uid integer NOT NULL DEFAULT 0;
+
+ALTER TABLE ONLY gi_users_achievements_favorites
+    DROP CONSTRAINT gi_users_achievements_favorites_pkey;
+
+ALTER TABLE ONLY gi_users_achievements_favorites
+    ADD CONSTRAINT gi_users_achievements_favorites_pkey PRIMARY KEY (username, uid, id);
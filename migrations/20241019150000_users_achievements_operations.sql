@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS users_achievements_operations (
+    revision bigserial PRIMARY KEY,
+    username text NOT NULL,
+    uid integer NOT NULL DEFAULT 0,
+    id integer NOT NULL,
+    completed boolean NOT NULL,
+    timestamp timestamp with time zone NOT NULL
+);
+
+ALTER TABLE ONLY users_achievements_operations
+    ADD CONSTRAINT users_achievements_operations_username_fkey FOREIGN KEY (username) REFERENCES users (username) ON UPDATE CASCADE ON DELETE CASCADE;
+
+ALTER TABLE ONLY users_achievements_operations
+    ADD CONSTRAINT users_achievements_operations_id_fkey FOREIGN KEY (id) REFERENCES achievements (id) ON DELETE CASCADE;
+
+CREATE INDEX users_achievements_operations_username_uid_id_index ON users_achievements_operations USING btree (username, uid, id);
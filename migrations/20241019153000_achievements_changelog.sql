@@ -0,0 +1,35 @@
+CREATE TABLE IF NOT EXISTS achievements_changelog (
+    commit_hash text NOT NULL,
+    timestamp timestamp with time zone NOT NULL DEFAULT now(),
+    kind text NOT NULL,
+    id integer NOT NULL,
+    change text NOT NULL,
+    old_value text,
+    new_value text
+);
+
+CREATE INDEX achievements_changelog_timestamp_index ON achievements_changelog USING btree (timestamp);
+
+CREATE TABLE IF NOT EXISTS zzz_achievements_changelog (
+    commit_hash text NOT NULL,
+    timestamp timestamp with time zone NOT NULL DEFAULT now(),
+    kind text NOT NULL,
+    id integer NOT NULL,
+    change text NOT NULL,
+    old_value text,
+    new_value text
+);
+
+CREATE INDEX zzz_achievements_changelog_timestamp_index ON zzz_achievements_changelog USING btree (timestamp);
+
+CREATE TABLE IF NOT EXISTS gi_achievements_changelog (
+    commit_hash text NOT NULL,
+    timestamp timestamp with time zone NOT NULL DEFAULT now(),
+    kind text NOT NULL,
+    id integer NOT NULL,
+    change text NOT NULL,
+    old_value text,
+    new_value text
+);
+
+CREATE INDEX gi_achievements_changelog_timestamp_index ON gi_achievements_changelog USING btree (timestamp);
@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS game_versions (
+    game text NOT NULL,
+    version text NOT NULL
+);
+
+ALTER TABLE ONLY game_versions
+    ADD CONSTRAINT game_versions_pkey PRIMARY KEY (game);
@@ -0,0 +1,13 @@
+-- region 'all' holds the global percentage
+CREATE TABLE IF NOT EXISTS achievements_percent_history (
+    id integer NOT NULL,
+    date date NOT NULL,
+    region text NOT NULL,
+    percent double precision NOT NULL
+);
+
+ALTER TABLE ONLY achievements_percent_history
+    ADD CONSTRAINT achievements_percent_history_pkey PRIMARY KEY (id, date, region);
+
+ALTER TABLE ONLY achievements_percent_history
+    ADD CONSTRAINT achievements_percent_history_id_fkey FOREIGN KEY (id) REFERENCES achievements (id) ON DELETE CASCADE;
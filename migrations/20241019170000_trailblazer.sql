@@ -0,0 +1,19 @@
+CREATE TABLE IF NOT EXISTS trailblazer_text (
+    language text NOT NULL,
+    name text NOT NULL
+);
+
+ALTER TABLE ONLY trailblazer_text
+    ADD CONSTRAINT trailblazer_text_pkey PRIMARY KEY (language);
+
+CREATE TABLE IF NOT EXISTS users_trailblazer (
+    username text NOT NULL,
+    gender text NOT NULL,
+    nickname text
+);
+
+ALTER TABLE ONLY users_trailblazer
+    ADD CONSTRAINT users_trailblazer_pkey PRIMARY KEY (username);
+
+ALTER TABLE ONLY users_trailblazer
+    ADD CONSTRAINT users_trailblazer_username_fkey FOREIGN KEY (username) REFERENCES users (username) ON UPDATE CASCADE ON DELETE CASCADE;
@@ -0,0 +1,33 @@
+-- url holds the authkey and is cleared once the job is done
+CREATE TABLE IF NOT EXISTS import_jobs (
+    id serial NOT NULL,
+    game text NOT NULL,
+    uid integer NOT NULL,
+    url text,
+    status text DEFAULT 'pending' NOT NULL,
+    gacha_type text,
+    error text,
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    started_at timestamp with time zone,
+    updated_at timestamp with time zone DEFAULT now() NOT NULL,
+    finished_at timestamp with time zone
+);
+
+ALTER TABLE ONLY import_jobs
+    ADD CONSTRAINT import_jobs_pkey PRIMARY KEY (id);
+
+CREATE INDEX import_jobs_status_index ON import_jobs (status);
+
+CREATE INDEX import_jobs_game_uid_index ON import_jobs (game, uid);
+
+CREATE TABLE IF NOT EXISTS import_jobs_counts (
+    id integer NOT NULL,
+    gacha_type text NOT NULL,
+    count integer NOT NULL
+);
+
+ALTER TABLE ONLY import_jobs_counts
+    ADD CONSTRAINT import_jobs_counts_pkey PRIMARY KEY (id, gacha_type);
+
+ALTER TABLE ONLY import_jobs_counts
+    ADD CONSTRAINT import_jobs_counts_id_fkey FOREIGN KEY (id) REFERENCES import_jobs (id) ON DELETE CASCADE;
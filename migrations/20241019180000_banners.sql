@@ -0,0 +1,42 @@
+-- rate_up_5 decides the 50/50 of pulls made between start_time and end_time
+CREATE TABLE IF NOT EXISTS banners (
+    id integer NOT NULL,
+    gacha_type text NOT NULL,
+    start_time timestamp with time zone NOT NULL,
+    end_time timestamp with time zone NOT NULL,
+    rate_up_5 integer[] NOT NULL,
+    rate_up_4 integer[] NOT NULL
+);
+
+ALTER TABLE ONLY banners
+    ADD CONSTRAINT banners_pkey PRIMARY KEY (id);
+
+CREATE INDEX banners_gacha_type_index ON banners (gacha_type);
+
+CREATE TABLE IF NOT EXISTS zzz_banners (
+    id integer NOT NULL,
+    gacha_type text NOT NULL,
+    start_time timestamp with time zone NOT NULL,
+    end_time timestamp with time zone NOT NULL,
+    rate_up_5 integer[] NOT NULL,
+    rate_up_4 integer[] NOT NULL
+);
+
+ALTER TABLE ONLY zzz_banners
+    ADD CONSTRAINT zzz_banners_pkey PRIMARY KEY (id);
+
+CREATE INDEX zzz_banners_gacha_type_index ON zzz_banners (gacha_type);
+
+CREATE TABLE IF NOT EXISTS gi_banners (
+    id integer NOT NULL,
+    gacha_type text NOT NULL,
+    start_time timestamp with time zone NOT NULL,
+    end_time timestamp with time zone NOT NULL,
+    rate_up_5 integer[] NOT NULL,
+    rate_up_4 integer[] NOT NULL
+);
+
+ALTER TABLE ONLY gi_banners
+    ADD CONSTRAINT gi_banners_pkey PRIMARY KEY (id);
+
+CREATE INDEX gi_banners_gacha_type_index ON gi_banners (gacha_type);
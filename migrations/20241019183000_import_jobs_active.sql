@@ -0,0 +1,22 @@
+UPDATE
+    import_jobs
+SET
+    status = 'error',
+    error = 'Superseded',
+    finished_at = now()
+WHERE
+    status IN ('pending', 'running', 'calculating')
+    AND id NOT IN (
+        SELECT
+            max(id)
+        FROM
+            import_jobs
+        WHERE
+            status IN ('pending', 'running', 'calculating')
+        GROUP BY
+            game,
+            uid);
+
+CREATE UNIQUE INDEX IF NOT EXISTS import_jobs_game_uid_active_index ON import_jobs USING btree (game, uid)
+WHERE
+    status IN ('pending', 'running', 'calculating');
@@ -1,7 +1,8 @@
 UPDATE
-    achievements
+    achievements_curated_text
 SET
     reference = NULL
 WHERE
-    id = $1;
+    id = $1
+    AND language = $2;
 
@@ -0,0 +1,18 @@
+SELECT
+    achievements.id,
+    achievements.version,
+    achievements_curated_text.comment,
+    achievements_curated_text.reference,
+    achievements.difficulty,
+    achievements_curated_text.video,
+    achievements.gacha,
+    achievements.timegated,
+    achievements.missable,
+    achievements.impossible
+FROM
+    achievements
+    LEFT JOIN achievements_curated_text ON achievements.id = achievements_curated_text.id
+        AND achievements_curated_text.language = $1
+ORDER BY
+    id;
+
@@ -0,0 +1,7 @@
+SELECT
+    id
+FROM
+    achievements
+WHERE
+    id = ANY ($1);
+
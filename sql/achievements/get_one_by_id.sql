@@ -4,10 +4,10 @@ SELECT
     achievements.jades,
     achievements.hidden,
     achievements.version,
-    achievements.comment,
-    achievements.reference,
+    COALESCE(achievements_curated_text.comment, achievements_curated_text_en.comment) comment,
+    COALESCE(achievements_curated_text.reference, achievements_curated_text_en.reference) reference,
     achievements.difficulty,
-    achievements.video,
+    COALESCE(achievements_curated_text.video, achievements_curated_text_en.video) video,
     achievements.gacha,
     achievements.timegated,
     achievements.missable,
@@ -19,6 +19,10 @@ SELECT
     achievement_series_text.name series_name
 FROM
     achievements
+    LEFT JOIN achievements_curated_text ON achievements.id = achievements_curated_text.id
+        AND achievements_curated_text.language = $2
+    LEFT JOIN achievements_curated_text AS achievements_curated_text_en ON achievements.id = achievements_curated_text_en.id
+        AND achievements_curated_text_en.language = 'en'
     JOIN achievements_percent ON achievements.id = achievements_percent.id
     JOIN achievements_text ON achievements.id = achievements_text.id
         AND achievements_text.language = $2
@@ -0,0 +1,14 @@
+SELECT
+    achievements.id,
+    achievements.series,
+    achievements.jades,
+    achievements_text.name,
+    achievements_text.description,
+    achievement_series_text.name series_name
+FROM
+    achievements
+    JOIN achievements_text ON achievements.id = achievements_text.id
+        AND achievements_text.language = 'en'
+    JOIN achievement_series_text ON series = achievement_series_text.id
+        AND achievement_series_text.language = 'en';
+
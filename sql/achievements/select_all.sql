@@ -1,11 +1,23 @@
-INSERT INTO users_achievements_completed (username, id)
-SELECT
-    $1,
-    id
-FROM
-    achievements
-WHERE
-    SET IS NULL AND NOT impossible
-ON CONFLICT (username, id)
-    DO NOTHING;
+WITH inserted AS (
+    INSERT INTO users_achievements_completed (username, id)
+    SELECT
+        $1,
+        id
+    FROM
+        achievements
+    WHERE
+        SET IS NULL AND NOT impossible
+    ON CONFLICT (username, uid, id)
+        DO NOTHING
+    RETURNING
+        username, uid, id)
+    INSERT INTO users_achievements_operations (username, uid, id, completed, timestamp)
+    SELECT
+        username,
+        uid,
+        id,
+        TRUE,
+        now()
+    FROM
+        inserted;
 
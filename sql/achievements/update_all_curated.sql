@@ -0,0 +1,36 @@
+WITH data AS (
+    SELECT
+        *
+    FROM
+        UNNEST($1::integer[], $2::text[], $3::text[], $4::text[], $5::text[], $6::text[], $7::text[], $8::bool[], $9::bool[], $10::bool[], $11::bool[]) AS data (id, language, version, comment, reference, difficulty, video, gacha, timegated, missable, impossible)
+),
+updated AS (
+    UPDATE
+        achievements
+    SET
+        version = data.version,
+        difficulty = data.difficulty,
+        gacha = data.gacha,
+        timegated = data.timegated,
+        missable = data.missable,
+        impossible = data.impossible
+    FROM
+        data
+    WHERE
+        achievements.id = data.id
+        AND data.language = 'en')
+INSERT INTO achievements_curated_text (id, language, comment, reference, video)
+SELECT
+    id,
+    language,
+    comment,
+    reference,
+    video
+FROM
+    data
+ON CONFLICT (id, language)
+    DO UPDATE SET
+        comment = EXCLUDED.comment,
+        reference = EXCLUDED.reference,
+        video = EXCLUDED.video;
+
@@ -1,7 +0,0 @@
-UPDATE
-    achievements
-SET
-    missable = $2
-WHERE
-    id = $1;
-
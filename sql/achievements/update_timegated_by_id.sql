@@ -1,7 +0,0 @@
-UPDATE
-    achievements
-SET
-    timegated = $2
-WHERE
-    id = $1;
-
@@ -0,0 +1,9 @@
+SELECT
+    *
+FROM
+    achievements_changelog
+ORDER BY
+    timestamp DESC,
+    kind DESC,
+    id;
+
@@ -0,0 +1,4 @@
+DELETE FROM achievements_difficulty_votes
+WHERE username = $1
+    AND id = $2;
+
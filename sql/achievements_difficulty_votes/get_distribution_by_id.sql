@@ -0,0 +1,9 @@
+SELECT
+    COUNT(*) FILTER (WHERE difficulty = 'easy') easy,
+    COUNT(*) FILTER (WHERE difficulty = 'medium') medium,
+    COUNT(*) FILTER (WHERE difficulty = 'hard') hard
+FROM
+    achievements_difficulty_votes
+WHERE
+    id = $1;
+
@@ -0,0 +1,6 @@
+INSERT INTO achievements_difficulty_votes (username, id, difficulty)
+    VALUES ($1, $2, $3)
+ON CONFLICT (username, id)
+    DO UPDATE SET
+        difficulty = EXCLUDED.difficulty;
+
@@ -0,0 +1,5 @@
+INSERT INTO achievements_feedback (achievement, username, language, field, content)
+    VALUES ($1, $2, $3, $4, $5)
+RETURNING
+    id;
+
@@ -0,0 +1,8 @@
+SELECT
+    COUNT(*)
+FROM
+    achievements_feedback
+WHERE
+    username = $1
+    AND status = 'pending';
+
@@ -0,0 +1,9 @@
+SELECT
+    *
+FROM
+    achievements_feedback
+WHERE
+    status = $1
+ORDER BY
+    created_at;
+
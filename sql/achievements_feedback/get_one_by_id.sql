@@ -0,0 +1,7 @@
+SELECT
+    *
+FROM
+    achievements_feedback
+WHERE
+    id = $1;
+
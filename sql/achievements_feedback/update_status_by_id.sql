@@ -0,0 +1,10 @@
+UPDATE
+    achievements_feedback
+SET
+    status = $2,
+    moderated_by = $3,
+    moderated_at = now()
+WHERE
+    id = $1
+    AND status = 'pending';
+
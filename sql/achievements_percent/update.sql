@@ -1,35 +1,31 @@
 WITH threshholded_users_achievements AS (
     SELECT
         users_achievements_completed.username,
+        users_achievements_completed.uid,
         id
     FROM
         users_achievements_completed
         JOIN (
             SELECT
-                username
+                username,
+                uid
             FROM
                 users_achievements_completed
             GROUP BY
-                username
+                username,
+                uid
             HAVING
                 count(*) >= $1) threshholded_users ON users_achievements_completed.username = threshholded_users.username
+            AND users_achievements_completed.uid = threshholded_users.uid
 ),
 achievements_percent AS (
     SELECT
         id,
         COUNT(*)::float / (
             SELECT
-                COUNT(*)
+                COUNT(DISTINCT (threshholded_users_achievements.username, threshholded_users_achievements.uid))
             FROM
-                users
-            WHERE
-                EXISTS (
-                    SELECT
-                        *
-                    FROM
-                        threshholded_users_achievements
-                    WHERE
-                        users.username = threshholded_users_achievements.username)) percent
+                threshholded_users_achievements) percent
             FROM
                 threshholded_users_achievements
             GROUP BY
@@ -0,0 +1,11 @@
+SELECT
+    date,
+    region,
+    percent
+FROM
+    achievements_percent_history
+WHERE
+    id = $1
+ORDER BY
+    date;
+
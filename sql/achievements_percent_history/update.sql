@@ -0,0 +1,74 @@
+WITH threshholded_users AS (
+    SELECT
+        username,
+        uid
+    FROM
+        users_achievements_completed
+    GROUP BY
+        username,
+        uid
+    HAVING
+        count(*) >= $1
+),
+users_regions AS (
+    SELECT DISTINCT
+        threshholded_users.username,
+        threshholded_users.uid,
+        mihomo.region
+    FROM
+        threshholded_users
+        JOIN connections ON threshholded_users.username = connections.username
+            AND threshholded_users.uid IN (0, connections.uid)
+        JOIN mihomo ON connections.uid = mihomo.uid
+),
+regions_count AS (
+    SELECT
+        region,
+        count(*) count
+    FROM
+        users_regions
+    GROUP BY
+        region
+),
+achievements_regions_count AS (
+    SELECT
+        id,
+        region,
+        count(*) count
+    FROM
+        users_achievements_completed
+        JOIN users_regions ON users_achievements_completed.username = users_regions.username
+            AND users_achievements_completed.uid = users_regions.uid
+    GROUP BY
+        id,
+        region
+),
+achievements_regions_percent AS (
+    SELECT
+        achievements.id,
+        regions_count.region,
+        COALESCE(achievements_regions_count.count, 0)::float / regions_count.count percent
+    FROM
+        achievements
+    CROSS JOIN regions_count
+    LEFT JOIN achievements_regions_count ON achievements.id = achievements_regions_count.id
+        AND regions_count.region = achievements_regions_count.region
+    UNION ALL
+    SELECT
+        id,
+        'all',
+        percent
+    FROM
+        achievements_percent)
+INSERT INTO achievements_percent_history (id, date, region, percent)
+SELECT
+    id,
+    CURRENT_DATE,
+    region,
+    percent
+FROM
+    achievements_regions_percent
+ON CONFLICT (id, date, region)
+    DO UPDATE SET
+        percent = EXCLUDED.percent;
+
@@ -0,0 +1,3 @@
+DELETE FROM banners
+WHERE id = $1;
+
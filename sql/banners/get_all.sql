@@ -0,0 +1,8 @@
+SELECT
+    *
+FROM
+    banners
+ORDER BY
+    start_time,
+    id;
+
@@ -0,0 +1,7 @@
+SELECT
+    *
+FROM
+    banners
+WHERE
+    id = $1;
+
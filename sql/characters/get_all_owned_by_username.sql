@@ -0,0 +1,39 @@
+SELECT
+    characters.id,
+    characters_text.name,
+    characters.id IN (
+        SELECT
+            character
+        FROM (
+            SELECT
+                uid,
+                character
+            FROM
+                warps_departure
+            UNION ALL
+            SELECT
+                uid,
+                character
+            FROM
+                warps_standard
+            UNION ALL
+            SELECT
+                uid,
+                character
+            FROM
+                warps_special
+            UNION ALL
+            SELECT
+                uid,
+                character
+            FROM
+                warps_lc) pulls
+            JOIN connections ON connections.uid = pulls.uid
+        WHERE
+            connections.username = $1
+            AND character IS NOT NULL) owned
+FROM
+    characters
+    JOIN characters_text ON characters.id = characters_text.id
+        AND characters_text.language = $2;
+
@@ -0,0 +1,3 @@
+DELETE FROM game_versions
+WHERE game = $1;
+
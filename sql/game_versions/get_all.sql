@@ -0,0 +1,7 @@
+SELECT
+    *
+FROM
+    game_versions
+ORDER BY
+    game;
+
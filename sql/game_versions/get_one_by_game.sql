@@ -0,0 +1,7 @@
+SELECT
+    *
+FROM
+    game_versions
+WHERE
+    game = $1;
+
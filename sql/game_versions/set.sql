@@ -0,0 +1,6 @@
+INSERT INTO game_versions (game, version)
+    VALUES ($1, $2)
+ON CONFLICT (game)
+    DO UPDATE SET
+        version = EXCLUDED.version;
+
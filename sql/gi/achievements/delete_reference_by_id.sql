@@ -1,7 +1,8 @@
 UPDATE
-    gi_achievements
+    gi_achievements_curated_text
 SET
     reference = NULL
 WHERE
-    id = $1;
+    id = $1
+    AND language = $2;
 
@@ -4,10 +4,10 @@ SELECT
     gi_achievements.primogems,
     gi_achievements.hidden,
     gi_achievements.version,
-    gi_achievements.comment,
-    gi_achievements.reference,
+    COALESCE(gi_achievements_curated_text.comment, gi_achievements_curated_text_en.comment) comment,
+    COALESCE(gi_achievements_curated_text.reference, gi_achievements_curated_text_en.reference) reference,
     gi_achievements.difficulty,
-    gi_achievements.video,
+    COALESCE(gi_achievements_curated_text.video, gi_achievements_curated_text_en.video) video,
     gi_achievements.gacha,
     gi_achievements.timegated,
     gi_achievements.missable,
@@ -19,6 +19,10 @@ SELECT
     gi_achievement_series_text.name series_name
 FROM
     gi_achievements
+    LEFT JOIN gi_achievements_curated_text ON gi_achievements.id = gi_achievements_curated_text.id
+        AND gi_achievements_curated_text.language = $1
+    LEFT JOIN gi_achievements_curated_text AS gi_achievements_curated_text_en ON gi_achievements.id = gi_achievements_curated_text_en.id
+        AND gi_achievements_curated_text_en.language = 'en'
     JOIN gi_achievements_percent ON gi_achievements.id = gi_achievements_percent.id
     JOIN gi_achievements_text ON gi_achievements.id = gi_achievements_text.id
         AND gi_achievements_text.language = $1
@@ -0,0 +1,7 @@
+SELECT
+    id
+FROM
+    gi_achievements
+WHERE
+    id = ANY ($1);
+
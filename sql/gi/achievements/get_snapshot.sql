@@ -0,0 +1,14 @@
+SELECT
+    gi_achievements.id,
+    gi_achievements.series,
+    gi_achievements.primogems,
+    gi_achievements_text.name,
+    gi_achievements_text.description,
+    gi_achievement_series_text.name series_name
+FROM
+    gi_achievements
+    JOIN gi_achievements_text ON gi_achievements.id = gi_achievements_text.id
+        AND gi_achievements_text.language = 'en'
+    JOIN gi_achievement_series_text ON series = gi_achievement_series_text.id
+        AND gi_achievement_series_text.language = 'en';
+
@@ -1,6 +1,7 @@
-INSERT INTO gi_achievements (id, series, primogems, hidden, priority)
+INSERT INTO gi_achievements (id, series, primogems, hidden, priority, version)
 SELECT
-    *
+    *,
+    $6
 FROM
     UNNEST($1::integer[], $2::integer[], $3::integer[], $4::bool[], $5::integer[])
 ON CONFLICT (id)
@@ -1,7 +1,6 @@
-UPDATE
-    gi_achievements
-SET
-    reference = $2
-WHERE
-    id = $1;
+INSERT INTO gi_achievements_curated_text (id, language, reference)
+    VALUES ($1, $2, $3)
+ON CONFLICT (id, language)
+    DO UPDATE SET
+        reference = EXCLUDED.reference;
 
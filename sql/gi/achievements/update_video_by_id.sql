@@ -1,7 +1,6 @@
-UPDATE
-    gi_achievements
-SET
-    video = $2
-WHERE
-    id = $1;
+INSERT INTO gi_achievements_curated_text (id, language, video)
+    VALUES ($1, $2, $3)
+ON CONFLICT (id, language)
+    DO UPDATE SET
+        video = EXCLUDED.video;
 
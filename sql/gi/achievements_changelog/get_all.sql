@@ -0,0 +1,9 @@
+SELECT
+    *
+FROM
+    gi_achievements_changelog
+ORDER BY
+    timestamp DESC,
+    kind DESC,
+    id;
+
@@ -1,35 +1,31 @@
 WITH threshholded_gi_users_achievements AS (
     SELECT
         gi_users_achievements_completed.username,
+        gi_users_achievements_completed.uid,
         id
     FROM
         gi_users_achievements_completed
         JOIN (
             SELECT
-                username
+                username,
+                uid
             FROM
                 gi_users_achievements_completed
             GROUP BY
-                username
+                username,
+                uid
             HAVING
                 count(*) >= $1) threshholded_users ON gi_users_achievements_completed.username = threshholded_users.username
+            AND gi_users_achievements_completed.uid = threshholded_users.uid
 ),
 gi_achievements_percent AS (
     SELECT
         id,
         COUNT(*)::float / (
             SELECT
-                COUNT(*)
+                COUNT(DISTINCT (threshholded_gi_users_achievements.username, threshholded_gi_users_achievements.uid))
             FROM
-                users
-            WHERE
-                EXISTS (
-                    SELECT
-                        *
-                    FROM
-                        threshholded_gi_users_achievements
-                    WHERE
-                        users.username = threshholded_gi_users_achievements.username)) percent
+                threshholded_gi_users_achievements) percent
             FROM
                 threshholded_gi_users_achievements
             GROUP BY
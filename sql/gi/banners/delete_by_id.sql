@@ -0,0 +1,3 @@
+DELETE FROM gi_banners
+WHERE id = $1;
+
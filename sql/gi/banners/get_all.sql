@@ -0,0 +1,8 @@
+SELECT
+    *
+FROM
+    gi_banners
+ORDER BY
+    start_time,
+    id;
+
@@ -0,0 +1,10 @@
+SELECT
+    *
+FROM
+    gi_banners
+WHERE
+    gacha_type = $1
+ORDER BY
+    start_time,
+    id;
+
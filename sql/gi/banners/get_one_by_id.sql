@@ -0,0 +1,7 @@
+SELECT
+    *
+FROM
+    gi_banners
+WHERE
+    id = $1;
+
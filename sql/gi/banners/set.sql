@@ -0,0 +1,10 @@
+INSERT INTO gi_banners (id, gacha_type, start_time, end_time, rate_up_5, rate_up_4)
+    VALUES ($1, $2, $3, $4, $5, $6)
+ON CONFLICT (id)
+    DO UPDATE SET
+        gacha_type = EXCLUDED.gacha_type,
+        start_time = EXCLUDED.start_time,
+        end_time = EXCLUDED.end_time,
+        rate_up_5 = EXCLUDED.rate_up_5,
+        rate_up_4 = EXCLUDED.rate_up_4;
+
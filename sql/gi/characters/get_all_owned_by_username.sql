@@ -0,0 +1,45 @@
+SELECT
+    gi_characters.id,
+    gi_characters_text.name,
+    gi_characters.id IN (
+        SELECT
+            character
+        FROM (
+            SELECT
+                uid,
+                character
+            FROM
+                gi_wishes_beginner
+            UNION ALL
+            SELECT
+                uid,
+                character
+            FROM
+                gi_wishes_standard
+            UNION ALL
+            SELECT
+                uid,
+                character
+            FROM
+                gi_wishes_character
+            UNION ALL
+            SELECT
+                uid,
+                character
+            FROM
+                gi_wishes_weapon
+            UNION ALL
+            SELECT
+                uid,
+                character
+            FROM
+                gi_wishes_chronicled) pulls
+            JOIN gi_connections ON gi_connections.uid = pulls.uid
+        WHERE
+            gi_connections.username = $1
+            AND character IS NOT NULL) owned
+FROM
+    gi_characters
+    JOIN gi_characters_text ON gi_characters.id = gi_characters_text.id
+        AND gi_characters_text.language = $2;
+
@@ -1,5 +1,5 @@
-INSERT INTO gi_users_achievements_completed (username, id)
-    VALUES ($1, $2)
-ON CONFLICT (username, id)
+INSERT INTO gi_users_achievements_completed (username, uid, id)
+    VALUES ($1, $2, $3)
+ON CONFLICT (username, uid, id)
     DO NOTHING;
 
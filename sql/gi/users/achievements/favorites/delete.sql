@@ -1,4 +1,5 @@
 DELETE FROM gi_users_achievements_favorites
 WHERE username = $1
-    AND id = $2;
+    AND uid = $2
+    AND id = $3;
 
@@ -3,5 +3,6 @@ SELECT
 FROM
     gi_users_achievements_favorites
 WHERE
-    username = $1;
+    username = $1
+    AND uid = $2;
 
@@ -0,0 +1,4 @@
+DELETE FROM gi_users_achievement_lists_entries
+WHERE list = $1
+    AND id = $2;
+
@@ -0,0 +1,3 @@
+DELETE FROM gi_users_achievement_lists_entries
+WHERE list = $1;
+
@@ -0,0 +1,12 @@
+SELECT
+    gi_users_achievement_lists_entries.list,
+    gi_users_achievement_lists_entries.id
+FROM
+    gi_users_achievement_lists_entries
+    JOIN gi_users_achievement_lists ON gi_users_achievement_lists_entries.list = gi_users_achievement_lists.id
+WHERE
+    gi_users_achievement_lists.username = $1
+ORDER BY
+    list,
+    gi_users_achievement_lists_entries.position;
+
@@ -0,0 +1,8 @@
+UPDATE
+    gi_users_achievement_lists
+SET
+    name = $3
+WHERE
+    username = $1
+    AND id = $2;
+
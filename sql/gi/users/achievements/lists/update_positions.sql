@@ -0,0 +1,11 @@
+UPDATE
+    gi_users_achievement_lists
+SET
+    position = data.position
+FROM
+    UNNEST($2::integer[])
+    WITH ORDINALITY AS data (id, position)
+WHERE
+    gi_users_achievement_lists.username = $1
+    AND gi_users_achievement_lists.id = data.id;
+
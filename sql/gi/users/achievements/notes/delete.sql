@@ -0,0 +1,4 @@
+DELETE FROM gi_users_achievements_notes
+WHERE username = $1
+    AND id = $2;
+
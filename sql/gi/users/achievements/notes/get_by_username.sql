@@ -0,0 +1,11 @@
+SELECT
+    *
+FROM
+    gi_users_achievements_notes
+WHERE
+    username = $1
+    AND ($2::text IS NULL
+        OR STRPOS(LOWER(note), LOWER($2)) > 0)
+ORDER BY
+    id;
+
@@ -0,0 +1,8 @@
+SELECT
+    *
+FROM
+    gi_users_achievements_notes
+WHERE
+    username = $1
+    AND id = $2;
+
@@ -0,0 +1,8 @@
+SELECT
+    id
+FROM
+    gi_wishes_beginner
+WHERE
+    uid = $1
+    AND id = ANY ($2);
+
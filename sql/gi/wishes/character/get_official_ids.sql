@@ -0,0 +1,9 @@
+SELECT
+    id
+FROM
+    gi_wishes_character
+WHERE
+    uid = $1
+    AND id = ANY ($2)
+    AND official;
+
@@ -0,0 +1,27 @@
+SELECT
+    uid
+FROM
+    gi_wishes_beginner
+UNION
+SELECT
+    uid
+FROM
+    gi_wishes_standard
+UNION
+SELECT
+    uid
+FROM
+    gi_wishes_character
+UNION
+SELECT
+    uid
+FROM
+    gi_wishes_weapon
+UNION
+SELECT
+    uid
+FROM
+    gi_wishes_chronicled
+ORDER BY
+    uid;
+
@@ -0,0 +1,9 @@
+SELECT
+    id
+FROM
+    gi_wishes_standard
+WHERE
+    uid = $1
+    AND id = ANY ($2)
+    AND official;
+
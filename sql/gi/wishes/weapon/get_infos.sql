@@ -1,7 +1,8 @@
 SELECT
     gi_wishes_weapon.character,
     gi_wishes_weapon.weapon,
-    COALESCE(gi_characters.rarity, gi_weapons.rarity) AS rarity
+    COALESCE(gi_characters.rarity, gi_weapons.rarity) AS rarity,
+    gi_wishes_weapon.timestamp
 FROM
     gi_wishes_weapon
     LEFT JOIN gi_characters ON gi_characters.id = character
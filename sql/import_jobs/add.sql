@@ -0,0 +1,9 @@
+INSERT INTO import_jobs (game, uid, url)
+    VALUES ($1, $2, $3)
+ON CONFLICT (game, uid)
+WHERE
+    status IN ('pending', 'running', 'calculating')
+    DO NOTHING
+RETURNING
+    id;
+
@@ -0,0 +1,24 @@
+UPDATE
+    import_jobs
+SET
+    status = 'running',
+    started_at = now(),
+    updated_at = now()
+WHERE
+    id = (
+        SELECT
+            id
+        FROM
+            import_jobs
+        WHERE
+            status = 'pending'
+            OR (status IN ('running', 'calculating')
+                AND updated_at < now() - INTERVAL '5 minutes')
+        ORDER BY
+            id
+        LIMIT 1
+        FOR UPDATE
+            SKIP LOCKED)
+RETURNING
+    *;
+
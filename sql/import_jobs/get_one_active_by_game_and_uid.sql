@@ -0,0 +1,12 @@
+SELECT
+    *
+FROM
+    import_jobs
+WHERE
+    game = $1
+    AND uid = $2
+    AND status IN ('pending', 'running', 'calculating')
+ORDER BY
+    id DESC
+LIMIT 1;
+
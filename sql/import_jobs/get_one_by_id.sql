@@ -0,0 +1,7 @@
+SELECT
+    *
+FROM
+    import_jobs
+WHERE
+    id = $1;
+
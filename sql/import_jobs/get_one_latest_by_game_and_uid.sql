@@ -0,0 +1,11 @@
+SELECT
+    *
+FROM
+    import_jobs
+WHERE
+    game = $1
+    AND uid = $2
+ORDER BY
+    id DESC
+LIMIT 1;
+
@@ -0,0 +1,11 @@
+UPDATE
+    import_jobs
+SET
+    url = NULL,
+    status = $2,
+    error = $3,
+    updated_at = now(),
+    finished_at = now()
+WHERE
+    id = $1;
+
@@ -0,0 +1,9 @@
+UPDATE
+    import_jobs
+SET
+    status = $2,
+    gacha_type = $3,
+    updated_at = now()
+WHERE
+    id = $1;
+
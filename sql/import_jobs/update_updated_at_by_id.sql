@@ -0,0 +1,8 @@
+UPDATE
+    import_jobs
+SET
+    updated_at = now()
+WHERE
+    id = $1
+    AND status IN ('running', 'calculating');
+
@@ -0,0 +1,13 @@
+WITH import_job AS (
+    UPDATE
+        import_jobs
+    SET
+        updated_at = now()
+    WHERE
+        id = $1)
+INSERT INTO import_jobs_counts (id, gacha_type, count)
+    VALUES ($1, $2, $3)
+ON CONFLICT (id, gacha_type)
+    DO UPDATE SET
+        count = import_jobs_counts.count + EXCLUDED.count;
+
@@ -0,0 +1,10 @@
+SELECT
+    gacha_type,
+    count
+FROM
+    import_jobs_counts
+WHERE
+    id = $1
+ORDER BY
+    gacha_type;
+
@@ -0,0 +1,7 @@
+UPDATE
+    import_jobs_counts
+SET
+    count = 0
+WHERE
+    id = $1;
+
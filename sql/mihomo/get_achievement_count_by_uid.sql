@@ -0,0 +1,7 @@
+SELECT
+    achievement_count
+FROM
+    mihomo
+WHERE
+    uid = $1;
+
@@ -0,0 +1,7 @@
+SELECT
+    name
+FROM
+    trailblazer_text
+WHERE
+    language = $1;
+
@@ -0,0 +1,9 @@
+INSERT INTO trailblazer_text (language, name)
+SELECT
+    *
+FROM
+    UNNEST($1::text[], $2::text[])
+ON CONFLICT (language)
+    DO UPDATE SET
+        name = EXCLUDED.name;
+
@@ -0,0 +1,10 @@
+SELECT
+    count(*)
+FROM
+    users_achievements_completed
+    JOIN achievements ON users_achievements_completed.id = achievements.id
+WHERE
+    username = $1
+    AND uid = $2
+    AND NOT impossible;
+
@@ -0,0 +1,8 @@
+WITH deleted AS (
+    DELETE FROM users_achievements_completed
+    WHERE username = $1
+        AND uid = $2
+        AND id = $3)
+INSERT INTO users_achievements_operations (username, uid, id, completed, timestamp)
+    VALUES ($1, $2, $3, FALSE, $4);
+
@@ -0,0 +1,16 @@
+WITH deleted AS (
+    DELETE FROM users_achievements_completed
+    WHERE username = $1
+        AND uid = $2
+    RETURNING
+        username, uid, id)
+    INSERT INTO users_achievements_operations (username, uid, id, completed, timestamp)
+    SELECT
+        username,
+        uid,
+        id,
+        FALSE,
+        now()
+    FROM
+        deleted;
+
@@ -0,0 +1,8 @@
+WITH inserted AS (
+    INSERT INTO users_achievements_completed (username, uid, id)
+        VALUES ($1, $2, $3)
+    ON CONFLICT (username, uid, id)
+        DO NOTHING)
+INSERT INTO users_achievements_operations (username, uid, id, completed, timestamp)
+    VALUES ($1, $2, $3, TRUE, $4);
+
@@ -0,0 +1,13 @@
+INSERT INTO users_achievement_lists (username, name, position)
+SELECT
+    $1,
+    $2,
+    COALESCE(MAX(position) + 1, 0)
+FROM
+    users_achievement_lists
+WHERE
+    username = $1
+RETURNING
+    id,
+    name;
+
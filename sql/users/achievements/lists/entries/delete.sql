@@ -0,0 +1,4 @@
+DELETE FROM users_achievement_lists_entries
+WHERE list = $1
+    AND id = $2;
+
@@ -0,0 +1,3 @@
+DELETE FROM users_achievement_lists_entries
+WHERE list = $1;
+
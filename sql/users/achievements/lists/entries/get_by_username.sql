@@ -0,0 +1,12 @@
+SELECT
+    users_achievement_lists_entries.list,
+    users_achievement_lists_entries.id
+FROM
+    users_achievement_lists_entries
+    JOIN users_achievement_lists ON users_achievement_lists_entries.list = users_achievement_lists.id
+WHERE
+    users_achievement_lists.username = $1
+ORDER BY
+    list,
+    users_achievement_lists_entries.position;
+
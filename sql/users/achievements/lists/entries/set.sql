@@ -0,0 +1,12 @@
+INSERT INTO users_achievement_lists_entries (list, id, position)
+SELECT
+    $1,
+    $2,
+    COALESCE(MAX(position) + 1, 0)
+FROM
+    users_achievement_lists_entries
+WHERE
+    list = $1
+ON CONFLICT (list, id)
+    DO NOTHING;
+
@@ -0,0 +1,11 @@
+SELECT
+    id,
+    name
+FROM
+    users_achievement_lists
+WHERE
+    username = $1
+ORDER BY
+    position,
+    id;
+
@@ -0,0 +1,11 @@
+UPDATE
+    users_achievement_lists
+SET
+    position = data.position
+FROM
+    UNNEST($2::integer[])
+    WITH ORDINALITY AS data (id, position)
+WHERE
+    users_achievement_lists.username = $1
+    AND users_achievement_lists.id = data.id;
+
@@ -0,0 +1,4 @@
+DELETE FROM users_achievements_notes
+WHERE username = $1
+    AND id = $2;
+
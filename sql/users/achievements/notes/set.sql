@@ -0,0 +1,6 @@
+INSERT INTO users_achievements_notes (username, id, note)
+    VALUES ($1, $2, $3)
+ON CONFLICT (username, id)
+    DO UPDATE SET
+        note = EXCLUDED.note;
+
@@ -0,0 +1,9 @@
+SELECT
+    max(timestamp) max
+FROM
+    users_achievements_operations
+WHERE
+    username = $1
+    AND uid = $2
+    AND id = $3;
+
@@ -0,0 +1,8 @@
+SELECT
+    max(revision) max
+FROM
+    users_achievements_operations
+WHERE
+    username = $1
+    AND uid = $2;
+
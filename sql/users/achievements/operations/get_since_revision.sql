@@ -0,0 +1,15 @@
+SELECT DISTINCT ON (id)
+    id,
+    completed,
+    timestamp
+FROM
+    users_achievements_operations
+WHERE
+    username = $1
+    AND uid = $2
+    AND revision > $3
+    AND revision <= $4
+ORDER BY
+    id,
+    revision DESC;
+
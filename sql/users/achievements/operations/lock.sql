@@ -0,0 +1,3 @@
+SELECT
+    pg_advisory_xact_lock(hashtext($1), $2);
+
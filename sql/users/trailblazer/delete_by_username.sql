@@ -0,0 +1,3 @@
+DELETE FROM users_trailblazer
+WHERE username = $1;
+
@@ -0,0 +1,7 @@
+SELECT
+    *
+FROM
+    users_trailblazer
+WHERE
+    username = $1;
+
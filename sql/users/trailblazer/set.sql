@@ -0,0 +1,7 @@
+INSERT INTO users_trailblazer (username, gender, nickname)
+    VALUES ($1, $2, $3)
+ON CONFLICT (username)
+    DO UPDATE SET
+        gender = EXCLUDED.gender,
+        nickname = EXCLUDED.nickname;
+
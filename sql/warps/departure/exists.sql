@@ -1,9 +0,0 @@
-SELECT
-    id,
-    uid
-FROM
-    warps_departure
-WHERE
-    id = $1
-    AND uid = $2;
-
@@ -0,0 +1,22 @@
+SELECT
+    uid
+FROM
+    warps_departure
+UNION
+SELECT
+    uid
+FROM
+    warps_standard
+UNION
+SELECT
+    uid
+FROM
+    warps_special
+UNION
+SELECT
+    uid
+FROM
+    warps_lc
+ORDER BY
+    uid;
+
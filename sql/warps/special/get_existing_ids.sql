@@ -0,0 +1,8 @@
+SELECT
+    id
+FROM
+    warps_special
+WHERE
+    uid = $1
+    AND id = ANY ($2);
+
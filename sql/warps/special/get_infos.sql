@@ -1,7 +1,8 @@
 SELECT
     warps_special.character,
     warps_special.light_cone,
-    COALESCE(characters.rarity, light_cones.rarity) AS rarity
+    COALESCE(characters.rarity, light_cones.rarity) AS rarity,
+    warps_special.timestamp
 FROM
     warps_special
     LEFT JOIN characters ON characters.id = character
@@ -1,7 +1,8 @@
 SELECT
     warps_standard.character,
     warps_standard.light_cone,
-    COALESCE(characters.rarity, light_cones.rarity) AS rarity
+    COALESCE(characters.rarity, light_cones.rarity) AS rarity,
+    warps_standard.timestamp
 FROM
     warps_standard
     LEFT JOIN characters ON characters.id = character
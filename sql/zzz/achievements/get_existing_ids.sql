@@ -0,0 +1,7 @@
+SELECT
+    id
+FROM
+    zzz_achievements
+WHERE
+    id = ANY ($1);
+
@@ -4,10 +4,10 @@ SELECT
     zzz_achievements.polychromes,
     zzz_achievements.hidden,
     zzz_achievements.version,
-    zzz_achievements.comment,
-    zzz_achievements.reference,
+    COALESCE(zzz_achievements_curated_text.comment, zzz_achievements_curated_text_en.comment) comment,
+    COALESCE(zzz_achievements_curated_text.reference, zzz_achievements_curated_text_en.reference) reference,
     zzz_achievements.difficulty,
-    zzz_achievements.video,
+    COALESCE(zzz_achievements_curated_text.video, zzz_achievements_curated_text_en.video) video,
     zzz_achievements.gacha,
     zzz_achievements.timegated,
     zzz_achievements.missable,
@@ -19,6 +19,10 @@ SELECT
     zzz_achievement_series_text.name series_name
 FROM
     zzz_achievements
+    LEFT JOIN zzz_achievements_curated_text ON zzz_achievements.id = zzz_achievements_curated_text.id
+        AND zzz_achievements_curated_text.language = $2
+    LEFT JOIN zzz_achievements_curated_text AS zzz_achievements_curated_text_en ON zzz_achievements.id = zzz_achievements_curated_text_en.id
+        AND zzz_achievements_curated_text_en.language = 'en'
     JOIN zzz_achievements_percent ON zzz_achievements.id = zzz_achievements_percent.id
     JOIN zzz_achievements_text ON zzz_achievements.id = zzz_achievements_text.id
         AND zzz_achievements_text.language = $2
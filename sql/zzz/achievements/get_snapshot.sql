@@ -0,0 +1,14 @@
+SELECT
+    zzz_achievements.id,
+    zzz_achievements.series,
+    zzz_achievements.polychromes,
+    zzz_achievements_text.name,
+    zzz_achievements_text.description,
+    zzz_achievement_series_text.name series_name
+FROM
+    zzz_achievements
+    JOIN zzz_achievements_text ON zzz_achievements.id = zzz_achievements_text.id
+        AND zzz_achievements_text.language = 'en'
+    JOIN zzz_achievement_series_text ON series = zzz_achievement_series_text.id
+        AND zzz_achievement_series_text.language = 'en';
+
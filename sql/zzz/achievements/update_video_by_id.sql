@@ -1,7 +1,6 @@
-UPDATE
-    zzz_achievements
-SET
-    video = $2
-WHERE
-    id = $1;
+INSERT INTO zzz_achievements_curated_text (id, language, video)
+    VALUES ($1, $2, $3)
+ON CONFLICT (id, language)
+    DO UPDATE SET
+        video = EXCLUDED.video;
 
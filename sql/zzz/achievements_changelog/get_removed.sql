@@ -0,0 +1,16 @@
+SELECT
+    kind,
+    id
+FROM ( SELECT DISTINCT ON (kind, id)
+        kind,
+        id,
+        change
+    FROM
+        zzz_achievements_changelog
+    ORDER BY
+        kind,
+        id,
+        timestamp DESC) latest
+WHERE
+    change = 'removed';
+
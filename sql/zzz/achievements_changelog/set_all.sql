@@ -0,0 +1,7 @@
+INSERT INTO zzz_achievements_changelog (commit_hash, kind, id, change, old_value, new_value)
+SELECT
+    $1,
+    *
+FROM
+    UNNEST($2::text[], $3::integer[], $4::text[], $5::text[], $6::text[]);
+
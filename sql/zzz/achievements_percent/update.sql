@@ -1,35 +1,31 @@
 WITH threshholded_zzz_users_achievements AS (
     SELECT
         zzz_users_achievements_completed.username,
+        zzz_users_achievements_completed.uid,
         id
     FROM
         zzz_users_achievements_completed
         JOIN (
             SELECT
-                username
+                username,
+                uid
             FROM
                 zzz_users_achievements_completed
             GROUP BY
-                username
+                username,
+                uid
             HAVING
                 count(*) >= $1) threshholded_users ON zzz_users_achievements_completed.username = threshholded_users.username
+            AND zzz_users_achievements_completed.uid = threshholded_users.uid
 ),
 zzz_achievements_percent AS (
     SELECT
         id,
         COUNT(*)::float / (
             SELECT
-                COUNT(*)
+                COUNT(DISTINCT (threshholded_zzz_users_achievements.username, threshholded_zzz_users_achievements.uid))
             FROM
-                users
-            WHERE
-                EXISTS (
-                    SELECT
-                        *
-                    FROM
-                        threshholded_zzz_users_achievements
-                    WHERE
-                        users.username = threshholded_zzz_users_achievements.username)) percent
+                threshholded_zzz_users_achievements) percent
             FROM
                 threshholded_zzz_users_achievements
             GROUP BY
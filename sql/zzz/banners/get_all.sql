@@ -0,0 +1,8 @@
+SELECT
+    *
+FROM
+    zzz_banners
+ORDER BY
+    start_time,
+    id;
+
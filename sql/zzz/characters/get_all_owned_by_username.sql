@@ -0,0 +1,33 @@
+SELECT
+    zzz_characters.id,
+    zzz_characters_text.name,
+    zzz_characters.id IN (
+        SELECT
+            character
+        FROM (
+            SELECT
+                uid,
+                character
+            FROM
+                zzz_signals_standard
+            UNION ALL
+            SELECT
+                uid,
+                character
+            FROM
+                zzz_signals_special
+            UNION ALL
+            SELECT
+                uid,
+                character
+            FROM
+                zzz_signals_w_engine) pulls
+            JOIN zzz_connections ON zzz_connections.uid = pulls.uid
+        WHERE
+            zzz_connections.username = $1
+            AND character IS NOT NULL) owned
+FROM
+    zzz_characters
+    JOIN zzz_characters_text ON zzz_characters.id = zzz_characters_text.id
+        AND zzz_characters_text.language = $2;
+
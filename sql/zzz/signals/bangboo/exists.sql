@@ -1,9 +0,0 @@
-SELECT
-    id,
-    uid
-FROM
-    zzz_signals_bangboo
-WHERE
-    id = $1
-    AND uid = $2;
-
@@ -1,7 +1,8 @@
 SELECT
     NULL::integer AS character,
     zzz_signals_bangboo.w_engine,
-    COALESCE(zzz_bangboos.rarity, zzz_w_engines.rarity) AS rarity
+    COALESCE(zzz_bangboos.rarity, zzz_w_engines.rarity) AS rarity,
+    zzz_signals_bangboo.timestamp
 FROM
     zzz_signals_bangboo
     LEFT JOIN zzz_bangboos ON zzz_bangboos.id = bangboo
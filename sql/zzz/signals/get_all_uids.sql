@@ -0,0 +1,22 @@
+SELECT
+    uid
+FROM
+    zzz_signals_standard
+UNION
+SELECT
+    uid
+FROM
+    zzz_signals_special
+UNION
+SELECT
+    uid
+FROM
+    zzz_signals_w_engine
+UNION
+SELECT
+    uid
+FROM
+    zzz_signals_bangboo
+ORDER BY
+    uid;
+
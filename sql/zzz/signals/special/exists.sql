@@ -1,9 +0,0 @@
-SELECT
-    id,
-    uid
-FROM
-    zzz_signals_special
-WHERE
-    id = $1
-    AND uid = $2;
-
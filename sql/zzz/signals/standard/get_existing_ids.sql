@@ -0,0 +1,8 @@
+SELECT
+    id
+FROM
+    zzz_signals_standard
+WHERE
+    uid = $1
+    AND id = ANY ($2);
+
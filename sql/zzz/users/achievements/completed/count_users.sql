@@ -2,11 +2,13 @@ SELECT
     count(*)
 FROM (
     SELECT
-        username
+        username,
+        uid
     FROM
         zzz_users_achievements_completed
     GROUP BY
-        username
+        username,
+        uid
     HAVING
         count(*) >= $1) t;
 
@@ -0,0 +1,25 @@
+WITH counts AS (
+    SELECT
+        username,
+        uid,
+        count(*)
+    FROM
+        zzz_users_achievements_completed
+    GROUP BY
+        username,
+        uid
+    HAVING
+        count(*) >= $1
+)
+SELECT
+    count(*) FILTER (WHERE counts.count < u.count) AS below
+FROM
+    counts u
+    CROSS JOIN counts
+WHERE
+    u.username = $2
+    AND u.uid = $3
+GROUP BY
+    u.username,
+    u.uid;
+
@@ -0,0 +1,4 @@
+DELETE FROM zzz_users_achievement_lists
+WHERE username = $1
+    AND id = $2;
+
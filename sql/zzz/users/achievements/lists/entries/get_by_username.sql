@@ -0,0 +1,12 @@
+SELECT
+    zzz_users_achievement_lists_entries.list,
+    zzz_users_achievement_lists_entries.id
+FROM
+    zzz_users_achievement_lists_entries
+    JOIN zzz_users_achievement_lists ON zzz_users_achievement_lists_entries.list = zzz_users_achievement_lists.id
+WHERE
+    zzz_users_achievement_lists.username = $1
+ORDER BY
+    list,
+    zzz_users_achievement_lists_entries.position;
+
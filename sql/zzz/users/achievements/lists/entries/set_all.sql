@@ -0,0 +1,12 @@
+INSERT INTO zzz_users_achievement_lists_entries (list, id, position)
+SELECT
+    $1,
+    data.id,
+    data.position
+FROM
+    UNNEST($2::integer[])
+    WITH ORDINALITY AS data (id, position)
+ON CONFLICT (list, id)
+    DO UPDATE SET
+        position = EXCLUDED.position;
+
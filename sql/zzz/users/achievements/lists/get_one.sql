@@ -0,0 +1,9 @@
+SELECT
+    id,
+    name
+FROM
+    zzz_users_achievement_lists
+WHERE
+    username = $1
+    AND id = $2;
+
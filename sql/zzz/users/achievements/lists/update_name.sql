@@ -0,0 +1,8 @@
+UPDATE
+    zzz_users_achievement_lists
+SET
+    name = $3
+WHERE
+    username = $1
+    AND id = $2;
+
@@ -0,0 +1,11 @@
+UPDATE
+    zzz_users_achievement_lists
+SET
+    position = data.position
+FROM
+    UNNEST($2::integer[])
+    WITH ORDINALITY AS data (id, position)
+WHERE
+    zzz_users_achievement_lists.username = $1
+    AND zzz_users_achievement_lists.id = data.id;
+
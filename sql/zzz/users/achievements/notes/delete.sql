@@ -0,0 +1,4 @@
+DELETE FROM zzz_users_achievements_notes
+WHERE username = $1
+    AND id = $2;
+
@@ -0,0 +1,5 @@
+SELECT
+    id
+FROM
+    zzz_w_engines;
+
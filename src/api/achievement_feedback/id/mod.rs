@@ -0,0 +1,127 @@
+use actix_session::Session;
+use actix_web::{put, web, HttpResponse, Responder};
+use serde::Deserialize;
+use sqlx::PgPool;
+use utoipa::{OpenApi, ToSchema};
+
+use super::{FeedbackField, FeedbackStatus};
+use crate::{api::ApiResult, database, Language};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "achievement-feedback/{id}")),
+    paths(put_achievement_feedback),
+    components(schemas(FeedbackModeration))
+)]
+struct ApiDoc;
+
+#[derive(Deserialize, ToSchema)]
+struct FeedbackModeration {
+    status: FeedbackStatus,
+    /// Replaces the submitted content when accepting
+    content: Option<String>,
+}
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(put_achievement_feedback);
+}
+
+#[utoipa::path(
+    tag = "achievement-feedback/{id}",
+    put,
+    path = "/api/achievement-feedback/{id}",
+    request_body = FeedbackModeration,
+    responses(
+        (status = 200, description = "Moderated, accepted feedback is written to the curated field"),
+        (status = 400, description = "Not logged in or not pending"),
+        (status = 403, description = "Not an admin"),
+        (status = 404, description = "No such feedback"),
+    )
+)]
+#[put("/api/achievement-feedback/{id}")]
+async fn put_achievement_feedback(
+    session: Session,
+    id: web::Path<i32>,
+    feedback_moderation: web::Json<FeedbackModeration>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    if !database::admins::exists(&username, &pool).await? {
+        return Ok(HttpResponse::Forbidden().finish());
+    }
+
+    let Some(db_feedback) = database::achievements_feedback::get_one_by_id(*id, &pool).await?
+    else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+
+    if db_feedback.status != FeedbackStatus::Pending.to_string()
+        || feedback_moderation.status == FeedbackStatus::Pending
+    {
+        return Ok(HttpResponse::BadRequest().finish());
+    }
+
+    let mut transaction = pool.begin().await?;
+
+    // Someone else moderated it in the meantime
+    if !database::achievements_feedback::update_status_by_id(
+        *id,
+        &feedback_moderation.status.to_string(),
+        &username,
+        &mut transaction,
+    )
+    .await?
+    {
+        return Ok(HttpResponse::BadRequest().finish());
+    }
+
+    if feedback_moderation.status == FeedbackStatus::Accepted {
+        let achievement = db_feedback.achievement;
+        let language: Language = db_feedback.language.parse()?;
+        let content = feedback_moderation
+            .content
+            .as_deref()
+            .unwrap_or(&db_feedback.content);
+
+        match db_feedback.field.parse()? {
+            FeedbackField::Comment => {
+                database::achievements::update_comment_by_id(
+                    achievement,
+                    language,
+                    content,
+                    &mut transaction,
+                )
+                .await?
+            }
+            FeedbackField::Reference => {
+                database::achievements::update_reference_by_id(
+                    achievement,
+                    language,
+                    content,
+                    &mut transaction,
+                )
+                .await?
+            }
+            FeedbackField::Video => {
+                database::achievements::update_video_by_id(
+                    achievement,
+                    language,
+                    content,
+                    &mut transaction,
+                )
+                .await?
+            }
+        }
+    }
+
+    transaction.commit().await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
@@ -0,0 +1,123 @@
+mod id;
+
+use actix_session::Session;
+use actix_web::{get, web, HttpResponse, Responder};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use strum::{Display, EnumString};
+use utoipa::{IntoParams, OpenApi, ToSchema};
+
+use crate::{api::ApiResult, database, Language};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "achievement-feedback")),
+    paths(get_achievement_feedback),
+    components(schemas(Feedback, FeedbackField, FeedbackStatus))
+)]
+struct ApiDoc;
+
+#[derive(Display, EnumString, Serialize, Deserialize, ToSchema, Clone, Copy)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum FeedbackField {
+    Comment,
+    Reference,
+    Video,
+}
+
+#[derive(Display, EnumString, Serialize, Deserialize, ToSchema, Clone, Copy, PartialEq, Eq)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum FeedbackStatus {
+    Pending,
+    Accepted,
+    Rejected,
+}
+
+#[derive(Serialize, ToSchema)]
+struct Feedback {
+    id: i32,
+    achievement: i32,
+    username: String,
+    language: Language,
+    field: FeedbackField,
+    content: String,
+    status: FeedbackStatus,
+    created_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    moderated_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    moderated_at: Option<DateTime<Utc>>,
+}
+
+impl From<database::achievements_feedback::DbAchievementFeedback> for Feedback {
+    fn from(db_feedback: database::achievements_feedback::DbAchievementFeedback) -> Self {
+        Feedback {
+            id: db_feedback.id,
+            achievement: db_feedback.achievement,
+            username: db_feedback.username,
+            language: db_feedback.language.parse().unwrap(),
+            field: db_feedback.field.parse().unwrap(),
+            content: db_feedback.content,
+            status: db_feedback.status.parse().unwrap(),
+            created_at: db_feedback.created_at,
+            moderated_by: db_feedback.moderated_by,
+            moderated_at: db_feedback.moderated_at,
+        }
+    }
+}
+
+#[derive(Deserialize, IntoParams)]
+struct FeedbackParams {
+    status: Option<FeedbackStatus>,
+}
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    let mut openapi = ApiDoc::openapi();
+    openapi.merge(id::openapi());
+    openapi
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_achievement_feedback)
+        .configure(id::configure);
+}
+
+#[utoipa::path(
+    tag = "achievement-feedback",
+    get,
+    path = "/api/achievement-feedback",
+    params(FeedbackParams),
+    responses(
+        (status = 200, description = "[Feedback]", body = Vec<Feedback>),
+        (status = 400, description = "Not logged in"),
+        (status = 403, description = "Not an admin"),
+    )
+)]
+#[get("/api/achievement-feedback")]
+async fn get_achievement_feedback(
+    session: Session,
+    feedback_params: web::Query<FeedbackParams>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    if !database::admins::exists(&username, &pool).await? {
+        return Ok(HttpResponse::Forbidden().finish());
+    }
+
+    let status = feedback_params.status.unwrap_or(FeedbackStatus::Pending);
+
+    let feedback: Vec<_> =
+        database::achievements_feedback::get_by_status(&status.to_string(), &pool)
+            .await?
+            .into_iter()
+            .map(Feedback::from)
+            .collect();
+
+    Ok(HttpResponse::Ok().json(feedback))
+}
@@ -1,9 +1,10 @@
+use actix_session::Session;
 use actix_web::{get, web, HttpResponse, Responder};
 use sqlx::PgPool;
 use utoipa::OpenApi;
 
 use crate::{
-    api::{achievement_series::AchievementSeries, ApiResult, LanguageParams},
+    api::{achievement_series::AchievementSeries, ApiResult, LanguageParams, Trailblazer},
     database,
 };
 
@@ -30,13 +31,18 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
 )]
 #[get("/api/achievement-series/{id}")]
 async fn get_achievement_series(
+    session: Session,
     id: web::Path<i32>,
     language_param: web::Query<LanguageParams>,
     pool: web::Data<PgPool>,
 ) -> ApiResult<impl Responder> {
-    let series = AchievementSeries::from(
+    let mut series = AchievementSeries::from(
         database::achievement_series::get_by_id(*id, language_param.lang, &pool).await?,
     );
 
+    series.name = Trailblazer::new(&session, language_param.lang, &pool)
+        .await?
+        .render(&series.name);
+
     Ok(HttpResponse::Ok().json(series))
 }
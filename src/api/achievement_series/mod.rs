@@ -1,5 +1,6 @@
 mod id;
 
+use actix_session::Session;
 use actix_web::{get, web, HttpResponse, Responder};
 use serde::Serialize;
 use sqlx::PgPool;
@@ -7,7 +8,7 @@ use utoipa::{OpenApi, ToSchema};
 
 use crate::{api::ApiResult, database};
 
-use super::LanguageParams;
+use super::{LanguageParams, Trailblazer};
 
 #[derive(OpenApi)]
 #[openapi(
@@ -56,13 +57,20 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
 )]
 #[get("/api/achievement-series")]
 async fn get_achievement_seriess(
+    session: Session,
     language_param: web::Query<LanguageParams>,
     pool: web::Data<PgPool>,
 ) -> ApiResult<impl Responder> {
+    let trailblazer = Trailblazer::new(&session, language_param.lang, &pool).await?;
+
     let series: Vec<_> = database::achievement_series::get_all(language_param.lang, &pool)
         .await?
         .into_iter()
         .map(AchievementSeries::from)
+        .map(|s| AchievementSeries {
+            name: trailblazer.render(&s.name),
+            ..s
+        })
         .collect();
 
     Ok(HttpResponse::Ok().json(series))
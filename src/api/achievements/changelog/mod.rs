@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use actix_session::Session;
+use actix_web::{get, web, HttpResponse, Responder};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use utoipa::{OpenApi, ToSchema};
+
+use crate::{
+    api::{ApiResult, LanguageParams, Trailblazer},
+    database, ChangelogChange, ChangelogKind,
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "achievements/changelog")),
+    paths(get_achievements_changelog),
+    components(schemas(ChangelogUpdate, Change, ChangelogKind, ChangelogChange))
+)]
+struct ApiDoc;
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_achievements_changelog);
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ChangelogUpdate {
+    commit_hash: String,
+    timestamp: DateTime<Utc>,
+    changes: Vec<Change>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct Change {
+    kind: ChangelogKind,
+    id: i32,
+    /// Current name in the requested language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    change: ChangelogChange,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    old_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_value: Option<String>,
+}
+
+#[derive(Default)]
+pub struct Names {
+    pub achievements: HashMap<i32, String>,
+    pub series: HashMap<i32, String>,
+}
+
+impl Change {
+    pub fn new(
+        kind: &str,
+        id: i32,
+        change: &str,
+        old_value: Option<String>,
+        new_value: Option<String>,
+        names: &Names,
+    ) -> Self {
+        let kind = kind.parse().unwrap();
+
+        let name = match kind {
+            ChangelogKind::Achievement => names.achievements.get(&id),
+            ChangelogKind::Series => names.series.get(&id),
+        }
+        .cloned();
+
+        Change {
+            kind,
+            id,
+            name,
+            change: change.parse().unwrap(),
+            old_value,
+            new_value,
+        }
+    }
+}
+
+/// Expects the changes to be ordered by timestamp.
+pub fn group(changes: Vec<(String, DateTime<Utc>, Change)>) -> Vec<ChangelogUpdate> {
+    let mut updates: Vec<ChangelogUpdate> = Vec::new();
+
+    for (commit_hash, timestamp, change) in changes {
+        match updates.last_mut() {
+            Some(update) if update.commit_hash == commit_hash && update.timestamp == timestamp => {
+                update.changes.push(change)
+            }
+            _ => updates.push(ChangelogUpdate {
+                commit_hash,
+                timestamp,
+                changes: vec![change],
+            }),
+        }
+    }
+
+    updates
+}
+
+#[utoipa::path(
+    tag = "achievements/changelog",
+    get,
+    path = "/api/achievements/changelog",
+    params(LanguageParams),
+    responses(
+        (status = 200, description = "[ChangelogUpdate]", body = Vec<ChangelogUpdate>),
+    )
+)]
+#[get("/api/achievements/changelog")]
+async fn get_achievements_changelog(
+    session: Session,
+    language_params: web::Query<LanguageParams>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let trailblazer = Trailblazer::new(&session, language_params.lang, &pool).await?;
+
+    let mut names = Names::default();
+
+    for db_achievement in database::achievements::get_all(language_params.lang, &pool).await? {
+        names.series.insert(
+            db_achievement.series,
+            trailblazer.render(&db_achievement.series_name),
+        );
+        names
+            .achievements
+            .insert(db_achievement.id, trailblazer.render(&db_achievement.name));
+    }
+
+    let changes = database::achievements_changelog::get_all(&pool)
+        .await?
+        .into_iter()
+        .map(|c| {
+            (
+                c.commit_hash,
+                c.timestamp,
+                Change::new(
+                    &c.kind,
+                    c.id,
+                    &c.change,
+                    c.old_value.map(|v| trailblazer.render(&v)),
+                    c.new_value.map(|v| trailblazer.render(&v)),
+                    &names,
+                ),
+            )
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(group(changes)))
+}
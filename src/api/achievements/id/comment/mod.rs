@@ -4,7 +4,10 @@ use serde::Deserialize;
 use sqlx::PgPool;
 use utoipa::{OpenApi, ToSchema};
 
-use crate::{api::ApiResult, database};
+use crate::{
+    api::{ApiResult, LanguageParams},
+    database,
+};
 
 #[derive(OpenApi)]
 #[openapi(
@@ -32,6 +35,7 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
     tag = "achievements/{id}/comment",
     put,
     path = "/api/achievements/{id}/comment",
+    params(LanguageParams),
     request_body = CommentUpdate,
     responses(
         (status = 200, description = "Updated comment"),
@@ -43,6 +47,7 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
 async fn put_achievement_comment(
     session: Session,
     id: web::Path<i32>,
+    language_params: web::Query<LanguageParams>,
     comment_update: web::Json<CommentUpdate>,
     pool: web::Data<PgPool>,
 ) -> ApiResult<impl Responder> {
@@ -54,7 +59,13 @@ async fn put_achievement_comment(
         return Ok(HttpResponse::Forbidden().finish());
     }
 
-    database::achievements::update_comment_by_id(*id, &comment_update.comment, &pool).await?;
+    database::achievements::update_comment_by_id(
+        *id,
+        language_params.lang,
+        &comment_update.comment,
+        &mut *pool.acquire().await?,
+    )
+    .await?;
 
     Ok(HttpResponse::Ok().finish())
 }
@@ -63,6 +74,7 @@ async fn put_achievement_comment(
     tag = "achievements/{id}/comment",
     delete,
     path = "/api/achievements/{id}/comment",
+    params(LanguageParams),
     responses(
         (status = 200, description = "Deleted comment"),
         (status = 403, description = "Not an admin"),
@@ -73,6 +85,7 @@ async fn put_achievement_comment(
 async fn delete_achievement_comment(
     session: Session,
     id: web::Path<i32>,
+    language_params: web::Query<LanguageParams>,
     pool: web::Data<PgPool>,
 ) -> ApiResult<impl Responder> {
     let Ok(Some(username)) = session.get::<String>("username") else {
@@ -83,7 +96,7 @@ async fn delete_achievement_comment(
         return Ok(HttpResponse::Forbidden().finish());
     }
 
-    database::achievements::delete_comment_by_id(*id, &pool).await?;
+    database::achievements::delete_comment_by_id(*id, language_params.lang, &pool).await?;
 
     Ok(HttpResponse::Ok().finish())
 }
@@ -0,0 +1,96 @@
+use actix_session::Session;
+use actix_web::{delete, put, web, HttpResponse, Responder};
+use serde::Deserialize;
+use sqlx::PgPool;
+use utoipa::{OpenApi, ToSchema};
+
+use crate::{
+    api::{achievements::Difficulty, ApiResult},
+    database,
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "achievements/{id}/difficulty-vote")),
+    paths(put_achievement_difficulty_vote, delete_achievement_difficulty_vote),
+    components(schemas(DifficultyVote))
+)]
+struct ApiDoc;
+
+#[derive(Deserialize, ToSchema)]
+struct DifficultyVote {
+    difficulty: Difficulty,
+}
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(put_achievement_difficulty_vote)
+        .service(delete_achievement_difficulty_vote);
+}
+
+#[utoipa::path(
+    tag = "achievements/{id}/difficulty-vote",
+    put,
+    path = "/api/achievements/{id}/difficulty-vote",
+    request_body = DifficultyVote,
+    responses(
+        (status = 200, description = "Voted"),
+        (status = 400, description = "Not logged in"),
+        (status = 404, description = "No such achievement"),
+    )
+)]
+#[put("/api/achievements/{id}/difficulty-vote")]
+async fn put_achievement_difficulty_vote(
+    session: Session,
+    id: web::Path<i32>,
+    difficulty_vote: web::Json<DifficultyVote>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    if database::achievements::get_existing_ids(&[*id], &pool)
+        .await?
+        .is_empty()
+    {
+        return Ok(HttpResponse::NotFound().finish());
+    }
+
+    let vote = database::achievements_difficulty_votes::DbDifficultyVote {
+        username,
+        id: *id,
+        difficulty: difficulty_vote.difficulty.to_string(),
+    };
+
+    database::achievements_difficulty_votes::set(&vote, &pool).await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[utoipa::path(
+    tag = "achievements/{id}/difficulty-vote",
+    delete,
+    path = "/api/achievements/{id}/difficulty-vote",
+    responses(
+        (status = 200, description = "Deleted vote"),
+        (status = 400, description = "Not logged in"),
+    )
+)]
+#[delete("/api/achievements/{id}/difficulty-vote")]
+async fn delete_achievement_difficulty_vote(
+    session: Session,
+    id: web::Path<i32>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    database::achievements_difficulty_votes::delete(&username, *id, &pool).await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
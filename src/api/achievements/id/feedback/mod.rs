@@ -0,0 +1,92 @@
+use actix_session::Session;
+use actix_web::{post, web, HttpResponse, Responder};
+use serde::Deserialize;
+use sqlx::PgPool;
+use utoipa::{OpenApi, ToSchema};
+
+use crate::{
+    api::{achievement_feedback::FeedbackField, ApiResult},
+    database, Language,
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "achievements/{id}/feedback")),
+    paths(post_achievement_feedback),
+    components(schemas(FeedbackSubmission))
+)]
+struct ApiDoc;
+
+#[derive(Deserialize, ToSchema)]
+struct FeedbackSubmission {
+    field: FeedbackField,
+    content: String,
+    #[serde(default)]
+    language: Language,
+}
+
+const MAX_LENGTH: usize = 2000;
+const MAX_PENDING: i64 = 20;
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(post_achievement_feedback);
+}
+
+#[utoipa::path(
+    tag = "achievements/{id}/feedback",
+    post,
+    path = "/api/achievements/{id}/feedback",
+    request_body = FeedbackSubmission,
+    responses(
+        (status = 200, description = "Feedback id", body = i32),
+        (status = 400, description = "Not logged in or invalid content"),
+        (status = 404, description = "No such achievement"),
+        (status = 429, description = "Too many pending submissions"),
+    )
+)]
+#[post("/api/achievements/{id}/feedback")]
+async fn post_achievement_feedback(
+    session: Session,
+    id: web::Path<i32>,
+    feedback_submission: web::Json<FeedbackSubmission>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    let content = feedback_submission.content.trim();
+
+    if content.is_empty() || content.chars().count() > MAX_LENGTH {
+        return Ok(HttpResponse::BadRequest().finish());
+    }
+
+    if database::achievements::get_existing_ids(&[*id], &pool)
+        .await?
+        .is_empty()
+    {
+        return Ok(HttpResponse::NotFound().finish());
+    }
+
+    if database::achievements_feedback::count_pending_by_username(&username, &pool).await?
+        >= MAX_PENDING
+    {
+        return Ok(HttpResponse::TooManyRequests().finish());
+    }
+
+    let feedback_id = database::achievements_feedback::add(
+        *id,
+        &username,
+        &feedback_submission.language.to_string(),
+        &feedback_submission.field.to_string(),
+        content,
+        &pool,
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(feedback_id))
+}
@@ -1,7 +1,10 @@
 mod comment;
 mod difficulty;
+mod difficulty_vote;
+mod feedback;
 mod gacha;
 mod impossible;
+mod percent_history;
 mod reference;
 mod version;
 mod video;
@@ -14,7 +17,7 @@ use utoipa::OpenApi;
 use crate::{
     api::{
         achievements::{Achievement, LanguageParams},
-        ApiResult,
+        ApiResult, Trailblazer,
     },
     database,
 };
@@ -27,8 +30,11 @@ pub fn openapi() -> utoipa::openapi::OpenApi {
     let mut openapi = ApiDoc::openapi();
     openapi.merge(comment::openapi());
     openapi.merge(difficulty::openapi());
+    openapi.merge(difficulty_vote::openapi());
+    openapi.merge(feedback::openapi());
     openapi.merge(gacha::openapi());
     openapi.merge(impossible::openapi());
+    openapi.merge(percent_history::openapi());
     openapi.merge(reference::openapi());
     openapi.merge(version::openapi());
     openapi.merge(video::openapi());
@@ -38,8 +44,11 @@ pub fn openapi() -> utoipa::openapi::OpenApi {
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.configure(comment::configure)
         .configure(difficulty::configure)
+        .configure(difficulty_vote::configure)
+        .configure(feedback::configure)
         .configure(gacha::configure)
         .configure(impossible::configure)
+        .configure(percent_history::configure)
         .configure(reference::configure)
         .configure(version::configure)
         .configure(video::configure)
@@ -76,11 +85,18 @@ async fn get_achievement(
     }
 
     let mut achievement = Achievement::from(db_achievement);
+    achievement.render(&Trailblazer::new(&session, language_params.lang, &pool).await?);
 
     if let Some(set) = achievement.set {
         achievement.related =
             Some(database::achievements::get_all_related_ids(achievement.id, set, &pool).await?);
     }
 
+    achievement.difficulty_votes = Some(
+        database::achievements_difficulty_votes::get_distribution_by_id(achievement.id, &pool)
+            .await?
+            .into(),
+    );
+
     Ok(HttpResponse::Ok().json(achievement))
 }
@@ -0,0 +1,82 @@
+use std::collections::BTreeMap;
+
+use actix_web::{get, web, HttpResponse, Responder};
+use chrono::NaiveDate;
+use serde::Serialize;
+use sqlx::PgPool;
+use utoipa::{OpenApi, ToSchema};
+
+use crate::{
+    api::{ApiResult, Region},
+    database,
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "achievements/{id}/percent-history")),
+    paths(get_achievement_percent_history),
+    components(schemas(PercentHistory))
+)]
+struct ApiDoc;
+
+#[derive(Default, Serialize, ToSchema)]
+struct PercentHistory {
+    date: NaiveDate,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    percent: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    percent_na: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    percent_eu: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    percent_asia: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    percent_cn: Option<f64>,
+}
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_achievement_percent_history);
+}
+
+#[utoipa::path(
+    tag = "achievements/{id}/percent-history",
+    get,
+    path = "/api/achievements/{id}/percent-history",
+    responses(
+        (status = 200, description = "[PercentHistory], one entry per day", body = Vec<PercentHistory>),
+    )
+)]
+#[get("/api/achievements/{id}/percent-history")]
+async fn get_achievement_percent_history(
+    id: web::Path<i32>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let mut percent_history: BTreeMap<NaiveDate, PercentHistory> = BTreeMap::new();
+
+    for db_percent in database::achievements_percent_history::get_by_id(*id, &pool).await? {
+        let entry = percent_history
+            .entry(db_percent.date)
+            .or_insert_with(|| PercentHistory {
+                date: db_percent.date,
+                ..Default::default()
+            });
+
+        let percent = Some(db_percent.percent);
+
+        match db_percent.region.parse() {
+            Ok(Region::Na) => entry.percent_na = percent,
+            Ok(Region::Eu) => entry.percent_eu = percent,
+            Ok(Region::Asia) => entry.percent_asia = percent,
+            Ok(Region::Cn) => entry.percent_cn = percent,
+            Err(_) => entry.percent = percent,
+        }
+    }
+
+    let percent_history: Vec<_> = percent_history.into_values().collect();
+
+    Ok(HttpResponse::Ok().json(percent_history))
+}
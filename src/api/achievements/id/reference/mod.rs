@@ -4,7 +4,10 @@ use serde::Deserialize;
 use sqlx::PgPool;
 use utoipa::{OpenApi, ToSchema};
 
-use crate::{api::ApiResult, database};
+use crate::{
+    api::{ApiResult, LanguageParams},
+    database,
+};
 
 #[derive(OpenApi)]
 #[openapi(
@@ -32,6 +35,7 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
     tag = "achievements/{id}/reference",
     put,
     path = "/api/achievements/{id}/reference",
+    params(LanguageParams),
     request_body = ReferenceUpdate,
     responses(
         (status = 200, description = "Updated reference"),
@@ -43,6 +47,7 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
 async fn put_achievement_reference(
     session: Session,
     id: web::Path<i32>,
+    language_params: web::Query<LanguageParams>,
     reference_update: web::Json<ReferenceUpdate>,
     pool: web::Data<PgPool>,
 ) -> ApiResult<impl Responder> {
@@ -54,7 +59,13 @@ async fn put_achievement_reference(
         return Ok(HttpResponse::Forbidden().finish());
     }
 
-    database::achievements::update_reference_by_id(*id, &reference_update.reference, &pool).await?;
+    database::achievements::update_reference_by_id(
+        *id,
+        language_params.lang,
+        &reference_update.reference,
+        &mut *pool.acquire().await?,
+    )
+    .await?;
 
     Ok(HttpResponse::Ok().finish())
 }
@@ -63,6 +74,7 @@ async fn put_achievement_reference(
     tag = "achievements/{id}/reference",
     delete,
     path = "/api/achievements/{id}/reference",
+    params(LanguageParams),
     responses(
         (status = 200, description = "Deleted reference"),
         (status = 403, description = "Not an admin"),
@@ -73,6 +85,7 @@ async fn put_achievement_reference(
 async fn delete_achievement_reference(
     session: Session,
     id: web::Path<i32>,
+    language_params: web::Query<LanguageParams>,
     pool: web::Data<PgPool>,
 ) -> ApiResult<impl Responder> {
     let Ok(Some(username)) = session.get::<String>("username") else {
@@ -83,7 +96,7 @@ async fn delete_achievement_reference(
         return Ok(HttpResponse::Forbidden().finish());
     }
 
-    database::achievements::delete_reference_by_id(*id, &pool).await?;
+    database::achievements::delete_reference_by_id(*id, language_params.lang, &pool).await?;
 
     Ok(HttpResponse::Ok().finish())
 }
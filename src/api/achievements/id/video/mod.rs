@@ -4,7 +4,10 @@ use serde::Deserialize;
 use sqlx::PgPool;
 use utoipa::{OpenApi, ToSchema};
 
-use crate::{api::ApiResult, database};
+use crate::{
+    api::{ApiResult, LanguageParams},
+    database,
+};
 
 #[derive(OpenApi)]
 #[openapi(
@@ -32,6 +35,7 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
     tag = "achievements/{id}/video",
     put,
     path = "/api/achievements/{id}/video",
+    params(LanguageParams),
     request_body = VideoUpdate,
     responses(
         (status = 200, description = "Updated video"),
@@ -43,6 +47,7 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
 async fn put_achievement_video(
     session: Session,
     id: web::Path<i32>,
+    language_params: web::Query<LanguageParams>,
     video_update: web::Json<VideoUpdate>,
     pool: web::Data<PgPool>,
 ) -> ApiResult<impl Responder> {
@@ -54,7 +59,13 @@ async fn put_achievement_video(
         return Ok(HttpResponse::Forbidden().finish());
     }
 
-    database::achievements::update_video_by_id(*id, &video_update.video, &pool).await?;
+    database::achievements::update_video_by_id(
+        *id,
+        language_params.lang,
+        &video_update.video,
+        &mut *pool.acquire().await?,
+    )
+    .await?;
 
     Ok(HttpResponse::Ok().finish())
 }
@@ -63,6 +74,7 @@ async fn put_achievement_video(
     tag = "achievements/{id}/video",
     delete,
     path = "/api/achievements/{id}/video",
+    params(LanguageParams),
     responses(
         (status = 200, description = "Deleted video"),
         (status = 403, description = "Not an admin"),
@@ -73,6 +85,7 @@ async fn put_achievement_video(
 async fn delete_achievement_video(
     session: Session,
     id: web::Path<i32>,
+    language_params: web::Query<LanguageParams>,
     pool: web::Data<PgPool>,
 ) -> ApiResult<impl Responder> {
     let Ok(Some(username)) = session.get::<String>("username") else {
@@ -83,7 +96,7 @@ async fn delete_achievement_video(
         return Ok(HttpResponse::Forbidden().finish());
     }
 
-    database::achievements::delete_video_by_id(*id, &pool).await?;
+    database::achievements::delete_video_by_id(*id, language_params.lang, &pool).await?;
 
     Ok(HttpResponse::Ok().finish())
 }
@@ -1,3 +1,4 @@
+pub mod changelog;
 mod id;
 
 use actix_session::Session;
@@ -7,7 +8,7 @@ use sqlx::PgPool;
 use utoipa::{OpenApi, ToSchema};
 
 use crate::{
-    api::{ApiResult, LanguageParams},
+    api::{ApiResult, LanguageParams, Trailblazer},
     database, Difficulty,
 };
 
@@ -19,7 +20,8 @@ use crate::Language;
     paths(get_achievements),
     components(schemas(
         Language,
-        Achievement
+        Achievement,
+        DifficultyVotes
     ))
 )]
 struct ApiDoc;
@@ -52,6 +54,27 @@ struct Achievement {
     #[serde(skip_serializing_if = "Option::is_none")]
     related: Option<Vec<i32>>,
     percent: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    difficulty_votes: Option<DifficultyVotes>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct DifficultyVotes {
+    easy: i64,
+    medium: i64,
+    hard: i64,
+}
+
+impl From<database::achievements_difficulty_votes::DbDifficultyDistribution> for DifficultyVotes {
+    fn from(
+        db_distribution: database::achievements_difficulty_votes::DbDifficultyDistribution,
+    ) -> Self {
+        DifficultyVotes {
+            easy: db_distribution.easy.unwrap_or_default(),
+            medium: db_distribution.medium.unwrap_or_default(),
+            hard: db_distribution.hard.unwrap_or_default(),
+        }
+    }
 }
 
 impl From<database::achievements::DbAchievement> for Achievement {
@@ -79,18 +102,31 @@ impl From<database::achievements::DbAchievement> for Achievement {
             set: db_achievement.set,
             related: None,
             percent: db_achievement.percent,
+            difficulty_votes: None,
         }
     }
 }
 
+impl Achievement {
+    fn render(&mut self, trailblazer: &Trailblazer) {
+        self.series_name = trailblazer.render(&self.series_name);
+        self.name = trailblazer.render(&self.name);
+        self.description = trailblazer.render(&self.description);
+    }
+}
+
 pub fn openapi() -> utoipa::openapi::OpenApi {
     let mut openapi = ApiDoc::openapi();
+    openapi.merge(changelog::openapi());
     openapi.merge(id::openapi());
     openapi
 }
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
-    cfg.service(get_achievements).configure(id::configure);
+    // Before id, which would match the changelog path
+    cfg.service(get_achievements)
+        .configure(changelog::configure)
+        .configure(id::configure);
 }
 
 #[utoipa::path(
@@ -120,12 +156,16 @@ async fn get_achievements(
         db_achievements.retain(|a| !(a.hidden && a.impossible));
     }
 
+    let trailblazer = Trailblazer::new(&session, language_params.lang, &pool).await?;
+
     let mut achievements = db_achievements
         .into_iter()
         .map(Achievement::from)
         .collect::<Vec<_>>();
 
     for achievement in &mut achievements {
+        achievement.render(&trailblazer);
+
         if let Some(set) = achievement.set {
             achievement.related = Some(
                 database::achievements::get_all_related_ids(achievement.id, set, &pool).await?,
@@ -0,0 +1,172 @@
+use actix_session::Session;
+use actix_web::{delete, get, put, web, HttpResponse, Responder};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use sqlx::PgPool;
+use utoipa::{OpenApi, ToSchema};
+
+use crate::{
+    api::{
+        banners::{self, Banner},
+        ApiResult,
+    },
+    database, Game,
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "banners/{game}/{id}")),
+    paths(get_banner, put_banner, delete_banner),
+    components(schemas(BannerUpdate))
+)]
+struct ApiDoc;
+
+#[derive(Deserialize, ToSchema)]
+struct BannerUpdate {
+    gacha_type: String,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    rate_up_5: Vec<i32>,
+    rate_up_4: Vec<i32>,
+}
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_banner)
+        .service(put_banner)
+        .service(delete_banner);
+}
+
+#[utoipa::path(
+    tag = "banners/{game}/{id}",
+    get,
+    path = "/api/banners/{game}/{id}",
+    responses(
+        (status = 200, description = "Banner", body = Banner),
+        (status = 404, description = "No such banner"),
+    )
+)]
+#[get("/api/banners/{game}/{id}")]
+async fn get_banner(
+    path: web::Path<(Game, i32)>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let (game, id) = path.into_inner();
+
+    let db_banner = banners::get_one_by_id(game, id, &pool).await?;
+
+    let Some(db_banner) = db_banner else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+
+    Ok(HttpResponse::Ok().json(Banner::from(db_banner)))
+}
+
+#[utoipa::path(
+    tag = "banners/{game}/{id}",
+    put,
+    path = "/api/banners/{game}/{id}",
+    request_body = BannerUpdate,
+    responses(
+        (status = 200, description = "Added or updated banner, stats are recalculated in the background"),
+        (status = 400, description = "Not logged in, unknown gacha type, end before start or overlapping another banner"),
+        (status = 403, description = "Not an admin"),
+    ),
+    security(("admin" = []))
+)]
+#[put("/api/banners/{game}/{id}")]
+async fn put_banner(
+    session: Session,
+    path: web::Path<(Game, i32)>,
+    banner_update: web::Json<BannerUpdate>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    if !database::admins::exists(&username, &pool).await? {
+        return Ok(HttpResponse::Forbidden().finish());
+    }
+
+    let (game, id) = path.into_inner();
+
+    if !banners::is_gacha_type(game, &banner_update.gacha_type)
+        || banner_update.end <= banner_update.start
+    {
+        return Ok(HttpResponse::BadRequest().finish());
+    }
+
+    let banner_update = banner_update.into_inner();
+
+    let old_gacha_type = banners::get_one_by_id(game, id, &pool)
+        .await?
+        .map(|b| b.gacha_type)
+        .filter(|gacha_type| gacha_type != &banner_update.gacha_type);
+
+    let banner = database::banners::DbBanner {
+        id,
+        gacha_type: banner_update.gacha_type,
+        start_time: banner_update.start,
+        end_time: banner_update.end,
+        rate_up_5: banner_update.rate_up_5,
+        rate_up_4: banner_update.rate_up_4,
+    };
+
+    if banners::overlapping(&banner, &banners::get_all(game, &pool).await?).is_some() {
+        return Ok(HttpResponse::BadRequest().finish());
+    }
+
+    banners::set(game, &banner, &pool).await?;
+
+    if let Some(old_gacha_type) = old_gacha_type {
+        banners::recalculate_stats(game, old_gacha_type, pool.get_ref().clone());
+    }
+    banners::recalculate_stats(game, banner.gacha_type, pool.get_ref().clone());
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[utoipa::path(
+    tag = "banners/{game}/{id}",
+    delete,
+    path = "/api/banners/{game}/{id}",
+    responses(
+        (status = 200, description = "Deleted banner, stats are recalculated in the background"),
+        (status = 403, description = "Not an admin"),
+    ),
+    security(("admin" = []))
+)]
+#[delete("/api/banners/{game}/{id}")]
+async fn delete_banner(
+    session: Session,
+    path: web::Path<(Game, i32)>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    if !database::admins::exists(&username, &pool).await? {
+        return Ok(HttpResponse::Forbidden().finish());
+    }
+
+    let (game, id) = path.into_inner();
+
+    let Some(db_banner) = banners::get_one_by_id(game, id, &pool).await? else {
+        return Ok(HttpResponse::Ok().finish());
+    };
+
+    match game {
+        Game::Hsr => database::banners::delete_by_id(id, &pool).await?,
+        Game::Zzz => database::zzz::banners::delete_by_id(id, &pool).await?,
+        Game::Gi => database::gi::banners::delete_by_id(id, &pool).await?,
+    }
+
+    banners::recalculate_stats(game, db_banner.gacha_type, pool.get_ref().clone());
+
+    Ok(HttpResponse::Ok().finish())
+}
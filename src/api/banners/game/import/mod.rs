@@ -0,0 +1,200 @@
+use std::collections::{HashMap, HashSet};
+
+use actix_multipart::form::MultipartForm;
+use actix_session::Session;
+use actix_web::{post, web, HttpResponse, Responder};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use utoipa::{OpenApi, ToSchema};
+
+use crate::{
+    api::{banners, csv_lines, ApiResult, File, ImportError, ImportParams},
+    database, Game,
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "banners/{game}/import")),
+    paths(import_banners),
+    components(schemas(BannerImportReport, ImportError))
+)]
+struct ApiDoc;
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(import_banners);
+}
+
+/// Rate-up items are separated by spaces or semicolons.
+#[derive(Deserialize)]
+struct Banner {
+    id: i32,
+    gacha_type: String,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    #[serde(default)]
+    rate_up_5: String,
+    #[serde(default)]
+    rate_up_4: String,
+}
+
+#[derive(Default, Serialize, ToSchema)]
+struct BannerImportReport {
+    rows: usize,
+    errors: Vec<ImportError>,
+    applied: bool,
+}
+
+fn items(items: &str) -> Result<Vec<i32>, std::num::ParseIntError> {
+    items
+        .split(|c: char| c == ';' || c.is_whitespace())
+        .filter(|item| !item.is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+#[utoipa::path(
+    tag = "banners/{game}/import",
+    post,
+    path = "/api/banners/{game}/import",
+    params(ImportParams),
+    request_body(content = File, content_type = "multipart/form-data", description = "CSV with the columns id, gacha_type, start, end, rate_up_5, rate_up_4"),
+    responses(
+        (status = 200, description = "BannerImportReport, applied unless dry_run is set, stats are recalculated in the background", body = BannerImportReport),
+        (status = 400, description = "Not logged in or invalid, duplicate or overlapping rows, nothing was applied", body = BannerImportReport),
+        (status = 403, description = "Not an admin")
+    ),
+    security(("admin" = []))
+)]
+#[post("/api/banners/{game}/import")]
+async fn import_banners(
+    session: Session,
+    game: web::Path<Game>,
+    import_params: web::Query<ImportParams>,
+    file: MultipartForm<File>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    if !database::admins::exists(&username, &pool).await? {
+        return Ok(HttpResponse::Forbidden().finish());
+    }
+
+    let lines = csv_lines(&file, 0);
+
+    let mut report = BannerImportReport::default();
+    let mut db_banners = Vec::new();
+    let mut rows = HashMap::new();
+
+    let mut reader = csv::Reader::from_reader(lines.as_bytes());
+    for (i, banner) in reader.deserialize().enumerate() {
+        let row = i + 1;
+        report.rows += 1;
+
+        let banner: Banner = match banner {
+            Ok(banner) => banner,
+            Err(e) => {
+                report.errors.push(ImportError {
+                    row,
+                    key: None,
+                    message: e.to_string(),
+                });
+                continue;
+            }
+        };
+
+        let mut error = |message: String| {
+            report.errors.push(ImportError {
+                row,
+                key: Some(banner.id),
+                message,
+            })
+        };
+
+        let first = *rows.entry(banner.id).or_insert(row);
+        if first != row {
+            error(format!("Duplicate id, already on row {first}"));
+            continue;
+        }
+
+        if !banners::is_gacha_type(*game, &banner.gacha_type) {
+            error(format!("Invalid gacha type \"{}\"", banner.gacha_type));
+            continue;
+        }
+
+        if banner.end <= banner.start {
+            error("End is before start".to_string());
+            continue;
+        }
+
+        let (rate_up_5, rate_up_4) = match (items(&banner.rate_up_5), items(&banner.rate_up_4)) {
+            (Ok(rate_up_5), Ok(rate_up_4)) => (rate_up_5, rate_up_4),
+            (Err(e), _) | (_, Err(e)) => {
+                error(format!("Invalid rate-up item: {e}"));
+                continue;
+            }
+        };
+
+        db_banners.push((
+            row,
+            database::banners::DbBanner {
+                id: banner.id,
+                gacha_type: banner.gacha_type,
+                start_time: banner.start,
+                end_time: banner.end,
+                rate_up_5,
+                rate_up_4,
+            },
+        ));
+    }
+
+    // The banners of the file replace the stored ones with the same id
+    let stored: Vec<_> = banners::get_all(*game, &pool)
+        .await?
+        .into_iter()
+        .filter(|b| !rows.contains_key(&b.id))
+        .collect();
+
+    for (row, db_banner) in &db_banners {
+        let others = stored.iter().chain(db_banners.iter().map(|(_, b)| b));
+
+        if let Some(other) = banners::overlapping(db_banner, others) {
+            report.errors.push(ImportError {
+                row: *row,
+                key: Some(db_banner.id),
+                message: format!("Overlaps banner {}", other.id),
+            });
+        }
+    }
+
+    if import_params.dry_run {
+        return Ok(HttpResponse::Ok().json(report));
+    }
+
+    if !report.errors.is_empty() {
+        return Ok(HttpResponse::BadRequest().json(report));
+    }
+
+    let mut gacha_types = HashSet::new();
+    for (_, db_banner) in &db_banners {
+        if let Some(old) = banners::get_one_by_id(*game, db_banner.id, &pool).await? {
+            gacha_types.insert(old.gacha_type);
+        }
+
+        banners::set(*game, db_banner, &pool).await?;
+        gacha_types.insert(db_banner.gacha_type.clone());
+    }
+    report.applied = true;
+
+    for gacha_type in gacha_types {
+        banners::recalculate_stats(*game, gacha_type, pool.get_ref().clone());
+    }
+
+    Ok(HttpResponse::Ok().json(report))
+}
@@ -0,0 +1,53 @@
+mod id;
+mod import;
+
+use actix_web::{get, web, HttpResponse, Responder};
+use sqlx::PgPool;
+use utoipa::OpenApi;
+
+use crate::{
+    api::{
+        banners::{self, Banner},
+        ApiResult,
+    },
+    Game,
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "banners/{game}")),
+    paths(get_banners)
+)]
+struct ApiDoc;
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    let mut openapi = ApiDoc::openapi();
+    openapi.merge(id::openapi());
+    openapi.merge(import::openapi());
+    openapi
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_banners)
+        .configure(import::configure)
+        .configure(id::configure);
+}
+
+#[utoipa::path(
+    tag = "banners/{game}",
+    get,
+    path = "/api/banners/{game}",
+    responses(
+        (status = 200, description = "[Banner]", body = Vec<Banner>),
+    )
+)]
+#[get("/api/banners/{game}")]
+async fn get_banners(game: web::Path<Game>, pool: web::Data<PgPool>) -> ApiResult<impl Responder> {
+    let banners: Vec<_> = banners::get_all(*game, &pool)
+        .await?
+        .into_iter()
+        .map(Banner::from)
+        .collect();
+
+    Ok(HttpResponse::Ok().json(banners))
+}
@@ -0,0 +1,120 @@
+mod game;
+
+use actix_web::{rt, web};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use utoipa::{OpenApi, ToSchema};
+
+use crate::{
+    database,
+    gacha_import::{self, Gi, Hsr, Zzz},
+    GachaType, Game, GiGachaType, ZzzGachaType,
+};
+
+#[derive(OpenApi)]
+#[openapi(tags((name = "banners")), components(schemas(Banner)))]
+struct ApiDoc;
+
+#[derive(Serialize, ToSchema)]
+struct Banner {
+    id: i32,
+    gacha_type: String,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    /// Pulling anything else on this banner loses the 50/50
+    rate_up_5: Vec<i32>,
+    rate_up_4: Vec<i32>,
+}
+
+impl From<database::banners::DbBanner> for Banner {
+    fn from(db_banner: database::banners::DbBanner) -> Self {
+        Banner {
+            id: db_banner.id,
+            gacha_type: db_banner.gacha_type,
+            start: db_banner.start_time,
+            end: db_banner.end_time,
+            rate_up_5: db_banner.rate_up_5,
+            rate_up_4: db_banner.rate_up_4,
+        }
+    }
+}
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    let mut openapi = ApiDoc::openapi();
+    openapi.merge(game::openapi());
+    openapi
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.configure(game::configure);
+}
+
+fn is_gacha_type(game: Game, gacha_type: &str) -> bool {
+    match game {
+        Game::Hsr => gacha_type.parse::<GachaType>().is_ok(),
+        Game::Zzz => gacha_type.parse::<ZzzGachaType>().is_ok(),
+        Game::Gi => gacha_type.parse::<GiGachaType>().is_ok(),
+    }
+}
+
+/// Another banner of the same gacha type that runs at the same time. Stats and exports take the first banner that
+/// fits a pull, so these can't be stored.
+fn overlapping<'a>(
+    banner: &database::banners::DbBanner,
+    banners: impl IntoIterator<Item = &'a database::banners::DbBanner>,
+) -> Option<&'a database::banners::DbBanner> {
+    banners.into_iter().find(|b| {
+        b.id != banner.id
+            && b.gacha_type == banner.gacha_type
+            && b.start_time < banner.end_time
+            && banner.start_time < b.end_time
+    })
+}
+
+async fn get_all(game: Game, pool: &PgPool) -> anyhow::Result<Vec<database::banners::DbBanner>> {
+    match game {
+        Game::Hsr => database::banners::get_all(pool).await,
+        Game::Zzz => database::zzz::banners::get_all(pool).await,
+        Game::Gi => database::gi::banners::get_all(pool).await,
+    }
+}
+
+async fn get_one_by_id(
+    game: Game,
+    id: i32,
+    pool: &PgPool,
+) -> anyhow::Result<Option<database::banners::DbBanner>> {
+    match game {
+        Game::Hsr => database::banners::get_one_by_id(id, pool).await,
+        Game::Zzz => database::zzz::banners::get_one_by_id(id, pool).await,
+        Game::Gi => database::gi::banners::get_one_by_id(id, pool).await,
+    }
+}
+
+async fn set(
+    game: Game,
+    banner: &database::banners::DbBanner,
+    pool: &PgPool,
+) -> anyhow::Result<()> {
+    match game {
+        Game::Hsr => database::banners::set(banner, pool).await,
+        Game::Zzz => database::zzz::banners::set(banner, pool).await,
+        Game::Gi => database::gi::banners::set(banner, pool).await,
+    }
+}
+
+/// Banners decide the 50/50s, so the stats of the gacha type are recalculated for every uid in the background.
+fn recalculate_stats(game: Game, gacha_type: String, pool: PgPool) {
+    rt::spawn(async move {
+        let result = match game {
+            Game::Hsr => gacha_import::recalculate_stats::<Hsr>(&gacha_type, &pool).await,
+            Game::Zzz => gacha_import::recalculate_stats::<Zzz>(&gacha_type, &pool).await,
+            Game::Gi => gacha_import::recalculate_stats::<Gi>(&gacha_type, &pool).await,
+        };
+
+        if let Err(e) = result {
+            error!("Recalculating the {game} {gacha_type} stats failed with {e}");
+        }
+    });
+}
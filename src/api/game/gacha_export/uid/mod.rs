@@ -0,0 +1,329 @@
+use actix_session::Session;
+use actix_web::{get, http::header, web, HttpResponse, Responder};
+use chrono::{DateTime, Duration, Utc};
+use serde::Deserialize;
+use sqlx::PgPool;
+use strum::IntoEnumIterator;
+use utoipa::{IntoParams, OpenApi, ToSchema};
+
+use crate::{
+    api::{
+        game::{time_zone, verified, Srgf, SrgfInfo, Uigf, UigfAccount, UigfEntry, UigfInfo},
+        ApiResult, LanguageParams,
+    },
+    database,
+    gacha_import::{Gacha, Gi, Hsr, Zzz},
+    GachaType, Game, GiGachaType, Language, ZzzGachaType,
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "{game}/gacha-export/{uid}")),
+    paths(get_gacha_export),
+    components(schemas(Format))
+)]
+struct ApiDoc;
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_gacha_export);
+}
+
+#[derive(Default, PartialEq, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+enum Format {
+    /// UIGF v4, works for all games
+    #[default]
+    Uigf4,
+    /// SRGF v1, Star Rail only
+    Srgf,
+}
+
+#[derive(Deserialize, IntoParams)]
+struct ExportParams {
+    #[serde(default)]
+    format: Format,
+}
+
+/// Same rules as the trackers, private uids can only be exported with a verified connection.
+async fn forbidden(game: Game, uid: i32, session: &Session, pool: &PgPool) -> anyhow::Result<bool> {
+    let private = match game {
+        Game::Hsr => database::connections::get_by_uid(uid, pool)
+            .await?
+            .iter()
+            .any(|c| c.private),
+        Game::Zzz => database::zzz::connections::get_by_uid(uid, pool)
+            .await?
+            .iter()
+            .any(|c| c.private),
+        Game::Gi => database::gi::connections::get_by_uid(uid, pool)
+            .await?
+            .iter()
+            .any(|c| c.private),
+    };
+
+    if !private {
+        return Ok(false);
+    }
+
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(true);
+    };
+
+    verified(game, uid, &username, pool)
+        .await
+        .map(|verified| !verified)
+}
+
+/// The banner a pull was made on, if it's known.
+fn gacha_id(banners: &[database::banners::DbBanner], timestamp: DateTime<Utc>) -> Option<String> {
+    banners
+        .iter()
+        .find(|b| b.start_time <= timestamp && timestamp < b.end_time)
+        .map(|b| b.id.to_string())
+}
+
+fn time(timestamp: DateTime<Utc>, time_zone: i64) -> String {
+    (timestamp + Duration::hours(time_zone))
+        .format("%Y-%m-%d %H:%M:%S")
+        .to_string()
+}
+
+async fn hkrpg(uid: i32, language: Language, pool: &PgPool) -> anyhow::Result<Vec<UigfEntry>> {
+    let time_zone = time_zone(Game::Hsr, uid);
+
+    let mut list = Vec::new();
+
+    for gacha_type in GachaType::iter() {
+        let warps = match gacha_type {
+            GachaType::Departure => {
+                database::warps::departure::get_by_uid(uid, language, pool).await?
+            }
+            GachaType::Standard => {
+                database::warps::standard::get_by_uid(uid, language, pool).await?
+            }
+            GachaType::Special => database::warps::special::get_by_uid(uid, language, pool).await?,
+            GachaType::Lc => database::warps::lc::get_by_uid(uid, language, pool).await?,
+        };
+
+        let banners = database::banners::get_by_gacha_type(&gacha_type.to_string(), pool).await?;
+
+        for warp in warps {
+            let (item_id, item_type) = match warp.character {
+                Some(character) => (character, "Character"),
+                None => (warp.light_cone.unwrap(), "Light Cone"),
+            };
+
+            list.push(UigfEntry {
+                uigf_gacha_type: None,
+                // Required by SRGF, 0 if the banner isn't known
+                gacha_id: Some(gacha_id(&banners, warp.timestamp).unwrap_or("0".to_string())),
+                gacha_type: Hsr::gacha_type_id(gacha_type).to_string(),
+                item_id: item_id.to_string(),
+                count: "1".to_string(),
+                time: time(warp.timestamp, time_zone),
+                name: warp.name.unwrap_or_default(),
+                item_type: item_type.to_string(),
+                rank_type: warp.rarity.unwrap_or_default().to_string(),
+                id: warp.id.to_string(),
+            });
+        }
+    }
+
+    Ok(list)
+}
+
+async fn nap(uid: i32, language: Language, pool: &PgPool) -> anyhow::Result<Vec<UigfEntry>> {
+    let time_zone = time_zone(Game::Zzz, uid);
+
+    let mut list = Vec::new();
+
+    for gacha_type in ZzzGachaType::iter() {
+        let signals = match gacha_type {
+            ZzzGachaType::Standard => {
+                database::zzz::signals::standard::get_by_uid(uid, language, pool).await?
+            }
+            ZzzGachaType::Special => {
+                database::zzz::signals::special::get_by_uid(uid, language, pool).await?
+            }
+            ZzzGachaType::WEngine => {
+                database::zzz::signals::w_engine::get_by_uid(uid, language, pool).await?
+            }
+            ZzzGachaType::Bangboo => {
+                database::zzz::signals::bangboo::get_by_uid(uid, language, pool).await?
+            }
+        };
+
+        let banners =
+            database::zzz::banners::get_by_gacha_type(&gacha_type.to_string(), pool).await?;
+
+        for signal in signals {
+            let (item_id, item_type) = match (signal.character, signal.w_engine) {
+                (Some(character), _) => (character, "Agents"),
+                (_, Some(w_engine)) => (w_engine, "W-Engines"),
+                _ => (signal.bangboo.unwrap(), "Bangboo"),
+            };
+
+            list.push(UigfEntry {
+                uigf_gacha_type: None,
+                gacha_id: gacha_id(&banners, signal.timestamp),
+                gacha_type: Zzz::gacha_type_id(gacha_type).to_string(),
+                item_id: item_id.to_string(),
+                count: "1".to_string(),
+                time: time(signal.timestamp, time_zone),
+                name: signal.name.unwrap_or_default(),
+                item_type: item_type.to_string(),
+                rank_type: signal.rarity.unwrap_or_default().to_string(),
+                id: signal.id.to_string(),
+            });
+        }
+    }
+
+    Ok(list)
+}
+
+async fn hk4e(uid: i32, language: Language, pool: &PgPool) -> anyhow::Result<Vec<UigfEntry>> {
+    let time_zone = time_zone(Game::Gi, uid);
+
+    let mut list = Vec::new();
+
+    for gacha_type in GiGachaType::iter() {
+        let wishes = match gacha_type {
+            GiGachaType::Beginner => {
+                database::gi::wishes::beginner::get_by_uid(uid, language, pool).await?
+            }
+            GiGachaType::Standard => {
+                database::gi::wishes::standard::get_by_uid(uid, language, pool).await?
+            }
+            GiGachaType::Character => {
+                database::gi::wishes::character::get_by_uid(uid, language, pool).await?
+            }
+            GiGachaType::Weapon => {
+                database::gi::wishes::weapon::get_by_uid(uid, language, pool).await?
+            }
+            GiGachaType::Chronicled => {
+                database::gi::wishes::chronicled::get_by_uid(uid, language, pool).await?
+            }
+        };
+
+        // The second character banner (400) isn't stored separately, both are exported as 301
+        let gacha_type_id = Gi::gacha_type_id(gacha_type);
+
+        for wish in wishes {
+            let (item_id, item_type) = match wish.character {
+                Some(character) => (character, "Character"),
+                None => (wish.weapon.unwrap(), "Weapon"),
+            };
+
+            list.push(UigfEntry {
+                uigf_gacha_type: Some(gacha_type_id.to_string()),
+                gacha_id: None,
+                gacha_type: gacha_type_id.to_string(),
+                item_id: item_id.to_string(),
+                count: "1".to_string(),
+                time: time(wish.timestamp, time_zone),
+                name: wish.name.unwrap_or_default(),
+                item_type: item_type.to_string(),
+                rank_type: wish.rarity.unwrap_or_default().to_string(),
+                id: wish.id.to_string(),
+            });
+        }
+    }
+
+    Ok(list)
+}
+
+#[utoipa::path(
+    tag = "{game}/gacha-export/{uid}",
+    get,
+    path = "/api/{game}/gacha-export/{uid}",
+    params(ExportParams, LanguageParams),
+    responses(
+        (status = 200, description = "UIGF v4 or SRGF v1 file"),
+        (status = 400, description = "SRGF for a game other than Star Rail"),
+        (status = 403, description = "Private"),
+    )
+)]
+#[get("/api/{game}/gacha-export/{uid}")]
+async fn get_gacha_export(
+    session: Session,
+    path: web::Path<(Game, i32)>,
+    export_params: web::Query<ExportParams>,
+    language_params: web::Query<LanguageParams>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let (game, uid) = *path;
+
+    if export_params.format == Format::Srgf && !matches!(game, Game::Hsr) {
+        return Ok(HttpResponse::BadRequest().finish());
+    }
+
+    if forbidden(game, uid, &session, &pool).await? {
+        return Ok(HttpResponse::Forbidden().finish());
+    }
+
+    let language = language_params.lang;
+
+    let list = match game {
+        Game::Hsr => hkrpg(uid, language, &pool).await?,
+        Game::Zzz => nap(uid, language, &pool).await?,
+        Game::Gi => hk4e(uid, language, &pool).await?,
+    };
+
+    let timezone = time_zone(game, uid);
+    let export_timestamp = Utc::now().timestamp();
+    let export_app = "stardb".to_string();
+    let export_app_version = env!("CARGO_PKG_VERSION").to_string();
+
+    let mut response = HttpResponse::Ok();
+    response.insert_header((
+        header::CONTENT_DISPOSITION,
+        format!("attachment; filename=\"stardb_{game}_{uid}.json\""),
+    ));
+
+    Ok(match export_params.format {
+        Format::Uigf4 => {
+            let accounts = vec![UigfAccount {
+                uid: uid.to_string(),
+                timezone,
+                lang: language.uigf(),
+                list,
+            }];
+
+            let mut uigf = Uigf {
+                info: UigfInfo {
+                    export_timestamp,
+                    export_app,
+                    export_app_version,
+                    version: "v4.0".to_string(),
+                },
+                hk4e: Vec::new(),
+                hkrpg: Vec::new(),
+                nap: Vec::new(),
+            };
+
+            match game {
+                Game::Hsr => uigf.hkrpg = accounts,
+                Game::Zzz => uigf.nap = accounts,
+                Game::Gi => uigf.hk4e = accounts,
+            }
+
+            response.json(uigf)
+        }
+        Format::Srgf => response.json(Srgf {
+            info: SrgfInfo {
+                uid: uid.to_string(),
+                lang: language.uigf(),
+                region_time_zone: timezone,
+                export_timestamp,
+                export_app,
+                export_app_version,
+                srgf_version: "v1.0".to_string(),
+            },
+            list,
+        }),
+    })
+}
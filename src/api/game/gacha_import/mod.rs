@@ -0,0 +1,662 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    io::{BufReader, Read},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use actix_multipart::form::MultipartForm;
+use actix_session::Session;
+use actix_web::{post, web, HttpResponse, Responder};
+use chrono::{DateTime, NaiveDateTime};
+use serde::{de::IgnoredAny, Deserialize, Deserializer, Serialize};
+use sqlx::{sqlite::SqliteConnectOptions, ConnectOptions, Connection, PgPool};
+use strum::IntoEnumIterator;
+use utoipa::{IntoParams, OpenApi, ToSchema};
+
+use crate::{
+    api::{
+        game::{time_zone, verified},
+        ApiResult, File,
+    },
+    gacha_import::{self, Gacha, Gi, Hsr, Pull, Zzz},
+    Game,
+};
+
+const SQLITE_HEADER: &[u8; 16] = b"SQLite format 3\0";
+/// Starward databases of a few accounts stay well below this.
+const STARWARD_MAX_SIZE: u64 = 32 * 1024 * 1024;
+/// The database is untrusted, a view can make the query run for as long as it wants.
+const STARWARD_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "{game}/gacha-import")),
+    paths(import_gacha),
+    components(schemas(GachaImportReport, GachaTypeReport, GachaImportError))
+)]
+struct ApiDoc;
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(import_gacha);
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum GachaFile {
+    RngMoe(RngMoe),
+    GenshinWishExport(GenshinWishExport),
+    List(List),
+    Uigf(Uigf),
+}
+
+/// ZZZ.rng.moe, the pulls are in `data.profiles.{profile}.stores.0.items_{gacha_type}`
+#[derive(Deserialize)]
+struct RngMoe {
+    data: RngMoeData,
+}
+
+#[derive(Deserialize)]
+struct RngMoeData {
+    profiles: HashMap<String, RngMoeProfile>,
+}
+
+#[derive(Deserialize)]
+struct RngMoeProfile {
+    stores: HashMap<String, HashMap<String, serde_json::Value>>,
+}
+
+#[derive(Deserialize)]
+struct RngMoeSignal {
+    uid: String,
+    #[serde(rename = "itemId")]
+    item_id: i32,
+    timestamp: i64,
+}
+
+/// Genshin Wish Export's own `gacha-list-{uid}.json`
+#[derive(Deserialize)]
+struct GenshinWishExport {
+    #[serde(deserialize_with = "uid")]
+    uid: i32,
+    result: Vec<(String, Vec<GenshinWishExportWish>)>,
+}
+
+/// time, name, item_type, rank_type, gacha_type and id, older versions without the id can't be imported
+#[derive(Deserialize)]
+struct GenshinWishExportWish(String, String, String, IgnoredAny, String, String);
+
+/// A row of the `StarRailGachaItem`, `ZZZGachaItem` or `GenshinGachaItem` table of Starward's database.
+#[derive(sqlx::FromRow)]
+#[sqlx(rename_all = "PascalCase")]
+struct StarwardItem {
+    uid: i64,
+    id: i64,
+    name: String,
+    time: String,
+    item_id: i64,
+    item_type: String,
+    gacha_type: i64,
+}
+
+/// One account with a flat list, used by SRGF v1, UIGF v3 and Starward.
+#[derive(Deserialize)]
+struct List {
+    info: ListInfo,
+    list: Vec<Entry>,
+}
+
+#[derive(Deserialize)]
+struct ListInfo {
+    #[serde(deserialize_with = "uid")]
+    uid: i32,
+    /// Older Starward exports leave it out
+    region_time_zone: Option<i64>,
+    /// SRGF, Star Rail only
+    srgf_version: Option<String>,
+    /// UIGF v3 and older, Genshin only
+    uigf_version: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Uigf {
+    info: UigfInfo,
+    #[serde(default)]
+    hk4e: Vec<Account>,
+    #[serde(default)]
+    hkrpg: Vec<Account>,
+    #[serde(default)]
+    nap: Vec<Account>,
+}
+
+#[derive(Deserialize)]
+struct UigfInfo {
+    version: String,
+}
+
+#[derive(Deserialize)]
+struct Account {
+    #[serde(deserialize_with = "uid")]
+    uid: i32,
+    timezone: i64,
+    list: Vec<Entry>,
+}
+
+#[derive(Deserialize)]
+struct Entry {
+    id: String,
+    gacha_type: String,
+    /// Genshin only, merges the two character banners
+    uigf_gacha_type: Option<String>,
+    #[serde(default)]
+    item_id: String,
+    #[serde(default)]
+    item_type: String,
+    #[serde(default)]
+    name: String,
+    time: String,
+}
+
+/// UIGF allows the uid as a string or a number.
+fn uid<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i32, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Uid {
+        Number(i32),
+        String(String),
+    }
+
+    match Uid::deserialize(deserializer)? {
+        Uid::Number(uid) => Ok(uid),
+        Uid::String(uid) => uid.parse().map_err(serde::de::Error::custom),
+    }
+}
+
+#[derive(Deserialize, IntoParams)]
+struct ImportParams {
+    #[serde(default)]
+    dry_run: bool,
+    /// Needed for ZZZ.rng.moe, its exports don't contain the uid. Picks the account of a Starward database.
+    uid: Option<i32>,
+    /// ZZZ.rng.moe profile, defaults to 1
+    profile: Option<String>,
+}
+
+#[derive(Default, Serialize, ToSchema)]
+struct GachaImportReport {
+    /// Why the file couldn't be read
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    gacha_types: Vec<GachaTypeReport>,
+    errors: Vec<GachaImportError>,
+    applied: bool,
+}
+
+#[derive(Serialize, ToSchema)]
+struct GachaTypeReport {
+    uid: i32,
+    gacha_type: String,
+    /// Pulls in the file
+    count: usize,
+    /// Pulls that aren't imported yet
+    new: usize,
+    /// Pulls that are already imported and stay as they are
+    existing: usize,
+    /// Existing pulls that came from an official import
+    official: usize,
+}
+
+#[derive(Serialize, ToSchema)]
+struct GachaImportError {
+    uid: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    message: String,
+}
+
+/// Checks the pulls of one account against the database and batches the new ones.
+async fn prepare<G: Gacha>(
+    account: Account,
+    items: &G::Items,
+    report: &mut GachaImportReport,
+    pool: &PgPool,
+) -> anyhow::Result<Vec<(G::GachaType, G::SetAll)>> {
+    let uid = account.uid;
+    let timestamp_offset = chrono::Duration::hours(account.timezone);
+
+    let mut pulls: Vec<_> = G::GachaType::iter().map(|t| (t, Vec::new())).collect();
+
+    for entry in account.list {
+        let mut error = |message: String| {
+            report.errors.push(GachaImportError {
+                uid,
+                id: Some(entry.id.clone()),
+                message,
+            })
+        };
+
+        let gacha_type = entry
+            .uigf_gacha_type
+            .as_deref()
+            .unwrap_or(&entry.gacha_type);
+
+        let Some((_, batch)) = pulls
+            .iter_mut()
+            .find(|(t, _)| G::gacha_type_id(*t) == gacha_type)
+        else {
+            error(format!("Unknown gacha type \"{gacha_type}\""));
+            continue;
+        };
+
+        let Ok(id) = entry.id.parse() else {
+            error("Invalid id".to_string());
+            continue;
+        };
+
+        let Ok(timestamp) = NaiveDateTime::parse_from_str(&entry.time, "%Y-%m-%d %H:%M:%S") else {
+            error(format!("Invalid time \"{}\"", entry.time));
+            continue;
+        };
+
+        batch.push(Pull {
+            id,
+            uid,
+            item_type: entry.item_type,
+            item_id: entry.item_id,
+            name: entry.name,
+            timestamp: timestamp.and_utc() - timestamp_offset,
+            official: false,
+        });
+    }
+
+    let mut set_alls = Vec::new();
+
+    for (gacha_type, pulls) in pulls {
+        if pulls.is_empty() {
+            continue;
+        }
+
+        let ids: Vec<_> = pulls.iter().map(|p| p.id).collect();
+
+        let mut known: HashSet<_> = G::get_existing_ids(gacha_type, uid, &ids, pool)
+            .await?
+            .into_iter()
+            .collect();
+        let existing = known.len();
+        let official = G::get_official_ids(gacha_type, uid, &ids, pool)
+            .await?
+            .len();
+
+        let mut set_all = G::SetAll::default();
+        let mut new = 0;
+
+        for pull in pulls {
+            // Also skips pulls that are in the file twice
+            if !known.insert(pull.id) {
+                continue;
+            }
+
+            let id = pull.id;
+
+            if let Err(e) = G::push(&mut set_all, pull, items, pool).await {
+                report.errors.push(GachaImportError {
+                    uid,
+                    id: Some(id.to_string()),
+                    message: e.to_string(),
+                });
+                continue;
+            }
+
+            new += 1;
+        }
+
+        report.gacha_types.push(GachaTypeReport {
+            uid,
+            gacha_type: gacha_type.to_string(),
+            count: ids.len(),
+            new,
+            existing,
+            official,
+        });
+
+        set_alls.push((gacha_type, set_all));
+    }
+
+    Ok(set_alls)
+}
+
+/// Nothing is written unless every account of the file is valid.
+async fn import<G: Gacha>(
+    accounts: Vec<Account>,
+    dry_run: bool,
+    report: &mut GachaImportReport,
+    pool: &PgPool,
+) -> anyhow::Result<()> {
+    let items = G::get_items(pool).await?;
+
+    let mut batches = Vec::new();
+
+    for account in accounts {
+        let uid = account.uid;
+
+        batches.push((uid, prepare::<G>(account, &items, report, pool).await?));
+    }
+
+    if dry_run || !report.errors.is_empty() {
+        return Ok(());
+    }
+
+    let mut transaction = pool.begin().await?;
+
+    for (_, set_alls) in &batches {
+        for (gacha_type, set_all) in set_alls {
+            G::set_all(*gacha_type, set_all, &mut transaction).await?;
+        }
+    }
+
+    transaction.commit().await?;
+
+    for (uid, _) in &batches {
+        gacha_import::calculate_stats::<G>(*uid, pool).await?;
+    }
+
+    report.applied = true;
+
+    Ok(())
+}
+
+/// Genshin's second character banner counts as the first one.
+fn uigf_gacha_type(gacha_type: &str) -> String {
+    match gacha_type {
+        "400" => "301",
+        gacha_type => gacha_type,
+    }
+    .to_string()
+}
+
+/// Starward keeps all accounts of all games in one database, `uid` picks one of them.
+async fn starward(game: Game, path: &Path, uid: Option<i32>) -> anyhow::Result<Vec<Account>> {
+    let table = match game {
+        Game::Hsr => "StarRailGachaItem",
+        Game::Zzz => "ZZZGachaItem",
+        Game::Gi => "GenshinGachaItem",
+    };
+
+    if fs::metadata(path)?.len() > STARWARD_MAX_SIZE {
+        return Err(anyhow::anyhow!(
+            "Starward databases can't be larger than {} MiB",
+            STARWARD_MAX_SIZE / 1024 / 1024
+        ));
+    }
+
+    let mut connection = SqliteConnectOptions::new()
+        .filename(path)
+        .read_only(true)
+        .connect()
+        .await?;
+
+    let deadline = Instant::now() + STARWARD_TIMEOUT;
+    connection
+        .lock_handle()
+        .await?
+        .set_progress_handler(1000, move || Instant::now() < deadline);
+
+    // Not our database, so the query can't be checked at compile time
+    let items: Vec<StarwardItem> = sqlx::query_as(&format!(
+        "SELECT Uid, Id, Name, Time, ItemId, ItemType, GachaType FROM {table} ORDER BY Id"
+    ))
+    .fetch_all(&mut connection)
+    .await?;
+
+    connection.close().await?;
+
+    let mut accounts: Vec<Account> = Vec::new();
+
+    for item in items {
+        let item_uid = item.uid.try_into()?;
+
+        if uid.is_some_and(|uid| uid != item_uid) {
+            continue;
+        }
+
+        let account = match accounts.iter().position(|a| a.uid == item_uid) {
+            Some(i) => &mut accounts[i],
+            None => {
+                accounts.push(Account {
+                    uid: item_uid,
+                    timezone: time_zone(game, item_uid),
+                    list: Vec::new(),
+                });
+                accounts.last_mut().unwrap()
+            }
+        };
+
+        let gacha_type = item.gacha_type.to_string();
+
+        account.list.push(Entry {
+            id: item.id.to_string(),
+            uigf_gacha_type: Some(uigf_gacha_type(&gacha_type)),
+            gacha_type,
+            // Old Genshin rows only have names
+            item_id: if item.item_id != 0 {
+                item.item_id.to_string()
+            } else {
+                String::new()
+            },
+            item_type: item.item_type,
+            name: item.name,
+            time: item.time,
+        });
+    }
+
+    Ok(accounts)
+}
+
+/// The accounts of a json file that fit the game.
+fn accounts(
+    game: Game,
+    gacha_file: GachaFile,
+    import_params: &ImportParams,
+) -> anyhow::Result<Vec<Account>> {
+    let accounts = match gacha_file {
+        GachaFile::RngMoe(rng_moe) if matches!(game, Game::Zzz) => {
+            let uid = import_params
+                .uid
+                .ok_or_else(|| anyhow::anyhow!("ZZZ.rng.moe exports need the uid parameter"))?;
+
+            let profile = import_params.profile.as_deref().unwrap_or("1");
+
+            let store = rng_moe
+                .data
+                .profiles
+                .get(profile)
+                .and_then(|p| p.stores.get("0"))
+                .ok_or_else(|| anyhow::anyhow!("No signals in profile \"{profile}\""))?;
+
+            let mut list = Vec::new();
+
+            for (key, signals) in store {
+                let Some(gacha_type) = key.strip_prefix("items_") else {
+                    continue;
+                };
+
+                let signals: Vec<RngMoeSignal> = serde_json::from_value(signals.clone())
+                    .map_err(|e| anyhow::anyhow!("Invalid signals in \"{key}\": {e}"))?;
+
+                for signal in signals {
+                    let timestamp =
+                        DateTime::from_timestamp_millis(signal.timestamp).ok_or_else(|| {
+                            anyhow::anyhow!("Invalid timestamp of signal {}", signal.uid)
+                        })?;
+
+                    list.push(Entry {
+                        id: signal.uid,
+                        gacha_type: gacha_type.to_string(),
+                        uigf_gacha_type: None,
+                        item_id: signal.item_id.to_string(),
+                        item_type: String::new(),
+                        name: String::new(),
+                        time: timestamp.format("%Y-%m-%d %H:%M:%S").to_string(),
+                    });
+                }
+            }
+
+            // Unix timestamps, so they are written out as utc instead of the server time
+            vec![Account {
+                uid,
+                timezone: 0,
+                list,
+            }]
+        }
+        GachaFile::GenshinWishExport(genshin_wish_export) if matches!(game, Game::Gi) => {
+            let uid = genshin_wish_export.uid;
+
+            let list = genshin_wish_export
+                .result
+                .into_iter()
+                .flat_map(|(_, wishes)| wishes)
+                .map(
+                    |GenshinWishExportWish(time, name, item_type, _, gacha_type, id)| Entry {
+                        id,
+                        uigf_gacha_type: Some(uigf_gacha_type(&gacha_type)),
+                        gacha_type,
+                        item_id: String::new(),
+                        item_type,
+                        name,
+                        time,
+                    },
+                )
+                .collect();
+
+            vec![Account {
+                uid,
+                timezone: time_zone(game, uid),
+                list,
+            }]
+        }
+        GachaFile::List(list) => {
+            match (&list.info.srgf_version, &list.info.uigf_version, game) {
+                (Some(version), _, Game::Hsr) if version.starts_with("v1") => {}
+                (_, Some(version), Game::Gi)
+                    if version.starts_with("v2") || version.starts_with("v3") => {}
+                (Some(version), _, _) => {
+                    return Err(anyhow::anyhow!(
+                        "SRGF {version} can't be imported for {game}"
+                    ))
+                }
+                (_, Some(version), _) => {
+                    return Err(anyhow::anyhow!(
+                        "UIGF {version} can't be imported for {game}"
+                    ))
+                }
+                (None, None, _) => {
+                    return Err(anyhow::anyhow!("Missing srgf_version or uigf_version"))
+                }
+            }
+
+            vec![Account {
+                uid: list.info.uid,
+                timezone: list
+                    .info
+                    .region_time_zone
+                    .unwrap_or_else(|| time_zone(game, list.info.uid)),
+                list: list.list,
+            }]
+        }
+        GachaFile::Uigf(uigf) if uigf.info.version.starts_with("v4") => match game {
+            Game::Hsr => uigf.hkrpg,
+            Game::Zzz => uigf.nap,
+            Game::Gi => uigf.hk4e,
+        },
+        GachaFile::Uigf(uigf) => {
+            return Err(anyhow::anyhow!(
+                "UIGF {} can't be imported",
+                uigf.info.version
+            ))
+        }
+        _ => return Err(anyhow::anyhow!("The file doesn't fit {game}")),
+    };
+
+    Ok(accounts)
+}
+
+#[utoipa::path(
+    tag = "{game}/gacha-import",
+    post,
+    path = "/api/{game}/gacha-import",
+    params(ImportParams),
+    request_body(content = File, content_type = "multipart/form-data", description = "UIGF v4, SRGF v1, UIGF v3, Starward json or database, Genshin Wish Export or ZZZ.rng.moe"),
+    responses(
+        (status = 200, description = "GachaImportReport, applied unless dry_run is set", body = GachaImportReport),
+        (status = 400, description = "Not logged in, invalid file or invalid pulls, nothing was applied", body = GachaImportReport),
+        (status = 403, description = "A uid of the file isn't verified for the user")
+    )
+)]
+#[post("/api/{game}/gacha-import")]
+async fn import_gacha(
+    session: Session,
+    game: web::Path<Game>,
+    import_params: web::Query<ImportParams>,
+    file: MultipartForm<File>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    let game = *game;
+
+    let path = file.file.file.path();
+
+    let mut header = [0; 16];
+    let sqlite = fs::File::open(path)?.read_exact(&mut header).is_ok() && &header == SQLITE_HEADER;
+
+    let mut report = GachaImportReport::default();
+
+    let accounts = if sqlite {
+        starward(game, path, import_params.uid).await
+    } else {
+        serde_json::from_reader(BufReader::new(&file.file.file))
+            .map_err(anyhow::Error::from)
+            .and_then(|gacha_file| accounts(game, gacha_file, &import_params))
+    };
+
+    let accounts = match accounts {
+        Ok(accounts) if accounts.is_empty() => {
+            report.error = Some("No pulls to import".to_string());
+
+            return Ok(HttpResponse::BadRequest().json(report));
+        }
+        Ok(accounts) => accounts,
+        Err(e) => {
+            report.error = Some(e.to_string());
+
+            return Ok(HttpResponse::BadRequest().json(report));
+        }
+    };
+
+    for account in &accounts {
+        if !verified(game, account.uid, &username, &pool).await? {
+            return Ok(HttpResponse::Forbidden().finish());
+        }
+    }
+
+    let dry_run = import_params.dry_run;
+
+    match game {
+        Game::Hsr => import::<Hsr>(accounts, dry_run, &mut report, &pool).await?,
+        Game::Zzz => import::<Zzz>(accounts, dry_run, &mut report, &pool).await?,
+        Game::Gi => import::<Gi>(accounts, dry_run, &mut report, &pool).await?,
+    }
+
+    if !dry_run && !report.errors.is_empty() {
+        return Ok(HttpResponse::BadRequest().json(report));
+    }
+
+    Ok(HttpResponse::Ok().json(report))
+}
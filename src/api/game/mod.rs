@@ -0,0 +1,116 @@
+mod gacha_export;
+mod gacha_import;
+
+use actix_web::web;
+use serde::Serialize;
+use sqlx::PgPool;
+use utoipa::OpenApi;
+
+use crate::{database, Game};
+
+#[derive(OpenApi)]
+#[openapi()]
+struct ApiDoc;
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    let mut openapi = ApiDoc::openapi();
+    openapi.merge(gacha_export::openapi());
+    openapi.merge(gacha_import::openapi());
+    openapi
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.configure(gacha_export::configure)
+        .configure(gacha_import::configure);
+}
+
+/// UIGF v4, see <https://uigf.org/en/standards/uigf.html>
+#[derive(Serialize)]
+struct Uigf {
+    info: UigfInfo,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    hk4e: Vec<UigfAccount>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    hkrpg: Vec<UigfAccount>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    nap: Vec<UigfAccount>,
+}
+
+#[derive(Serialize)]
+struct UigfInfo {
+    export_timestamp: i64,
+    export_app: String,
+    export_app_version: String,
+    version: String,
+}
+
+#[derive(Serialize)]
+struct UigfAccount {
+    uid: String,
+    timezone: i64,
+    lang: String,
+    list: Vec<UigfEntry>,
+}
+
+/// SRGF v1, see <https://uigf.org/en/standards/srgf.html>
+#[derive(Serialize)]
+struct Srgf {
+    info: SrgfInfo,
+    list: Vec<UigfEntry>,
+}
+
+#[derive(Serialize)]
+struct SrgfInfo {
+    uid: String,
+    lang: String,
+    region_time_zone: i64,
+    export_timestamp: i64,
+    export_app: String,
+    export_app_version: String,
+    srgf_version: String,
+}
+
+/// One pull, shared by all three games and SRGF.
+#[derive(Serialize)]
+struct UigfEntry {
+    /// Genshin only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uigf_gacha_type: Option<String>,
+    /// Star Rail and Zenless only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gacha_id: Option<String>,
+    gacha_type: String,
+    item_id: String,
+    count: String,
+    time: String,
+    name: String,
+    item_type: String,
+    rank_type: String,
+    id: String,
+}
+
+/// Time zone of the server a uid is on, the exports store local times.
+fn time_zone(game: Game, uid: i32) -> i64 {
+    match (game, uid / 100_000_000) {
+        (Game::Zzz, 10) | (Game::Hsr | Game::Gi, 6) => -5,
+        (Game::Zzz, 15) | (Game::Hsr | Game::Gi, 7) => 1,
+        _ => 8,
+    }
+}
+
+/// Whether the user has a verified connection to the uid.
+async fn verified(game: Game, uid: i32, username: &str, pool: &PgPool) -> anyhow::Result<bool> {
+    let verified = match game {
+        Game::Hsr => database::connections::get_by_uid_and_username(uid, username, pool)
+            .await
+            .map(|c| c.verified),
+        Game::Zzz => database::zzz::connections::get_by_uid_and_username(uid, username, pool)
+            .await
+            .map(|c| c.verified),
+        Game::Gi => database::gi::connections::get_by_uid_and_username(uid, username, pool)
+            .await
+            .map(|c| c.verified),
+    };
+
+    Ok(verified.unwrap_or_default())
+}
@@ -0,0 +1,93 @@
+use actix_session::Session;
+use actix_web::{delete, put, web, HttpResponse, Responder};
+use serde::Deserialize;
+use sqlx::PgPool;
+use utoipa::{OpenApi, ToSchema};
+
+use crate::{api::ApiResult, database, Game};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "game-versions/{game}")),
+    paths(put_game_version, delete_game_version),
+    components(schemas(VersionUpdate))
+)]
+struct ApiDoc;
+
+#[derive(Deserialize, ToSchema)]
+struct VersionUpdate {
+    version: String,
+}
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(put_game_version).service(delete_game_version);
+}
+
+#[utoipa::path(
+    tag = "game-versions/{game}",
+    put,
+    path = "/api/game-versions/{game}",
+    request_body = VersionUpdate,
+    responses(
+        (status = 200, description = "Updated version, assigned to newly added achievements"),
+        (status = 403, description = "Not an admin"),
+    ),
+    security(("admin" = []))
+)]
+#[put("/api/game-versions/{game}")]
+async fn put_game_version(
+    session: Session,
+    game: web::Path<Game>,
+    version_update: web::Json<VersionUpdate>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    if !database::admins::exists(&username, &pool).await? {
+        return Ok(HttpResponse::Forbidden().finish());
+    }
+
+    let game_version = database::game_versions::DbGameVersion {
+        game: game.to_string(),
+        version: version_update.version.clone(),
+    };
+
+    database::game_versions::set(&game_version, &pool).await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[utoipa::path(
+    tag = "game-versions/{game}",
+    delete,
+    path = "/api/game-versions/{game}",
+    responses(
+        (status = 200, description = "Deleted version"),
+        (status = 403, description = "Not an admin"),
+    ),
+    security(("admin" = []))
+)]
+#[delete("/api/game-versions/{game}")]
+async fn delete_game_version(
+    session: Session,
+    game: web::Path<Game>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    if !database::admins::exists(&username, &pool).await? {
+        return Ok(HttpResponse::Forbidden().finish());
+    }
+
+    database::game_versions::delete(*game, &pool).await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
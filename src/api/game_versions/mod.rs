@@ -0,0 +1,60 @@
+mod game;
+
+use actix_web::{get, web, HttpResponse, Responder};
+use serde::Serialize;
+use sqlx::PgPool;
+use utoipa::{OpenApi, ToSchema};
+
+use crate::{api::ApiResult, database, Game};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "game-versions")),
+    paths(get_game_versions),
+    components(schemas(GameVersion, Game))
+)]
+struct ApiDoc;
+
+#[derive(Serialize, ToSchema)]
+struct GameVersion {
+    game: Game,
+    version: String,
+}
+
+impl From<database::game_versions::DbGameVersion> for GameVersion {
+    fn from(db_game_version: database::game_versions::DbGameVersion) -> Self {
+        GameVersion {
+            game: db_game_version.game.parse().unwrap(),
+            version: db_game_version.version,
+        }
+    }
+}
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    let mut openapi = ApiDoc::openapi();
+    openapi.merge(game::openapi());
+    openapi
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_game_versions).configure(game::configure);
+}
+
+#[utoipa::path(
+    tag = "game-versions",
+    get,
+    path = "/api/game-versions",
+    responses(
+        (status = 200, description = "[GameVersion]", body = Vec<GameVersion>),
+    )
+)]
+#[get("/api/game-versions")]
+async fn get_game_versions(pool: web::Data<PgPool>) -> ApiResult<impl Responder> {
+    let game_versions: Vec<_> = database::game_versions::get_all(&pool)
+        .await?
+        .into_iter()
+        .map(GameVersion::from)
+        .collect();
+
+    Ok(HttpResponse::Ok().json(game_versions))
+}
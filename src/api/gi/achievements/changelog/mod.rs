@@ -0,0 +1,66 @@
+use actix_web::{get, web, HttpResponse, Responder};
+use sqlx::PgPool;
+use utoipa::OpenApi;
+
+use crate::{
+    api::{
+        achievements::changelog::{group, Change, Names},
+        ApiResult, LanguageParams,
+    },
+    database,
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "gi/achievements/changelog")),
+    paths(get_gi_achievements_changelog)
+)]
+struct ApiDoc;
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_gi_achievements_changelog);
+}
+
+#[utoipa::path(
+    tag = "gi/achievements/changelog",
+    get,
+    path = "/api/gi/achievements/changelog",
+    params(LanguageParams),
+    responses(
+        (status = 200, description = "[ChangelogUpdate]", body = Vec<ChangelogUpdate>),
+    )
+)]
+#[get("/api/gi/achievements/changelog")]
+async fn get_gi_achievements_changelog(
+    language_params: web::Query<LanguageParams>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let mut names = Names::default();
+
+    for db_achievement in database::gi::achievements::get_all(language_params.lang, &pool).await? {
+        names
+            .series
+            .insert(db_achievement.series, db_achievement.series_name);
+        names
+            .achievements
+            .insert(db_achievement.id, db_achievement.name);
+    }
+
+    let changes = database::gi::achievements_changelog::get_all(&pool)
+        .await?
+        .into_iter()
+        .map(|c| {
+            (
+                c.commit_hash,
+                c.timestamp,
+                Change::new(&c.kind, c.id, &c.change, c.old_value, c.new_value, &names),
+            )
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(group(changes)))
+}
@@ -1,3 +1,4 @@
+mod changelog;
 mod id;
 
 use actix_session::Session;
@@ -81,12 +82,16 @@ impl From<database::gi::achievements::DbAchievement> for Achievement {
 
 pub fn openapi() -> utoipa::openapi::OpenApi {
     let mut openapi = ApiDoc::openapi();
+    openapi.merge(changelog::openapi());
     openapi.merge(id::openapi());
     openapi
 }
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
-    cfg.service(get_gi_achievements).configure(id::configure);
+    // Before id, which would match the changelog path
+    cfg.service(get_gi_achievements)
+        .configure(changelog::configure)
+        .configure(id::configure);
 }
 
 #[utoipa::path(
@@ -1,7 +1,9 @@
 use actix_web::{get, web, HttpResponse, Responder};
+use sqlx::PgPool;
 use utoipa::OpenApi;
 
-use crate::api::{gi::wishes_import::WishesImportInfos, ApiResult};
+use super::WishesImportInfo;
+use crate::{api::ApiResult, database, Game};
 
 #[derive(OpenApi)]
 #[openapi(
@@ -23,19 +25,22 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
     get,
     path = "/api/gi/wishes-import/{uid}",
     responses(
-        (status = 200, description = "WishesImportInfo", body = WishesImportInfo)
+        (status = 200, description = "WishesImportInfo of the latest import", body = WishesImportInfo),
+        (status = 400, description = "No import yet"),
     )
 )]
 #[get("/api/gi/wishes-import/{uid}")]
 async fn get_gi_wishes_import(
     uid: web::Path<i32>,
-    wishes_import_infos: web::Data<WishesImportInfos>,
+    pool: web::Data<PgPool>,
 ) -> ApiResult<impl Responder> {
-    let Some(info) = wishes_import_infos.lock().await.get(&*uid).cloned() else {
+    let Some(db_import_job) =
+        database::import_jobs::get_one_latest_by_game_and_uid(Game::Gi, *uid, &pool).await?
+    else {
         return Ok(HttpResponse::BadRequest().finish());
     };
 
-    let info = info.lock().await.clone();
+    let info = WishesImportInfo::new(db_import_job, &pool).await?;
 
     Ok(HttpResponse::Ok().json(info))
 }
@@ -1,21 +1,22 @@
-use std::io::{BufRead, BufReader};
+use std::collections::{hash_map::Entry, HashMap};
 
 use actix_multipart::form::MultipartForm;
 use actix_session::Session;
 use actix_web::{post, web, HttpResponse, Responder};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
-use utoipa::OpenApi;
+use utoipa::{OpenApi, ToSchema};
 
 use crate::{
-    api::{ApiResult, File},
-    database,
+    api::{csv_lines, ApiResult, File, ImportError, ImportParams},
+    database, Difficulty, Language,
 };
 
 #[derive(OpenApi)]
 #[openapi(
     tags((name = "import-achievements")),
-    paths(import_achievements)
+    paths(import_achievements),
+    components(schemas(ImportReport, ImportError, AchievementDiff, FieldDiff))
 )]
 struct ApiDoc;
 
@@ -49,22 +50,98 @@ struct Achievement {
     missable: Option<String>,
     #[serde(rename = "Forbidden")]
     impossible: Option<String>,
+    #[serde(rename = "Language", default)]
+    language: Option<Language>,
+}
+
+#[derive(Default, Serialize, ToSchema)]
+struct ImportReport {
+    rows: usize,
+    unknown_keys: Vec<i32>,
+    errors: Vec<ImportError>,
+    diffs: Vec<AchievementDiff>,
+    applied: bool,
+}
+
+#[derive(Serialize, ToSchema)]
+struct AchievementDiff {
+    key: i32,
+    language: Language,
+    fields: Vec<FieldDiff>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct FieldDiff {
+    field: String,
+    old: Option<String>,
+    new: Option<String>,
+}
+
+fn diff<T: ToString + PartialEq>(
+    fields: &mut Vec<FieldDiff>,
+    field: &str,
+    old: &Option<T>,
+    new: &Option<T>,
+) {
+    if old != new {
+        fields.push(FieldDiff {
+            field: field.to_string(),
+            old: old.as_ref().map(ToString::to_string),
+            new: new.as_ref().map(ToString::to_string),
+        });
+    }
+}
+
+fn diff_curated(
+    old: &database::achievements::DbCuratedAchievement,
+    new: &database::achievements::DbCuratedAchievement,
+) -> Vec<FieldDiff> {
+    let mut fields = Vec::new();
+
+    diff(&mut fields, "version", &old.version, &new.version);
+    diff(&mut fields, "comment", &old.comment, &new.comment);
+    diff(&mut fields, "reference", &old.reference, &new.reference);
+    diff(&mut fields, "difficulty", &old.difficulty, &new.difficulty);
+    diff(&mut fields, "video", &old.video, &new.video);
+    diff(&mut fields, "gacha", &Some(old.gacha), &Some(new.gacha));
+    diff(
+        &mut fields,
+        "timegated",
+        &Some(old.timegated),
+        &Some(new.timegated),
+    );
+    diff(
+        &mut fields,
+        "missable",
+        &Some(old.missable),
+        &Some(new.missable),
+    );
+    diff(
+        &mut fields,
+        "impossible",
+        &Some(old.impossible),
+        &Some(new.impossible),
+    );
+
+    fields
 }
 
 #[utoipa::path(
     tag = "pinned",
     post,
     path = "/api/import-achievements",
+    params(ImportParams),
     request_body(content = File, content_type = "multipart/form-data"),
     responses(
-        (status = 200, description = "Successfully imported"),
-        (status = 400, description = "Not logged in"),
+        (status = 200, description = "ImportReport, applied unless dry_run is set", body = ImportReport),
+        (status = 400, description = "Not logged in or invalid file, nothing was applied", body = ImportReport),
         (status = 403, description = "Not an admin")
     )
 )]
 #[post("/api/import-achievements")]
 async fn import_achievements(
     session: Session,
+    import_params: web::Query<ImportParams>,
     file: MultipartForm<File>,
     pool: web::Data<PgPool>,
 ) -> ApiResult<impl Responder> {
@@ -76,78 +153,118 @@ async fn import_achievements(
         return Ok(HttpResponse::Forbidden().finish());
     }
 
-    let lines = BufReader::new(&file.file.file)
-        .lines()
-        .map_while(Result::ok)
-        .skip(1)
-        .collect::<Vec<_>>()
-        .join("\n");
+    let lines = csv_lines(&file, 1);
+
+    let mut current: HashMap<Language, HashMap<_, _>> = HashMap::new();
+    let mut rows: HashMap<(Language, i32), usize> = HashMap::new();
+
+    let mut report = ImportReport::default();
+    let mut updates = Vec::new();
 
     let mut reader = csv::Reader::from_reader(lines.as_bytes());
-    for achievement in reader.deserialize() {
-        let achievement: Achievement = achievement?;
-
-        database::achievements::update_version_by_id(achievement.key, &achievement.version, &pool)
-            .await?;
-
-        if let Some(difficulty) = &achievement.difficulty {
-            database::achievements::update_difficulty_by_id(
-                achievement.key,
-                &difficulty.to_lowercase(),
-                &pool,
-            )
-            .await?
-        } else {
-            database::achievements::delete_difficulty_by_id(achievement.key, &pool).await?;
-        }
+    for (i, achievement) in reader.deserialize().enumerate() {
+        let row = i + 1;
+        report.rows += 1;
 
-        if let Some(comment) = &achievement.comment {
-            database::achievements::update_comment_by_id(achievement.key, comment, &pool).await?
-        } else {
-            database::achievements::delete_comment_by_id(achievement.key, &pool).await?;
-        }
+        let achievement: Achievement = match achievement {
+            Ok(achievement) => achievement,
+            Err(e) => {
+                report.errors.push(ImportError {
+                    row,
+                    key: None,
+                    message: e.to_string(),
+                });
+                continue;
+            }
+        };
 
-        if let Some(reference) = &achievement.reference {
-            database::achievements::update_reference_by_id(achievement.key, reference, &pool)
-                .await?
-        } else {
-            database::achievements::delete_reference_by_id(achievement.key, &pool).await?;
+        let language = achievement.language.unwrap_or_default();
+
+        let first = *rows.entry((language, achievement.key)).or_insert(row);
+        if first != row {
+            report.errors.push(ImportError {
+                row,
+                key: Some(achievement.key),
+                message: format!("Duplicate key, already on row {first}"),
+            });
+            continue;
         }
 
-        if let Some(video) = &achievement.video {
-            database::achievements::update_video_by_id(achievement.key, video, &pool).await?
+        let curated = match current.entry(language) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(
+                database::achievements::get_all_curated(language, &pool)
+                    .await?
+                    .into_iter()
+                    .map(|a| (a.id, a))
+                    .collect(),
+            ),
+        };
+
+        let Some(old) = curated.get(&achievement.key) else {
+            report.unknown_keys.push(achievement.key);
+            continue;
+        };
+
+        let difficulty = match &achievement.difficulty {
+            Some(difficulty) => match difficulty.to_lowercase().parse::<Difficulty>() {
+                Ok(difficulty) => Some(difficulty.to_string()),
+                Err(_) => {
+                    report.errors.push(ImportError {
+                        row,
+                        key: Some(achievement.key),
+                        message: format!("Invalid difficulty \"{difficulty}\""),
+                    });
+                    continue;
+                }
+            },
+            None => None,
+        };
+
+        // Translated rows only carry the localized fields
+        let new = if language == Language::En {
+            database::achievements::DbCuratedAchievement {
+                id: achievement.key,
+                version: Some(achievement.version),
+                comment: achievement.comment,
+                reference: achievement.reference,
+                difficulty,
+                video: achievement.video,
+                gacha: achievement.gacha.is_some(),
+                timegated: achievement.timegated.is_some(),
+                missable: achievement.missable.is_some(),
+                impossible: achievement.impossible.is_some(),
+            }
         } else {
-            database::achievements::delete_video_by_id(achievement.key, &pool).await?;
+            database::achievements::DbCuratedAchievement {
+                comment: achievement.comment,
+                reference: achievement.reference,
+                video: achievement.video,
+                ..old.clone()
+            }
+        };
+
+        let fields = diff_curated(old, &new);
+        if !fields.is_empty() {
+            report.diffs.push(AchievementDiff {
+                key: achievement.key,
+                language,
+                fields,
+            });
+            updates.push((language, new));
         }
+    }
 
-        database::achievements::update_gacha_by_id(
-            achievement.key,
-            achievement.gacha.is_some(),
-            &pool,
-        )
-        .await?;
-
-        database::achievements::update_impossible_by_id(
-            achievement.key,
-            achievement.impossible.is_some(),
-            &pool,
-        )
-        .await?;
-
-        database::achievements::update_timegated_by_id(
-            achievement.key,
-            achievement.timegated.is_some(),
-            &pool,
-        )
-        .await?;
-
-        database::achievements::update_missable_by_id(
-            achievement.key,
-            achievement.missable.is_some(),
-            &pool,
-        )
-        .await?;
+    if import_params.dry_run {
+        return Ok(HttpResponse::Ok().json(report));
     }
 
-    Ok(HttpResponse::Ok().finish())
+    if !report.unknown_keys.is_empty() || !report.errors.is_empty() {
+        return Ok(HttpResponse::BadRequest().json(report));
+    }
+
+    database::achievements::update_all_curated(&updates, &pool).await?;
+    report.applied = true;
+
+    Ok(HttpResponse::Ok().json(report))
 }
@@ -1,5 +1,3 @@
-use std::io::{BufRead, BufReader};
-
 use actix_multipart::form::MultipartForm;
 use actix_session::Session;
 use actix_web::{post, web, HttpResponse, Responder};
@@ -8,8 +6,8 @@ use sqlx::PgPool;
 use utoipa::OpenApi;
 
 use crate::{
-    api::{ApiResult, File},
-    database,
+    api::{csv_lines, ApiResult, File},
+    database, Language,
 };
 
 #[derive(OpenApi)]
@@ -41,6 +39,8 @@ struct Achievement {
     timegated: Option<String>,
     #[serde(rename = "Forbidden")]
     impossible: Option<String>,
+    #[serde(rename = "Language", default)]
+    language: Option<Language>,
 }
 
 #[utoipa::path(
@@ -68,16 +68,32 @@ async fn import_gi_achievements(
         return Ok(HttpResponse::Forbidden().finish());
     }
 
-    let lines = BufReader::new(&file.file.file)
-        .lines()
-        .map_while(Result::ok)
-        .collect::<Vec<_>>()
-        .join("\n");
+    let lines = csv_lines(&file, 0);
 
     let mut reader = csv::Reader::from_reader(lines.as_bytes());
     for achievement in reader.deserialize() {
         let achievement: Achievement = achievement?;
 
+        let language = achievement.language.unwrap_or_default();
+
+        if let Some(comment) = &achievement.comment {
+            database::gi::achievements::update_comment_by_id(
+                achievement.key,
+                language,
+                comment,
+                &pool,
+            )
+            .await?
+        } else {
+            database::gi::achievements::delete_comment_by_id(achievement.key, language, &pool)
+                .await?;
+        }
+
+        // Translated rows only carry the localized fields
+        if language != Language::En {
+            continue;
+        }
+
         database::gi::achievements::update_version_by_id(
             achievement.key,
             &achievement.version,
@@ -96,13 +112,6 @@ async fn import_gi_achievements(
             database::gi::achievements::delete_difficulty_by_id(achievement.key, &pool).await?;
         }
 
-        if let Some(comment) = &achievement.comment {
-            database::gi::achievements::update_comment_by_id(achievement.key, comment, &pool)
-                .await?
-        } else {
-            database::gi::achievements::delete_comment_by_id(achievement.key, &pool).await?;
-        }
-
         database::gi::achievements::update_impossible_by_id(
             achievement.key,
             achievement.impossible.as_deref() == Some("Yes"),
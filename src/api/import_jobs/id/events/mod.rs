@@ -0,0 +1,139 @@
+use std::time::{Duration, Instant};
+
+use actix_web::{get, http::header, rt, web, HttpResponse, Responder};
+use chrono::Utc;
+use futures::stream;
+use sqlx::PgPool;
+use utoipa::OpenApi;
+
+use crate::{
+    api::{import_jobs::ImportJob, ApiResult},
+    database,
+    gacha_import::Status,
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "import-jobs/{id}/events")),
+    paths(get_import_job_events)
+)]
+struct ApiDoc;
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_import_job_events);
+}
+
+/// Jobs can run on any instance, so the database is polled instead of the client.
+const INTERVAL: Duration = Duration::from_secs(1);
+/// Polls without a change before a comment is sent to keep proxies from closing the stream.
+const KEEP_ALIVE: usize = 15;
+/// Longer than a job may take, clients reconnect if the job is still going.
+const MAX_LIFETIME: Duration = Duration::from_secs(35 * 60);
+/// Same as the workers, a running job without a heartbeat for this long is abandoned.
+const STALE: chrono::Duration = chrono::Duration::minutes(5);
+
+struct Events {
+    id: i32,
+    pool: PgPool,
+    last: Option<String>,
+    done: bool,
+    started: Instant,
+}
+
+impl Events {
+    /// Waits for the next change of the job, `None` once it is finished, failed or abandoned.
+    async fn next(&mut self) -> anyhow::Result<Option<web::Bytes>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let mut idle = 0;
+
+        loop {
+            if self.last.is_some() {
+                rt::time::sleep(INTERVAL).await;
+            }
+
+            if self.started.elapsed() > MAX_LIFETIME {
+                return Ok(None);
+            }
+
+            let Some(db_import_job) =
+                database::import_jobs::get_one_by_id(self.id, &self.pool).await?
+            else {
+                return Ok(None);
+            };
+
+            if matches!(
+                db_import_job.status.parse()?,
+                Status::Running | Status::Calculating
+            ) && db_import_job.updated_at < Utc::now() - STALE
+            {
+                return Ok(None);
+            }
+
+            let import_job = ImportJob::new(db_import_job, &self.pool).await?;
+
+            let status = import_job.status;
+            let data = serde_json::to_string(&import_job)?;
+
+            if self.last.as_ref() != Some(&data) {
+                self.done = matches!(status, Status::Finished | Status::Error);
+                self.last = Some(data.clone());
+
+                return Ok(Some(web::Bytes::from(format!(
+                    "event: {status}\ndata: {data}\n\n"
+                ))));
+            }
+
+            idle += 1;
+
+            if idle == KEEP_ALIVE {
+                return Ok(Some(web::Bytes::from_static(b": keep-alive\n\n")));
+            }
+        }
+    }
+}
+
+#[utoipa::path(
+    tag = "import-jobs/{id}/events",
+    get,
+    path = "/api/import-jobs/{id}/events",
+    responses(
+        (status = 200, description = "Server-sent ImportJob on every change, the event is named after the status. Ends after finished or error, when the job stops its heartbeat or after 35 minutes", body = ImportJob, content_type = "text/event-stream"),
+        (status = 404, description = "No such job"),
+    )
+)]
+#[get("/api/import-jobs/{id}/events")]
+async fn get_import_job_events(
+    id: web::Path<i32>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    if database::import_jobs::get_one_by_id(*id, &pool)
+        .await?
+        .is_none()
+    {
+        return Ok(HttpResponse::NotFound().finish());
+    }
+
+    let events = Events {
+        id: *id,
+        pool: pool.get_ref().clone(),
+        last: None,
+        done: false,
+        started: Instant::now(),
+    };
+
+    let stream = stream::unfold(events, |mut events| async move {
+        events.next().await.transpose().map(|event| (event, events))
+    });
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header((header::CACHE_CONTROL, "no-cache"))
+        .streaming(stream))
+}
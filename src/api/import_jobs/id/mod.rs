@@ -0,0 +1,45 @@
+mod events;
+
+use actix_web::{get, web, HttpResponse, Responder};
+use sqlx::PgPool;
+use utoipa::OpenApi;
+
+use super::ImportJob;
+use crate::{api::ApiResult, database};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "import-jobs/{id}")),
+    paths(get_import_job)
+)]
+struct ApiDoc;
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    let mut openapi = ApiDoc::openapi();
+    openapi.merge(events::openapi());
+    openapi
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_import_job).configure(events::configure);
+}
+
+#[utoipa::path(
+    tag = "import-jobs/{id}",
+    get,
+    path = "/api/import-jobs/{id}",
+    responses(
+        (status = 200, description = "ImportJob", body = ImportJob),
+        (status = 404, description = "No such job"),
+    )
+)]
+#[get("/api/import-jobs/{id}")]
+async fn get_import_job(id: web::Path<i32>, pool: web::Data<PgPool>) -> ApiResult<impl Responder> {
+    let Some(db_import_job) = database::import_jobs::get_one_by_id(*id, &pool).await? else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+
+    let import_job = ImportJob::new(db_import_job, &pool).await?;
+
+    Ok(HttpResponse::Ok().json(import_job))
+}
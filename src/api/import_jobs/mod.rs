@@ -0,0 +1,103 @@
+mod id;
+
+use std::collections::BTreeMap;
+
+use actix_web::web;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use utoipa::{OpenApi, ToSchema};
+
+use crate::{database, gacha_import::Status, Game};
+
+/// Status of the old per game import endpoints, which only know one error state with a message.
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportInfoStatus {
+    Pending,
+    Calculating,
+    Finished,
+    Error(String),
+}
+
+impl ImportInfoStatus {
+    pub fn new(db_import_job: &database::import_jobs::DbImportJob) -> anyhow::Result<Self> {
+        Ok(match db_import_job.status.parse()? {
+            Status::Pending | Status::Running => ImportInfoStatus::Pending,
+            Status::Calculating => ImportInfoStatus::Calculating,
+            Status::Finished => ImportInfoStatus::Finished,
+            Status::Error => {
+                ImportInfoStatus::Error(db_import_job.error.clone().unwrap_or_default())
+            }
+        })
+    }
+}
+
+/// New pulls per banner of a job.
+pub async fn counts(id: i32, pool: &PgPool) -> anyhow::Result<BTreeMap<String, i32>> {
+    Ok(database::import_jobs_counts::get_by_id(id, pool)
+        .await?
+        .into_iter()
+        .map(|c| (c.gacha_type, c.count))
+        .collect())
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "import-jobs")),
+    components(schemas(ImportJob, Status))
+)]
+struct ApiDoc;
+
+#[derive(Serialize, ToSchema)]
+pub struct ImportJob {
+    id: i32,
+    game: Game,
+    uid: i32,
+    status: Status,
+    /// The banner that is being imported or calculated
+    gacha_type: Option<String>,
+    /// New pulls per banner
+    counts: BTreeMap<String, i32>,
+    /// New pulls of all banners
+    new: i32,
+    error: Option<String>,
+    created_at: DateTime<Utc>,
+    started_at: Option<DateTime<Utc>>,
+    finished_at: Option<DateTime<Utc>>,
+}
+
+impl ImportJob {
+    pub async fn new(
+        db_import_job: database::import_jobs::DbImportJob,
+        pool: &PgPool,
+    ) -> anyhow::Result<Self> {
+        let counts = counts(db_import_job.id, pool).await?;
+
+        let new = counts.values().sum();
+
+        Ok(ImportJob {
+            id: db_import_job.id,
+            game: db_import_job.game.parse()?,
+            uid: db_import_job.uid,
+            status: db_import_job.status.parse()?,
+            gacha_type: db_import_job.gacha_type,
+            counts,
+            new,
+            error: db_import_job.error,
+            created_at: db_import_job.created_at,
+            started_at: db_import_job.started_at,
+            finished_at: db_import_job.finished_at,
+        })
+    }
+}
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    let mut openapi = ApiDoc::openapi();
+    openapi.merge(id::openapi());
+    openapi
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.configure(id::configure);
+}
@@ -1,5 +1,3 @@
-use std::io::{BufRead, BufReader};
-
 use actix_multipart::form::MultipartForm;
 use actix_session::Session;
 use actix_web::{post, web, HttpResponse, Responder};
@@ -8,8 +6,8 @@ use sqlx::PgPool;
 use utoipa::OpenApi;
 
 use crate::{
-    api::{ApiResult, File},
-    database,
+    api::{csv_lines, ApiResult, File},
+    database, Language,
 };
 
 #[derive(OpenApi)]
@@ -49,6 +47,8 @@ struct Achievement {
     missable: Option<String>,
     #[serde(rename = "Forbidden")]
     impossible: Option<String>,
+    #[serde(rename = "Language", default)]
+    language: Option<Language>,
 }
 
 #[utoipa::path(
@@ -76,52 +76,69 @@ async fn import_zzz_achievements(
         return Ok(HttpResponse::Forbidden().finish());
     }
 
-    let lines = BufReader::new(&file.file.file)
-        .lines()
-        .map_while(Result::ok)
-        .collect::<Vec<_>>()
-        .join("\n");
+    let lines = csv_lines(&file, 0);
 
     let mut reader = csv::Reader::from_reader(lines.as_bytes());
     for achievement in reader.deserialize() {
         let achievement: Achievement = achievement?;
 
-        database::zzz::achievements::update_version_by_id(
-            achievement.key,
-            &achievement.version,
-            &pool,
-        )
-        .await?;
+        let language = achievement.language.unwrap_or_default();
 
-        if let Some(difficulty) = &achievement.difficulty {
-            database::zzz::achievements::update_difficulty_by_id(
+        if let Some(comment) = &achievement.comment {
+            database::zzz::achievements::update_comment_by_id(
                 achievement.key,
-                &difficulty.to_lowercase(),
+                language,
+                comment,
                 &pool,
             )
             .await?
         } else {
-            database::zzz::achievements::delete_difficulty_by_id(achievement.key, &pool).await?;
+            database::zzz::achievements::delete_comment_by_id(achievement.key, language, &pool)
+                .await?;
         }
 
-        if let Some(comment) = &achievement.comment {
-            database::zzz::achievements::update_comment_by_id(achievement.key, comment, &pool)
-                .await?
+        if let Some(reference) = &achievement.reference {
+            database::zzz::achievements::update_reference_by_id(
+                achievement.key,
+                language,
+                reference,
+                &pool,
+            )
+            .await?
         } else {
-            database::zzz::achievements::delete_comment_by_id(achievement.key, &pool).await?;
+            database::zzz::achievements::delete_reference_by_id(achievement.key, language, &pool)
+                .await?;
         }
 
-        if let Some(reference) = &achievement.reference {
-            database::zzz::achievements::update_reference_by_id(achievement.key, reference, &pool)
+        if let Some(video) = &achievement.video {
+            database::zzz::achievements::update_video_by_id(achievement.key, language, video, &pool)
                 .await?
         } else {
-            database::zzz::achievements::delete_reference_by_id(achievement.key, &pool).await?;
+            database::zzz::achievements::delete_video_by_id(achievement.key, language, &pool)
+                .await?;
         }
 
-        if let Some(video) = &achievement.video {
-            database::zzz::achievements::update_video_by_id(achievement.key, video, &pool).await?
+        // Translated rows only carry the localized fields
+        if language != Language::En {
+            continue;
+        }
+
+        database::zzz::achievements::update_version_by_id(
+            achievement.key,
+            &achievement.version,
+            &pool,
+        )
+        .await?;
+
+        if let Some(difficulty) = &achievement.difficulty {
+            database::zzz::achievements::update_difficulty_by_id(
+                achievement.key,
+                &difficulty.to_lowercase(),
+                &pool,
+            )
+            .await?
         } else {
-            database::zzz::achievements::delete_video_by_id(achievement.key, &pool).await?;
+            database::zzz::achievements::delete_difficulty_by_id(achievement.key, &pool).await?;
         }
 
         database::zzz::achievements::update_gacha_by_id(
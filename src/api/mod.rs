@@ -1,9 +1,14 @@
+mod achievement_feedback;
 mod achievement_series;
 mod achievements;
+mod banners;
 mod characters;
+mod game;
+mod game_versions;
 mod gi;
 mod import_achievements;
 mod import_gi_achievements;
+mod import_jobs;
 mod import_zzz_achievements;
 mod languages;
 mod light_cones;
@@ -21,10 +26,15 @@ mod warps;
 mod warps_import;
 mod zzz;
 
-use std::env;
+use std::{
+    env,
+    io::{BufRead, BufReader},
+};
 
 use actix_multipart::form::{tempfile::TempFile, MultipartForm};
+use actix_session::Session;
 use actix_web::{guard, web};
+use regex::{Captures, Regex};
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
 use strum::{Display, EnumString};
@@ -33,7 +43,11 @@ use utoipa::{
     IntoParams, Modify, OpenApi, ToSchema,
 };
 
-use crate::{Difficulty, GachaType, GiGachaType, Language, ZzzGachaType};
+use crate::{database, Difficulty, GachaType, Gender, GiGachaType, Language, ZzzGachaType};
+
+lazy_static::lazy_static! {
+    static ref GENDER_RE: Regex = Regex::new(r"\{(M|F)#([^}]*)\}\{(F|M)#([^}]*)\}").unwrap();
+}
 
 type ApiResult<T> = Result<T, Box<dyn std::error::Error>>;
 
@@ -75,6 +89,76 @@ struct File {
     file: TempFile,
 }
 
+/// Shared by the csv imports, nothing is applied on a dry run or with errors.
+#[derive(Deserialize, IntoParams)]
+struct ImportParams {
+    #[serde(default)]
+    dry_run: bool,
+}
+
+#[derive(Serialize, ToSchema)]
+struct ImportError {
+    row: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    key: Option<i32>,
+    message: String,
+}
+
+/// The csv of an upload, `skip` drops the lines above the header.
+fn csv_lines(file: &File, skip: usize) -> String {
+    BufReader::new(&file.file.file)
+        .lines()
+        .map_while(Result::ok)
+        .skip(skip)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Renders the `{M#..}{F#..}` and `{NICKNAME}` templates of hsr texts for the logged in user.
+struct Trailblazer {
+    gender: Option<Gender>,
+    nickname: String,
+}
+
+impl Trailblazer {
+    async fn new(session: &Session, language: Language, pool: &PgPool) -> anyhow::Result<Self> {
+        let preference = if let Ok(Some(username)) = session.get::<String>("username") {
+            database::users_trailblazer::get_one_by_username(&username, pool).await?
+        } else {
+            None
+        };
+
+        let gender: Option<Gender> = preference.as_ref().map(|p| p.gender.parse()).transpose()?;
+
+        let nickname = match preference.and_then(|p| p.nickname) {
+            Some(nickname) => nickname,
+            None => database::trailblazer_text::get_one_by_language(language, pool)
+                .await?
+                .unwrap_or_else(|| "Trailblazer".to_string()),
+        };
+
+        Ok(Self { gender, nickname })
+    }
+
+    /// Without a preference both variants are shown as "male/female".
+    fn render(&self, s: &str) -> String {
+        GENDER_RE
+            .replace_all(s, |c: &Captures| {
+                let (first, second) = (c.get(2).unwrap().as_str(), c.get(4).unwrap().as_str());
+                let first_male = c.get(1).unwrap().as_str() == "M";
+
+                match self.gender {
+                    Some(Gender::Male) if first_male => first.to_string(),
+                    Some(Gender::Male) => second.to_string(),
+                    Some(Gender::Female) if first_male => second.to_string(),
+                    Some(Gender::Female) => first.to_string(),
+                    None => first.to_string() + "/" + second,
+                }
+            })
+            .replace("{NICKNAME}", &self.nickname)
+    }
+}
+
 fn private(ctx: &guard::GuardContext) -> bool {
     Some(env::var("API_KEY").unwrap().as_bytes())
         == ctx.head().headers().get("x-api-key").map(|h| h.as_bytes())
@@ -82,12 +166,17 @@ fn private(ctx: &guard::GuardContext) -> bool {
 
 pub fn openapi() -> utoipa::openapi::OpenApi {
     let mut openapi = ApiDoc::openapi();
+    openapi.merge(achievement_feedback::openapi());
     openapi.merge(achievement_series::openapi());
     openapi.merge(achievements::openapi());
+    openapi.merge(banners::openapi());
     openapi.merge(characters::openapi());
+    openapi.merge(game::openapi());
+    openapi.merge(game_versions::openapi());
     openapi.merge(gi::openapi());
     openapi.merge(import_achievements::openapi());
     openapi.merge(import_gi_achievements::openapi());
+    openapi.merge(import_jobs::openapi());
     openapi.merge(import_zzz_achievements::openapi());
     openapi.merge(languages::openapi());
     openapi.merge(light_cones::openapi());
@@ -108,12 +197,17 @@ pub fn openapi() -> utoipa::openapi::OpenApi {
 }
 
 pub fn configure(cfg: &mut web::ServiceConfig, pool: PgPool) {
-    cfg.configure(achievement_series::configure)
+    cfg.configure(achievement_feedback::configure)
+        .configure(achievement_series::configure)
         .configure(achievements::configure)
+        .configure(banners::configure)
         .configure(characters::configure)
+        .configure(game::configure)
+        .configure(game_versions::configure)
         .configure(gi::configure)
         .configure(import_achievements::configure)
         .configure(import_gi_achievements::configure)
+        .configure(import_jobs::configure)
         .configure(import_zzz_achievements::configure)
         .configure(languages::configure)
         .configure(light_cones::configure)
@@ -16,10 +16,14 @@ use async_rwlock::RwLock;
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
 use strum::IntoEnumIterator;
-use utoipa::OpenApi;
+use utoipa::{IntoParams, OpenApi};
 
 use crate::{
-    api::{private, ApiResult, Language, LanguageParams},
+    api::{
+        private,
+        users::{self, me::ProfileParams},
+        ApiResult, Language, LanguageParams, Trailblazer,
+    },
     database, Difficulty,
 };
 
@@ -57,6 +61,9 @@ struct AchievementTracker {
     currency_count: i32,
     currency_count_current: i32,
     user_count: i64,
+    /// Set when a connected uid's in-game count differs from the tracked count
+    #[serde(default)]
+    achievement_count_drift: bool,
     language: Language,
     versions: Vec<String>,
     series: Vec<Series>,
@@ -76,9 +83,16 @@ struct Series {
 struct AchievementGroup {
     complete: Option<i32>,
     favorite: Option<i32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    lists: Vec<i32>,
     achievements: Vec<Achievement>,
 }
 
+#[derive(Deserialize, IntoParams)]
+struct AchievementTrackerParams {
+    list: Option<i32>,
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 struct Achievement {
     id: i32,
@@ -260,6 +274,7 @@ async fn update_achievement_tracker(
                     .push(AchievementGroup {
                         complete: None,
                         favorite: None,
+                        lists: Vec::new(),
                         achievements: vec![achievement],
                     });
             }
@@ -289,6 +304,7 @@ async fn update_achievement_tracker(
             currency_count,
             currency_count_current: 0,
             user_count,
+            achievement_count_drift: false,
             language,
             versions,
             series,
@@ -314,7 +330,7 @@ async fn update_achievement_tracker(
     tag = "pages",
     get,
     path = "/api/pages/achievement-tracker",
-    params(LanguageParams),
+    params(LanguageParams, AchievementTrackerParams, ProfileParams),
     security(("api_key" = [])),
     responses(
         (status = 200, description = "AchievementTracker"),
@@ -324,6 +340,8 @@ async fn update_achievement_tracker(
 async fn get_achievement_tracker(
     session: Session,
     language_params: web::Query<LanguageParams>,
+    achievement_tracker_params: web::Query<AchievementTrackerParams>,
+    profile_params: web::Query<ProfileParams>,
     achievement_tracker_cache: web::Data<AchievementTrackerCache>,
     pool: web::Data<PgPool>,
 ) -> ApiResult<impl Responder> {
@@ -333,17 +351,99 @@ async fn get_achievement_tracker(
         .await[&language_params.lang]
         .clone();
 
+    // The cache keeps the raw templates
+    let trailblazer = Trailblazer::new(&session, language_params.lang, &pool).await?;
+
+    for series in achievement_tracker.series.iter_mut() {
+        series.series = trailblazer.render(&series.series);
+
+        for achievement in series
+            .achievement_groups
+            .iter_mut()
+            .flat_map(|group| group.achievements.iter_mut())
+        {
+            achievement.series_name = trailblazer.render(&achievement.series_name);
+            achievement.name = trailblazer.render(&achievement.name);
+            achievement.description = trailblazer.render(&achievement.description);
+        }
+    }
+
     if let Ok(Some(username)) = session.get::<String>("username") {
-        let completed = database::users_achievements_completed::get_by_username(&username, &pool)
-            .await?
-            .into_iter()
-            .map(|c| c.id)
-            .collect::<HashSet<_>>();
-        let favorites = database::users_achievements_favorites::get_by_username(&username, &pool)
-            .await?
-            .into_iter()
-            .map(|c| c.id)
-            .collect::<HashSet<_>>();
+        let Some(uid) = profile_params.hsr(&username, &pool).await? else {
+            return Ok(HttpResponse::Forbidden().finish());
+        };
+
+        let completed =
+            database::users_achievements_completed::get_by_username(&username, uid, &pool)
+                .await?
+                .into_iter()
+                .map(|c| c.id)
+                .collect::<HashSet<_>>();
+        let favorites =
+            database::users_achievements_favorites::get_by_username(&username, uid, &pool)
+                .await?
+                .into_iter()
+                .map(|c| c.id)
+                .collect::<HashSet<_>>();
+
+        let mut lists: HashMap<i32, Vec<i32>> = HashMap::new();
+        for entry in
+            database::users_achievement_lists_entries::get_by_username(&username, &pool).await?
+        {
+            lists.entry(entry.id).or_default().push(entry.list);
+        }
+
+        for series in achievement_tracker.series.iter_mut() {
+            for group in series.achievement_groups.iter_mut() {
+                group.lists = group
+                    .achievements
+                    .iter()
+                    .filter_map(|a| lists.get(&a.id))
+                    .flatten()
+                    .copied()
+                    .collect();
+                group.lists.sort_unstable();
+                group.lists.dedup();
+            }
+        }
+
+        if let Some(list) = achievement_tracker_params.list {
+            for series in achievement_tracker.series.iter_mut() {
+                series
+                    .achievement_groups
+                    .retain(|group| group.lists.contains(&list));
+
+                series.achievement_count = series.achievement_groups.len();
+                series.currency_count = series
+                    .achievement_groups
+                    .iter()
+                    .map(|group| group.achievements[0].currency)
+                    .sum();
+            }
+
+            achievement_tracker
+                .series
+                .retain(|series| !series.achievement_groups.is_empty());
+
+            for (series_index, series) in achievement_tracker.series.iter_mut().enumerate() {
+                for group in series.achievement_groups.iter_mut() {
+                    for achievement in group.achievements.iter_mut() {
+                        achievement.series_index = series_index;
+                    }
+                }
+            }
+
+            achievement_tracker.achievement_count = achievement_tracker
+                .series
+                .iter()
+                .map(|series| series.achievement_count)
+                .sum();
+            achievement_tracker.currency_count = achievement_tracker
+                .series
+                .iter()
+                .map(|series| series.currency_count)
+                .sum();
+        }
 
         let mut achievement_count_current_total = 0;
         let mut currency_count_current_total = 0;
@@ -389,6 +489,9 @@ async fn get_achievement_tracker(
 
         achievement_tracker.achievement_count_current = achievement_count_current_total;
         achievement_tracker.currency_count_current = currency_count_current_total;
+
+        achievement_tracker.achievement_count_drift =
+            users::me::achievements::reconciliation::drift(&username, uid, &pool).await?;
     }
 
     Ok(HttpResponse::Ok().json(achievement_tracker))
@@ -6,8 +6,11 @@ use sqlx::PgPool;
 use utoipa::OpenApi;
 
 use crate::{
-    api::{private, ApiResult, LanguageParams},
-    database,
+    api::{
+        pages::{BannerRun, BannerRuns},
+        private, ApiResult, LanguageParams,
+    },
+    database, GiGachaType,
 };
 
 #[derive(OpenApi)]
@@ -32,6 +35,10 @@ struct Wish {
     pull: usize,
     pull_4: usize,
     pull_5: usize,
+    /// The banner it was pulled on, if known
+    banner: Option<i32>,
+    /// Whether a pull of the highest rarity was rated up
+    rate_up: Option<bool>,
     timestamp: DateTime<Utc>,
 }
 
@@ -60,6 +67,8 @@ impl From<database::gi::wishes::DbWish> for Wish {
             pull: 0,
             pull_4: 0,
             pull_5: 0,
+            banner: None,
+            rate_up: None,
         }
     }
 }
@@ -84,6 +93,7 @@ struct Wishes {
     max_pull_4: usize,
     max_pull_5: usize,
     count: usize,
+    banners: Vec<BannerRun>,
     stats: Option<Stats>,
 }
 
@@ -238,6 +248,12 @@ async fn get_wish_tracker(
     let mut character_pull_4 = 0;
     let mut character_pull_5 = 0;
 
+    let mut character_banner_runs = BannerRuns::new(
+        database::gi::banners::get_by_gacha_type(&GiGachaType::Character.to_string(), &pool)
+            .await?,
+        5,
+    );
+
     for wish in database::gi::wishes::character::get_by_uid(uid, language, &pool).await? {
         let mut wish: Wish = wish.into();
 
@@ -249,6 +265,9 @@ async fn get_wish_tracker(
         wish.pull_4 = character_pull_4;
         wish.pull_5 = character_pull_5;
 
+        (wish.banner, wish.rate_up) =
+            character_banner_runs.push(wish.item_id, wish.rarity, wish.timestamp, wish.pull_5);
+
         match wish.rarity {
             4 => character_pull_4 = 0,
             5 => character_pull_5 = 0,
@@ -271,6 +290,7 @@ async fn get_wish_tracker(
     };
 
     character.count = character.wishes.len();
+    character.banners = character_banner_runs.runs();
     // Character
 
     // Weapon
@@ -279,6 +299,11 @@ async fn get_wish_tracker(
     let mut weapon_pull_4 = 0;
     let mut weapon_pull_5 = 0;
 
+    let mut weapon_banner_runs = BannerRuns::new(
+        database::gi::banners::get_by_gacha_type(&GiGachaType::Weapon.to_string(), &pool).await?,
+        5,
+    );
+
     for wish in database::gi::wishes::weapon::get_by_uid(uid, language, &pool).await? {
         let mut wish: Wish = wish.into();
 
@@ -290,6 +315,9 @@ async fn get_wish_tracker(
         wish.pull_4 = weapon_pull_4;
         wish.pull_5 = weapon_pull_5;
 
+        (wish.banner, wish.rate_up) =
+            weapon_banner_runs.push(wish.item_id, wish.rarity, wish.timestamp, wish.pull_5);
+
         match wish.rarity {
             4 => weapon_pull_4 = 0,
             5 => weapon_pull_5 = 0,
@@ -312,6 +340,7 @@ async fn get_wish_tracker(
     };
 
     weapon.count = weapon.wishes.len();
+    weapon.banners = weapon_banner_runs.runs();
     // Weapon
 
     // Chronicled
@@ -320,6 +349,12 @@ async fn get_wish_tracker(
     let mut chronicled_pull_4 = 0;
     let mut chronicled_pull_5 = 0;
 
+    let mut chronicled_banner_runs = BannerRuns::new(
+        database::gi::banners::get_by_gacha_type(&GiGachaType::Chronicled.to_string(), &pool)
+            .await?,
+        5,
+    );
+
     for wish in database::gi::wishes::chronicled::get_by_uid(uid, language, &pool).await? {
         let mut wish: Wish = wish.into();
 
@@ -331,6 +366,9 @@ async fn get_wish_tracker(
         wish.pull_4 = chronicled_pull_4;
         wish.pull_5 = chronicled_pull_5;
 
+        (wish.banner, wish.rate_up) =
+            chronicled_banner_runs.push(wish.item_id, wish.rarity, wish.timestamp, wish.pull_5);
+
         match wish.rarity {
             4 => chronicled_pull_4 = 0,
             5 => chronicled_pull_5 = 0,
@@ -353,6 +391,7 @@ async fn get_wish_tracker(
     };
 
     chronicled.count = chronicled.wishes.len();
+    chronicled.banners = chronicled_banner_runs.runs();
     // Chronicled
 
     {
@@ -6,9 +6,13 @@ mod warp_tracker;
 mod zzz;
 
 use actix_web::web;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
 use sqlx::PgPool;
 use utoipa::OpenApi;
 
+use crate::database;
+
 #[derive(OpenApi)]
 #[openapi(tags((name = "pages")))]
 struct ApiDoc;
@@ -32,3 +36,106 @@ pub fn configure(cfg: &mut web::ServiceConfig, pool: PgPool) {
         .configure(|sc| gi::configure(sc, pool.clone()))
         .configure(|sc| zzz::configure(sc, pool.clone()));
 }
+
+/// A banner and the pulls a uid made on it.
+#[derive(Serialize)]
+struct BannerRun {
+    id: i32,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    rate_up_5: Vec<i32>,
+    rate_up_4: Vec<i32>,
+    count: usize,
+    count_4: usize,
+    count_5: usize,
+    /// Pity before the first pull on the banner
+    pity_in: usize,
+    /// Pity after the last pull on the banner
+    pity_out: usize,
+}
+
+/// Assigns the pulls of one gacha type to the banners they were made on.
+struct BannerRuns {
+    runs: Vec<BannerRun>,
+    /// The highest rarity, zzz counts one lower
+    rarity_5: i32,
+}
+
+impl BannerRuns {
+    fn new(banners: Vec<database::banners::DbBanner>, rarity_5: i32) -> Self {
+        let runs = banners
+            .into_iter()
+            .map(|b| BannerRun {
+                id: b.id,
+                start: b.start_time,
+                end: b.end_time,
+                rate_up_5: b.rate_up_5,
+                rate_up_4: b.rate_up_4,
+                count: 0,
+                count_4: 0,
+                count_5: 0,
+                pity_in: 0,
+                pity_out: 0,
+            })
+            .collect();
+
+        Self { runs, rarity_5 }
+    }
+
+    /// `pull_5` includes this pull. Returns the banner and, for the highest rarity, whether it was rated up.
+    ///
+    /// Pulls during overlapping banners go to the one rating up the item.
+    fn push(
+        &mut self,
+        item: i32,
+        rarity: i32,
+        timestamp: DateTime<Utc>,
+        pull_5: usize,
+    ) -> (Option<i32>, Option<bool>) {
+        let active: Vec<_> = (0..self.runs.len())
+            .filter(|&i| self.runs[i].start <= timestamp && timestamp < self.runs[i].end)
+            .collect();
+
+        let Some(&first) = active.first() else {
+            return (None, None);
+        };
+
+        let rate_up = active
+            .iter()
+            .any(|&i| self.runs[i].rate_up_5.contains(&item));
+
+        let i = active
+            .iter()
+            .copied()
+            .find(|&i| {
+                self.runs[i].rate_up_5.contains(&item) || self.runs[i].rate_up_4.contains(&item)
+            })
+            .unwrap_or(first);
+
+        let run = &mut self.runs[i];
+
+        if run.count == 0 {
+            run.pity_in = pull_5 - 1;
+        }
+
+        run.count += 1;
+
+        if rarity == self.rarity_5 {
+            run.count_5 += 1;
+            run.pity_out = 0;
+        } else {
+            if rarity == self.rarity_5 - 1 {
+                run.count_4 += 1;
+            }
+
+            run.pity_out = pull_5;
+        }
+
+        (Some(run.id), (rarity == self.rarity_5).then_some(rate_up))
+    }
+
+    /// Only the banners that were pulled on.
+    fn runs(self) -> Vec<BannerRun> {
+        self.runs.into_iter().filter(|r| r.count > 0).collect()
+    }
+}
@@ -6,8 +6,11 @@ use sqlx::PgPool;
 use utoipa::OpenApi;
 
 use crate::{
-    api::{private, ApiResult, LanguageParams},
-    database,
+    api::{
+        pages::{BannerRun, BannerRuns},
+        private, ApiResult, LanguageParams,
+    },
+    database, GachaType,
 };
 
 #[derive(OpenApi)]
@@ -32,6 +35,10 @@ struct Warp {
     pull: usize,
     pull_4: usize,
     pull_5: usize,
+    /// The banner it was pulled on, if known
+    banner: Option<i32>,
+    /// Whether a pull of the highest rarity was rated up
+    rate_up: Option<bool>,
     timestamp: DateTime<Utc>,
 }
 
@@ -60,6 +67,8 @@ impl From<database::warps::DbWarp> for Warp {
             pull: 0,
             pull_4: 0,
             pull_5: 0,
+            banner: None,
+            rate_up: None,
         }
     }
 }
@@ -83,6 +92,7 @@ struct Warps {
     max_pull_4: usize,
     max_pull_5: usize,
     count: usize,
+    banners: Vec<BannerRun>,
     stats: Option<Stats>,
 }
 
@@ -225,6 +235,11 @@ async fn get_warp_tracker(
     let mut special_pull_4 = 0;
     let mut special_pull_5 = 0;
 
+    let mut special_banner_runs = BannerRuns::new(
+        database::banners::get_by_gacha_type(&GachaType::Special.to_string(), &pool).await?,
+        5,
+    );
+
     for warp in database::warps::special::get_by_uid(uid, language, &pool).await? {
         let mut warp: Warp = warp.into();
 
@@ -236,6 +251,9 @@ async fn get_warp_tracker(
         warp.pull_4 = special_pull_4;
         warp.pull_5 = special_pull_5;
 
+        (warp.banner, warp.rate_up) =
+            special_banner_runs.push(warp.item_id, warp.rarity, warp.timestamp, warp.pull_5);
+
         match warp.rarity {
             4 => special_pull_4 = 0,
             5 => special_pull_5 = 0,
@@ -258,6 +276,7 @@ async fn get_warp_tracker(
     };
 
     special.count = special.warps.len();
+    special.banners = special_banner_runs.runs();
     // Special
 
     // Lc
@@ -266,6 +285,11 @@ async fn get_warp_tracker(
     let mut lc_pull_4 = 0;
     let mut lc_pull_5 = 0;
 
+    let mut lc_banner_runs = BannerRuns::new(
+        database::banners::get_by_gacha_type(&GachaType::Lc.to_string(), &pool).await?,
+        5,
+    );
+
     for warp in database::warps::lc::get_by_uid(uid, language, &pool).await? {
         let mut warp: Warp = warp.into();
 
@@ -277,6 +301,9 @@ async fn get_warp_tracker(
         warp.pull_4 = lc_pull_4;
         warp.pull_5 = lc_pull_5;
 
+        (warp.banner, warp.rate_up) =
+            lc_banner_runs.push(warp.item_id, warp.rarity, warp.timestamp, warp.pull_5);
+
         match warp.rarity {
             4 => lc_pull_4 = 0,
             5 => lc_pull_5 = 0,
@@ -299,6 +326,7 @@ async fn get_warp_tracker(
     };
 
     lc.count = lc.warps.len();
+    lc.banners = lc_banner_runs.runs();
     // Lc
 
     {
@@ -6,8 +6,11 @@ use sqlx::PgPool;
 use utoipa::OpenApi;
 
 use crate::{
-    api::{private, ApiResult, LanguageParams},
-    database,
+    api::{
+        pages::{BannerRun, BannerRuns},
+        private, ApiResult, LanguageParams,
+    },
+    database, ZzzGachaType,
 };
 
 #[derive(OpenApi)]
@@ -32,6 +35,10 @@ struct Signal {
     pull: usize,
     pull_4: usize,
     pull_5: usize,
+    /// The banner it was pulled on, if known
+    banner: Option<i32>,
+    /// Whether a pull of the highest rarity was rated up
+    rate_up: Option<bool>,
     timestamp: DateTime<Utc>,
 }
 
@@ -67,6 +74,8 @@ impl From<database::zzz::signals::DbSignal> for Signal {
             pull: 0,
             pull_4: 0,
             pull_5: 0,
+            banner: None,
+            rate_up: None,
         }
     }
 }
@@ -89,6 +98,7 @@ struct Signals {
     max_pull_4: usize,
     max_pull_5: usize,
     count: usize,
+    banners: Vec<BannerRun>,
     stats: Stats,
 }
 
@@ -203,6 +213,12 @@ async fn get_signal_tracker(
     let mut special_pull_a = 0;
     let mut special_pull_s = 0;
 
+    let mut special_banner_runs = BannerRuns::new(
+        database::zzz::banners::get_by_gacha_type(&ZzzGachaType::Special.to_string(), &pool)
+            .await?,
+        4,
+    );
+
     for signal in database::zzz::signals::special::get_by_uid(uid, language, &pool).await? {
         let mut signal: Signal = signal.into();
 
@@ -214,6 +230,13 @@ async fn get_signal_tracker(
         signal.pull_4 = special_pull_a;
         signal.pull_5 = special_pull_s;
 
+        (signal.banner, signal.rate_up) = special_banner_runs.push(
+            signal.item_id,
+            signal.rarity,
+            signal.timestamp,
+            signal.pull_5,
+        );
+
         match signal.rarity {
             3 => special_pull_a = 0,
             4 => {
@@ -239,6 +262,7 @@ async fn get_signal_tracker(
     };
 
     special.count = special.signals.len();
+    special.banners = special_banner_runs.runs();
     // Special
 
     // WEngine
@@ -247,6 +271,12 @@ async fn get_signal_tracker(
     let mut w_engine_pull_a = 0;
     let mut w_engine_pull_s = 0;
 
+    let mut w_engine_banner_runs = BannerRuns::new(
+        database::zzz::banners::get_by_gacha_type(&ZzzGachaType::WEngine.to_string(), &pool)
+            .await?,
+        4,
+    );
+
     for signal in database::zzz::signals::w_engine::get_by_uid(uid, language, &pool).await? {
         let mut signal: Signal = signal.into();
 
@@ -258,6 +288,13 @@ async fn get_signal_tracker(
         signal.pull_4 = w_engine_pull_a;
         signal.pull_5 = w_engine_pull_s;
 
+        (signal.banner, signal.rate_up) = w_engine_banner_runs.push(
+            signal.item_id,
+            signal.rarity,
+            signal.timestamp,
+            signal.pull_5,
+        );
+
         match signal.rarity {
             3 => w_engine_pull_a = 0,
             4 => {
@@ -283,6 +320,7 @@ async fn get_signal_tracker(
     };
 
     w_engine.count = w_engine.signals.len();
+    w_engine.banners = w_engine_banner_runs.runs();
     // WEngine
 
     // Bangboo
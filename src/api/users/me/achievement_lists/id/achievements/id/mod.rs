@@ -0,0 +1,97 @@
+use actix_session::Session;
+use actix_web::{delete, put, web, HttpResponse, Responder};
+use sqlx::PgPool;
+use utoipa::OpenApi;
+
+use crate::{api::ApiResult, database};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "users/me/achievement-lists/{id}/achievements/{achievement}")),
+    paths(put_user_achievement_list_achievement, delete_user_achievement_list_achievement)
+)]
+struct ApiDoc;
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(put_user_achievement_list_achievement)
+        .service(delete_user_achievement_list_achievement);
+}
+
+#[utoipa::path(
+    tag = "users/me/achievement-lists/{id}/achievements/{achievement}",
+    put,
+    path = "/api/users/me/achievement-lists/{id}/achievements/{achievement}",
+    responses(
+        (status = 200, description = "Appended the achievement to the list"),
+        (status = 400, description = "Not logged in"),
+        (status = 404, description = "No such list or achievement"),
+    )
+)]
+#[put("/api/users/me/achievement-lists/{id}/achievements/{achievement}")]
+async fn put_user_achievement_list_achievement(
+    session: Session,
+    path: web::Path<(i32, i32)>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    let (id, achievement) = path.into_inner();
+
+    if database::users_achievement_lists::get_one(&username, id, &pool)
+        .await?
+        .is_none()
+    {
+        return Ok(HttpResponse::NotFound().finish());
+    }
+
+    if database::achievements::get_existing_ids(&[achievement], &pool)
+        .await?
+        .is_empty()
+    {
+        return Ok(HttpResponse::NotFound().finish());
+    }
+
+    database::users_achievement_lists_entries::add(id, achievement, &pool).await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[utoipa::path(
+    tag = "users/me/achievement-lists/{id}/achievements/{achievement}",
+    delete,
+    path = "/api/users/me/achievement-lists/{id}/achievements/{achievement}",
+    responses(
+        (status = 200, description = "Removed the achievement from the list"),
+        (status = 400, description = "Not logged in"),
+        (status = 404, description = "No such list"),
+    )
+)]
+#[delete("/api/users/me/achievement-lists/{id}/achievements/{achievement}")]
+async fn delete_user_achievement_list_achievement(
+    session: Session,
+    path: web::Path<(i32, i32)>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    let (id, achievement) = path.into_inner();
+
+    if database::users_achievement_lists::get_one(&username, id, &pool)
+        .await?
+        .is_none()
+    {
+        return Ok(HttpResponse::NotFound().finish());
+    }
+
+    database::users_achievement_lists_entries::delete(id, achievement, &pool).await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
@@ -3,7 +3,10 @@ use actix_web::{delete, put, web, HttpResponse, Responder};
 use sqlx::PgPool;
 use utoipa::OpenApi;
 
-use crate::{api::ApiResult, database};
+use crate::{
+    api::{users::me::ProfileParams, ApiResult},
+    database,
+};
 
 #[derive(OpenApi)]
 #[openapi(
@@ -25,14 +28,17 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
     tag = "users/me/achievements/completed/{id}",
     put,
     path = "/api/users/me/achievements/completed/{id}",
+    params(ProfileParams),
     responses(
         (status = 200, description = "Successful add of the achievement"),
         (status = 400, description = "Not logged in"),
+        (status = 403, description = "Uid not connected"),
     )
 )]
 #[put("/api/users/me/achievements/completed/{id}")]
 async fn put_user_achievement_completed(
     session: Session,
+    profile_params: web::Query<ProfileParams>,
     id: web::Path<i32>,
     pool: web::Data<PgPool>,
 ) -> ApiResult<impl Responder> {
@@ -40,9 +46,13 @@ async fn put_user_achievement_completed(
         return Ok(HttpResponse::BadRequest().finish());
     };
 
+    let Some(uid) = profile_params.hsr(&username, &pool).await? else {
+        return Ok(HttpResponse::Forbidden().finish());
+    };
+
     let id = *id;
     let db_complete =
-        database::users_achievements_completed::DbUserAchievementCompleted { username, id };
+        database::users_achievements_completed::DbUserAchievementCompleted { username, uid, id };
     database::users_achievements_completed::add(&db_complete, &pool).await?;
 
     Ok(HttpResponse::Ok().finish())
@@ -52,14 +62,17 @@ async fn put_user_achievement_completed(
     tag = "users/me/achievements/completed/{id}",
     delete,
     path = "/api/users/me/achievements/completed/{id}",
+    params(ProfileParams),
     responses(
         (status = 200, description = "Successful delete of the achievement"),
         (status = 400, description = "Not logged in"),
+        (status = 403, description = "Uid not connected"),
     )
 )]
 #[delete("/api/users/me/achievements/completed/{id}")]
 async fn delete_user_achievement_completed(
     session: Session,
+    profile_params: web::Query<ProfileParams>,
     id: web::Path<i32>,
     pool: web::Data<PgPool>,
 ) -> ApiResult<impl Responder> {
@@ -67,10 +80,14 @@ async fn delete_user_achievement_completed(
         return Ok(HttpResponse::BadRequest().finish());
     };
 
+    let Some(uid) = profile_params.hsr(&username, &pool).await? else {
+        return Ok(HttpResponse::Forbidden().finish());
+    };
+
     let id = *id;
 
     let db_complete =
-        database::users_achievements_completed::DbUserAchievementCompleted { username, id };
+        database::users_achievements_completed::DbUserAchievementCompleted { username, uid, id };
 
     database::users_achievements_completed::delete(&db_complete, &pool).await?;
 
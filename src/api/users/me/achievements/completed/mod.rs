@@ -5,7 +5,10 @@ use actix_web::{delete, get, put, web, HttpResponse, Responder};
 use sqlx::PgPool;
 use utoipa::OpenApi;
 
-use crate::{api::ApiResult, database};
+use crate::{
+    api::{users::me::ProfileParams, ApiResult},
+    database,
+};
 
 #[derive(OpenApi)]
 #[openapi(
@@ -31,22 +34,29 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
     tag = "users/me/achievements/completed",
     get,
     path = "/api/users/me/achievements/completed",
+    params(ProfileParams),
     responses(
         (status = 200, description = "Achievement ids", body = Vec<i64>),
         (status = 400, description = "Not logged in"),
+        (status = 403, description = "Uid not connected"),
     )
 )]
 #[get("/api/users/me/achievements/completed")]
 async fn get_user_achievements_completed(
     session: Session,
+    profile_params: web::Query<ProfileParams>,
     pool: web::Data<PgPool>,
 ) -> ApiResult<impl Responder> {
     let Ok(Some(username)) = session.get::<String>("username") else {
         return Ok(HttpResponse::BadRequest().finish());
     };
 
+    let Some(uid) = profile_params.hsr(&username, &pool).await? else {
+        return Ok(HttpResponse::Forbidden().finish());
+    };
+
     let completed: Vec<_> =
-        database::users_achievements_completed::get_by_username(&username, &pool)
+        database::users_achievements_completed::get_by_username(&username, uid, &pool)
             .await?
             .iter()
             .map(|c| c.id)
@@ -59,15 +69,18 @@ async fn get_user_achievements_completed(
     tag = "users/me/achievements/completed",
     put,
     path = "/api/users/me/achievements/completed",
+    params(ProfileParams),
     request_body = Vec<i64>,
     responses(
         (status = 200, description = "Success"),
         (status = 400, description = "Not logged in"),
+        (status = 403, description = "Uid not connected"),
     )
 )]
 #[put("/api/users/me/achievements/completed")]
 async fn put_user_achievements_completed(
     session: Session,
+    profile_params: web::Query<ProfileParams>,
     ids: web::Json<Vec<i32>>,
     pool: web::Data<PgPool>,
 ) -> ApiResult<impl Responder> {
@@ -75,8 +88,15 @@ async fn put_user_achievements_completed(
         return Ok(HttpResponse::BadRequest().finish());
     };
 
-    let mut complete =
-        database::users_achievements_completed::DbUserAchievementCompleted { username, id: 0 };
+    let Some(uid) = profile_params.hsr(&username, &pool).await? else {
+        return Ok(HttpResponse::Forbidden().finish());
+    };
+
+    let mut complete = database::users_achievements_completed::DbUserAchievementCompleted {
+        username,
+        uid,
+        id: 0,
+    };
 
     for id in ids.0 {
         complete.id = id;
@@ -91,15 +111,18 @@ async fn put_user_achievements_completed(
     tag = "users/me/achievements/completed",
     delete,
     path = "/api/users/me/achievements/completed",
+    params(ProfileParams),
     request_body = Vec<i64>,
     responses(
         (status = 200, description = "Success"),
         (status = 400, description = "Not logged in"),
+        (status = 403, description = "Uid not connected"),
     )
 )]
 #[delete("/api/users/me/achievements/completed")]
 async fn delete_user_achievements_completed(
     session: Session,
+    profile_params: web::Query<ProfileParams>,
     ids: web::Json<Vec<i32>>,
     pool: web::Data<PgPool>,
 ) -> ApiResult<impl Responder> {
@@ -107,8 +130,15 @@ async fn delete_user_achievements_completed(
         return Ok(HttpResponse::BadRequest().finish());
     };
 
-    let mut complete =
-        database::users_achievements_completed::DbUserAchievementCompleted { username, id: 0 };
+    let Some(uid) = profile_params.hsr(&username, &pool).await? else {
+        return Ok(HttpResponse::Forbidden().finish());
+    };
+
+    let mut complete = database::users_achievements_completed::DbUserAchievementCompleted {
+        username,
+        uid,
+        id: 0,
+    };
 
     for id in ids.0 {
         complete.id = id;
@@ -5,7 +5,10 @@ use actix_web::{get, put, web, HttpResponse, Responder};
 use sqlx::PgPool;
 use utoipa::OpenApi;
 
-use crate::{api::ApiResult, database};
+use crate::{
+    api::{users::me::ProfileParams, ApiResult},
+    database,
+};
 
 #[derive(OpenApi)]
 #[openapi(
@@ -30,22 +33,29 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
     tag = "users/me/achievements/favorites",
     get,
     path = "/api/users/me/achievements/favorites",
+    params(ProfileParams),
     responses(
         (status = 200, description = "Achievement ids", body = Vec<i64>),
         (status = 400, description = "Not logged in"),
+        (status = 403, description = "Uid not connected"),
     )
 )]
 #[get("/api/users/me/achievements/favorites")]
 async fn get_user_achievements_favorites(
     session: Session,
+    profile_params: web::Query<ProfileParams>,
     pool: web::Data<PgPool>,
 ) -> ApiResult<impl Responder> {
     let Ok(Some(username)) = session.get::<String>("username") else {
         return Ok(HttpResponse::BadRequest().finish());
     };
 
+    let Some(uid) = profile_params.hsr(&username, &pool).await? else {
+        return Ok(HttpResponse::Forbidden().finish());
+    };
+
     let favorites: Vec<_> =
-        database::users_achievements_favorites::get_by_username(&username, &pool)
+        database::users_achievements_favorites::get_by_username(&username, uid, &pool)
             .await?
             .iter()
             .map(|c| c.id)
@@ -58,15 +68,18 @@ async fn get_user_achievements_favorites(
     tag = "users/me/achievements/favorites",
     put,
     path = "/api/users/me/achievements/favorites",
+    params(ProfileParams),
     request_body = Vec<i64>,
     responses(
         (status = 200, description = "Success"),
         (status = 400, description = "Not logged in"),
+        (status = 403, description = "Uid not connected"),
     )
 )]
 #[put("/api/users/me/achievements/favorites")]
 async fn put_user_achievements_favorites(
     session: Session,
+    profile_params: web::Query<ProfileParams>,
     ids: web::Json<Vec<i32>>,
     pool: web::Data<PgPool>,
 ) -> ApiResult<impl Responder> {
@@ -74,8 +87,15 @@ async fn put_user_achievements_favorites(
         return Ok(HttpResponse::BadRequest().finish());
     };
 
-    let mut favorite =
-        database::users_achievements_favorites::DbUserAchievementFavorite { username, id: 0 };
+    let Some(uid) = profile_params.hsr(&username, &pool).await? else {
+        return Ok(HttpResponse::Forbidden().finish());
+    };
+
+    let mut favorite = database::users_achievements_favorites::DbUserAchievementFavorite {
+        username,
+        uid,
+        id: 0,
+    };
 
     for id in ids.0 {
         favorite.id = id;
@@ -1,5 +1,11 @@
 mod completed;
 mod favorites;
+mod id;
+mod notes;
+pub mod rarest;
+pub mod recommendations;
+pub mod reconciliation;
+mod sync;
 
 use actix_web::web;
 use utoipa::OpenApi;
@@ -12,10 +18,22 @@ pub fn openapi() -> utoipa::openapi::OpenApi {
     let mut openapi = ApiDoc::openapi();
     openapi.merge(completed::openapi());
     openapi.merge(favorites::openapi());
+    openapi.merge(id::openapi());
+    openapi.merge(notes::openapi());
+    openapi.merge(rarest::openapi());
+    openapi.merge(reconciliation::openapi());
+    openapi.merge(recommendations::openapi());
+    openapi.merge(sync::openapi());
     openapi
 }
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.configure(completed::configure)
-        .configure(favorites::configure);
+        .configure(favorites::configure)
+        .configure(id::configure)
+        .configure(notes::configure)
+        .configure(rarest::configure)
+        .configure(reconciliation::configure)
+        .configure(recommendations::configure)
+        .configure(sync::configure);
 }
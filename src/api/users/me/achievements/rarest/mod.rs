@@ -0,0 +1,141 @@
+use std::collections::HashSet;
+
+use actix_session::Session;
+use actix_web::{get, web, HttpResponse, Responder};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use utoipa::{IntoParams, OpenApi, ToSchema};
+
+use crate::{
+    api::{users::me::ProfileParams, ApiResult, LanguageParams, Trailblazer},
+    database,
+};
+
+/// Same threshold the stats use to count users.
+pub const THRESHOLD: i64 = 100;
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "users/me/achievements/rarest")),
+    paths(get_user_achievements_rarest),
+    components(schemas(Rarest, RareAchievement))
+)]
+struct ApiDoc;
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_user_achievements_rarest);
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct RarestParams {
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct Rarest {
+    completed: usize,
+    /// Percentage of tracked profiles with less completions, null below the threshold
+    percentile: Option<f64>,
+    achievements: Vec<RareAchievement>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct RareAchievement {
+    pub id: i32,
+    pub name: String,
+    pub series_name: String,
+    pub percent: f64,
+}
+
+/// Picks the `limit` completed achievements with the lowest global completion rate.
+pub fn rarest(
+    achievements: Vec<RareAchievement>,
+    completed: &HashSet<i32>,
+    users: i64,
+    users_below: Option<i64>,
+    limit: usize,
+) -> Rarest {
+    let mut achievements: Vec<_> = achievements
+        .into_iter()
+        .filter(|a| completed.contains(&a.id))
+        .collect();
+
+    achievements.sort_by(|a, b| a.percent.total_cmp(&b.percent));
+    achievements.truncate(limit);
+
+    let percentile = users_below
+        .filter(|_| users > 0)
+        .map(|below| below as f64 / users as f64 * 100.0);
+
+    Rarest {
+        completed: completed.len(),
+        percentile,
+        achievements,
+    }
+}
+
+#[utoipa::path(
+    tag = "users/me/achievements/rarest",
+    get,
+    path = "/api/users/me/achievements/rarest",
+    params(LanguageParams, ProfileParams, RarestParams),
+    responses(
+        (status = 200, description = "Rarest", body = Rarest),
+        (status = 400, description = "Not logged in"),
+        (status = 403, description = "Uid not connected"),
+    )
+)]
+#[get("/api/users/me/achievements/rarest")]
+async fn get_user_achievements_rarest(
+    session: Session,
+    language_params: web::Query<LanguageParams>,
+    profile_params: web::Query<ProfileParams>,
+    rarest_params: web::Query<RarestParams>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    let Some(uid) = profile_params.hsr(&username, &pool).await? else {
+        return Ok(HttpResponse::Forbidden().finish());
+    };
+
+    let completed = database::users_achievements_completed::get_by_username(&username, uid, &pool)
+        .await?
+        .into_iter()
+        .map(|c| c.id)
+        .collect();
+
+    let users = database::users_achievements_completed::count_users(THRESHOLD, &pool).await?;
+    let users_below =
+        database::users_achievements_completed::count_users_below(THRESHOLD, &username, uid, &pool)
+            .await?;
+
+    let trailblazer = Trailblazer::new(&session, language_params.lang, &pool).await?;
+
+    let achievements = database::achievements::get_all(language_params.lang, &pool)
+        .await?
+        .into_iter()
+        .map(|a| RareAchievement {
+            id: a.id,
+            name: trailblazer.render(&a.name),
+            series_name: trailblazer.render(&a.series_name),
+            percent: a.percent,
+        })
+        .collect();
+
+    let rarest = rarest(
+        achievements,
+        &completed,
+        users,
+        users_below,
+        rarest_params.limit.unwrap_or(10),
+    );
+
+    Ok(HttpResponse::Ok().json(rarest))
+}
@@ -0,0 +1,290 @@
+use std::collections::HashSet;
+
+use actix_session::Session;
+use actix_web::{get, web, HttpResponse, Responder};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use utoipa::{IntoParams, OpenApi, ToSchema};
+
+use crate::{
+    api::{users::me::ProfileParams, ApiResult, LanguageParams, Trailblazer},
+    database, Difficulty,
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "users/me/achievements/recommendations")),
+    paths(get_user_achievements_recommendations),
+    components(schemas(Recommendation, RecommendationReason))
+)]
+struct ApiDoc;
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_user_achievements_recommendations);
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct RecommendationsParams {
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct Recommendation {
+    id: i32,
+    name: String,
+    series_name: String,
+    currency: i32,
+    percent: f64,
+    score: f64,
+    reasons: Vec<RecommendationReason>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RecommendationReason {
+    Common { percent: f64 },
+    Rare { percent: f64 },
+    Difficulty { difficulty: Difficulty },
+    Reward { currency: i32 },
+    Timegated,
+    Missable,
+    OwnedCharacter { character: i32, name: String },
+    MissingCharacter { character: i32, name: String },
+    UnknownCharacter,
+}
+
+/// The fields of an achievement that the recommendations are based on.
+pub struct Candidate {
+    pub id: i32,
+    pub name: String,
+    pub description: String,
+    pub comment: Option<String>,
+    pub series_name: String,
+    pub currency: i32,
+    pub percent: f64,
+    pub difficulty: Option<String>,
+    pub timegated: bool,
+    pub missable: bool,
+    pub gacha: bool,
+    pub set: Option<i32>,
+}
+
+pub struct OwnedCharacter {
+    pub id: i32,
+    pub name: String,
+    pub owned: bool,
+}
+
+/// Ranks all candidates that are not part of a completed set and returns the best `limit`.
+pub fn recommend(
+    candidates: Vec<Candidate>,
+    completed: &HashSet<i32>,
+    characters: &[OwnedCharacter],
+    limit: usize,
+) -> Vec<Recommendation> {
+    let completed_sets: HashSet<_> = candidates
+        .iter()
+        .filter(|c| completed.contains(&c.id))
+        .filter_map(|c| c.set)
+        .collect();
+
+    let max_currency = candidates
+        .iter()
+        .map(|c| c.currency)
+        .max()
+        .unwrap_or(1)
+        .max(1);
+
+    let mut recommendations: Vec<_> = candidates
+        .into_iter()
+        .filter(|c| !completed.contains(&c.id))
+        .filter(|c| c.set.is_none_or(|set| !completed_sets.contains(&set)))
+        .map(|c| score(c, characters, max_currency))
+        .collect();
+
+    recommendations.sort_by(|a, b| b.score.total_cmp(&a.score));
+    recommendations.truncate(limit);
+
+    recommendations
+}
+
+fn score(candidate: Candidate, characters: &[OwnedCharacter], max_currency: i32) -> Recommendation {
+    let mut reasons = Vec::new();
+
+    // Ease weighs double, a global completion rate of 100% is worth as much as the best reward.
+    let mut score = 2.0 * candidate.percent / 100.0;
+
+    if candidate.percent >= 50.0 {
+        reasons.push(RecommendationReason::Common {
+            percent: candidate.percent,
+        });
+    } else if candidate.percent < 5.0 {
+        reasons.push(RecommendationReason::Rare {
+            percent: candidate.percent,
+        });
+    }
+
+    if let Some(difficulty) = candidate
+        .difficulty
+        .as_ref()
+        .and_then(|d| d.parse::<Difficulty>().ok())
+    {
+        score += match difficulty {
+            Difficulty::Easy => 0.5,
+            Difficulty::Medium => 0.0,
+            Difficulty::Hard => -0.5,
+        };
+
+        reasons.push(RecommendationReason::Difficulty { difficulty });
+    }
+
+    score += candidate.currency as f64 / max_currency as f64;
+
+    if candidate.currency == max_currency {
+        reasons.push(RecommendationReason::Reward {
+            currency: candidate.currency,
+        });
+    }
+
+    if candidate.timegated {
+        score += 0.25;
+
+        reasons.push(RecommendationReason::Timegated);
+    }
+
+    if candidate.missable {
+        score += 0.5;
+
+        reasons.push(RecommendationReason::Missable);
+    }
+
+    if candidate.gacha {
+        let text = format!(
+            "{} {} {}",
+            candidate.name,
+            candidate.description,
+            candidate.comment.as_deref().unwrap_or_default()
+        )
+        .to_lowercase();
+
+        let mut required: Vec<_> = characters
+            .iter()
+            .filter(|c| c.name.chars().count() >= 3 && text.contains(&c.name.to_lowercase()))
+            .collect();
+
+        // Prefer a character the user already has if several names match.
+        required.sort_by_key(|c| !c.owned);
+
+        match required.first() {
+            Some(character) if character.owned => {
+                reasons.push(RecommendationReason::OwnedCharacter {
+                    character: character.id,
+                    name: character.name.clone(),
+                });
+            }
+            Some(character) => {
+                score -= 2.0;
+
+                reasons.push(RecommendationReason::MissingCharacter {
+                    character: character.id,
+                    name: character.name.clone(),
+                });
+            }
+            None => {
+                score -= 1.0;
+
+                reasons.push(RecommendationReason::UnknownCharacter);
+            }
+        }
+    }
+
+    Recommendation {
+        id: candidate.id,
+        name: candidate.name,
+        series_name: candidate.series_name,
+        currency: candidate.currency,
+        percent: candidate.percent,
+        score,
+        reasons,
+    }
+}
+
+#[utoipa::path(
+    tag = "users/me/achievements/recommendations",
+    get,
+    path = "/api/users/me/achievements/recommendations",
+    params(LanguageParams, ProfileParams, RecommendationsParams),
+    responses(
+        (status = 200, description = "[Recommendation]", body = Vec<Recommendation>),
+        (status = 400, description = "Not logged in"),
+        (status = 403, description = "Uid not connected"),
+    )
+)]
+#[get("/api/users/me/achievements/recommendations")]
+async fn get_user_achievements_recommendations(
+    session: Session,
+    language_params: web::Query<LanguageParams>,
+    profile_params: web::Query<ProfileParams>,
+    recommendations_params: web::Query<RecommendationsParams>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    let Some(uid) = profile_params.hsr(&username, &pool).await? else {
+        return Ok(HttpResponse::Forbidden().finish());
+    };
+
+    let completed = database::users_achievements_completed::get_by_username(&username, uid, &pool)
+        .await?
+        .into_iter()
+        .map(|c| c.id)
+        .collect();
+
+    let characters: Vec<_> =
+        database::characters::get_all_owned_by_username(&username, language_params.lang, &pool)
+            .await?
+            .into_iter()
+            .map(|c| OwnedCharacter {
+                id: c.id,
+                name: c.name,
+                owned: c.owned.unwrap_or_default(),
+            })
+            .collect();
+
+    let trailblazer = Trailblazer::new(&session, language_params.lang, &pool).await?;
+
+    let candidates = database::achievements::get_all(language_params.lang, &pool)
+        .await?
+        .into_iter()
+        .filter(|a| !a.impossible)
+        .map(|a| Candidate {
+            id: a.id,
+            name: trailblazer.render(&a.name),
+            description: trailblazer.render(&a.description),
+            comment: a.comment,
+            series_name: trailblazer.render(&a.series_name),
+            currency: a.jades,
+            percent: a.percent,
+            difficulty: a.difficulty,
+            timegated: a.timegated,
+            missable: a.missable,
+            gacha: a.gacha,
+            set: a.set,
+        })
+        .collect();
+
+    let recommendations = recommend(
+        candidates,
+        &completed,
+        &characters,
+        recommendations_params.limit.unwrap_or(20),
+    );
+
+    Ok(HttpResponse::Ok().json(recommendations))
+}
@@ -0,0 +1,134 @@
+use actix_session::Session;
+use actix_web::{get, web, HttpResponse, Responder};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use utoipa::{OpenApi, ToSchema};
+
+use crate::{api::ApiResult, database};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "users/me/achievements/reconciliation")),
+    paths(get_user_achievements_reconciliation),
+    components(schemas(Reconciliation, UidReconciliation))
+)]
+struct ApiDoc;
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_user_achievements_reconciliation);
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct Reconciliation {
+    /// Tracked count of the default profile
+    pub tracked: i64,
+    pub uids: Vec<UidReconciliation>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct UidReconciliation {
+    pub uid: i32,
+    pub name: String,
+    pub tracked: i64,
+    pub in_game: i32,
+    /// In-game count minus tracked count
+    pub difference: i64,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Reconciliation {
+    /// Checks every uid for the default profile 0.
+    pub fn drift(&self, uid: i32) -> bool {
+        self.uids
+            .iter()
+            .any(|u| (uid == 0 || u.uid == uid) && u.difference != 0)
+    }
+}
+
+/// Compares the tracked count with the in-game count of every connected uid.
+/// Uids without a profile of their own are compared against the default profile.
+pub async fn reconcile(username: &str, pool: &PgPool) -> anyhow::Result<Reconciliation> {
+    let tracked =
+        database::users_achievements_completed::count_by_username(username, 0, pool).await?;
+
+    let mut uids = Vec::new();
+
+    for connection in database::connections::get_by_username(username, pool).await? {
+        if !database::mihomo::exists(connection.uid, pool).await? {
+            continue;
+        }
+
+        let db_mihomo = database::mihomo::get_one_by_uid(connection.uid, pool).await?;
+
+        let profile_tracked = database::users_achievements_completed::count_by_username(
+            username,
+            connection.uid,
+            pool,
+        )
+        .await?;
+        let tracked = if profile_tracked > 0 {
+            profile_tracked
+        } else {
+            tracked
+        };
+
+        uids.push(UidReconciliation {
+            uid: db_mihomo.uid,
+            name: db_mihomo.name,
+            tracked,
+            in_game: db_mihomo.achievement_count,
+            difference: db_mihomo.achievement_count as i64 - tracked,
+            updated_at: db_mihomo.updated_at,
+        });
+    }
+
+    Ok(Reconciliation { tracked, uids })
+}
+
+/// `reconcile(..).drift(uid)` without looking at the other connections, except for the default profile 0.
+pub async fn drift(username: &str, uid: i32, pool: &PgPool) -> anyhow::Result<bool> {
+    if uid == 0 {
+        return Ok(reconcile(username, pool).await?.drift(uid));
+    }
+
+    let Some(in_game) = database::mihomo::get_achievement_count_by_uid(uid, pool).await? else {
+        return Ok(false);
+    };
+
+    let mut tracked =
+        database::users_achievements_completed::count_by_username(username, uid, pool).await?;
+    if tracked == 0 {
+        tracked =
+            database::users_achievements_completed::count_by_username(username, 0, pool).await?;
+    }
+
+    Ok(in_game as i64 != tracked)
+}
+
+#[utoipa::path(
+    tag = "users/me/achievements/reconciliation",
+    get,
+    path = "/api/users/me/achievements/reconciliation",
+    responses(
+        (status = 200, description = "Reconciliation", body = Reconciliation),
+        (status = 400, description = "Not logged in"),
+    )
+)]
+#[get("/api/users/me/achievements/reconciliation")]
+async fn get_user_achievements_reconciliation(
+    session: Session,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    let reconciliation = reconcile(&username, &pool).await?;
+
+    Ok(HttpResponse::Ok().json(reconciliation))
+}
@@ -0,0 +1,175 @@
+use actix_session::Session;
+use actix_web::{post, web, HttpResponse, Responder};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use utoipa::{OpenApi, ToSchema};
+
+use crate::{
+    api::{users::me::ProfileParams, ApiResult},
+    database,
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "users/me/achievements/sync")),
+    paths(sync_user_achievements),
+    components(schemas(SyncRequest, SyncResponse, Operation))
+)]
+struct ApiDoc;
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(sync_user_achievements);
+}
+
+const MAX_OPERATIONS: usize = 5000;
+
+#[derive(Deserialize, ToSchema)]
+struct SyncRequest {
+    /// Revision of the last successful sync, 0 for the first one
+    revision: i64,
+    operations: Vec<Operation>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct SyncResponse {
+    revision: i64,
+    /// Latest operation per achievement since the requested revision,
+    /// including the accepted operations of the request
+    operations: Vec<Operation>,
+    rejected: usize,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+struct Operation {
+    id: i32,
+    completed: bool,
+    /// Timestamps in the future count as now
+    timestamp: DateTime<Utc>,
+}
+
+impl From<database::users_achievements_operations::DbUserAchievementOperation> for Operation {
+    fn from(
+        db_operation: database::users_achievements_operations::DbUserAchievementOperation,
+    ) -> Self {
+        Operation {
+            id: db_operation.id,
+            completed: db_operation.completed,
+            timestamp: db_operation.timestamp,
+        }
+    }
+}
+
+#[utoipa::path(
+    tag = "users/me/achievements/sync",
+    post,
+    path = "/api/users/me/achievements/sync",
+    params(ProfileParams),
+    request_body = SyncRequest,
+    responses(
+        (status = 200, description = "SyncResponse", body = SyncResponse),
+        (status = 400, description = "Not logged in or too many operations"),
+        (status = 403, description = "Uid not connected"),
+    )
+)]
+#[post("/api/users/me/achievements/sync")]
+async fn sync_user_achievements(
+    session: Session,
+    profile_params: web::Query<ProfileParams>,
+    sync_request: web::Json<SyncRequest>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    let Some(uid) = profile_params.hsr(&username, &pool).await? else {
+        return Ok(HttpResponse::Forbidden().finish());
+    };
+
+    if sync_request.operations.len() > MAX_OPERATIONS {
+        return Ok(HttpResponse::BadRequest().finish());
+    }
+
+    let mut operations: Vec<_> = sync_request.operations.iter().collect();
+    operations.sort_by_key(|o| o.timestamp);
+
+    // A timestamp in the future would block every later write to the achievement
+    let now = Utc::now();
+
+    let mut rejected = 0;
+
+    // Either the whole batch is applied or nothing
+    let mut transaction = pool.begin().await?;
+
+    // Other syncs of the profile wait, so the checks and the revision below see every earlier write
+    database::users_achievements_operations::lock(&username, uid, &mut transaction).await?;
+
+    for operation in operations {
+        let timestamp = operation.timestamp.min(now);
+
+        // Last writer wins, the server keeps its state on ties
+        if let Some(latest) = database::users_achievements_operations::get_latest_timestamp(
+            &username,
+            uid,
+            operation.id,
+            &mut transaction,
+        )
+        .await?
+        {
+            if latest >= timestamp {
+                rejected += 1;
+                continue;
+            }
+        }
+
+        let complete = database::users_achievements_completed::DbUserAchievementCompleted {
+            username: username.clone(),
+            uid,
+            id: operation.id,
+        };
+
+        if operation.completed {
+            database::users_achievements_completed::add_at(&complete, timestamp, &mut transaction)
+                .await?;
+        } else {
+            database::users_achievements_completed::delete_at(
+                &complete,
+                timestamp,
+                &mut transaction,
+            )
+            .await?;
+        }
+    }
+
+    let revision =
+        database::users_achievements_operations::get_revision(&username, uid, &mut transaction)
+            .await?
+            .max(sync_request.revision);
+
+    let operations = database::users_achievements_operations::get_since_revision(
+        &username,
+        uid,
+        sync_request.revision,
+        revision,
+        &mut transaction,
+    )
+    .await?
+    .into_iter()
+    .map(Operation::from)
+    .collect();
+
+    transaction.commit().await?;
+
+    let sync_response = SyncResponse {
+        revision,
+        operations,
+        rejected,
+    };
+
+    Ok(HttpResponse::Ok().json(sync_response))
+}
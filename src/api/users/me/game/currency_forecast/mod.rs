@@ -0,0 +1,270 @@
+use std::collections::HashSet;
+
+use actix_session::Session;
+use actix_web::{get, web, HttpResponse, Responder};
+use chrono::{Days, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use utoipa::{IntoParams, OpenApi, ToSchema};
+
+use crate::{
+    api::{users::me::ProfileParams, ApiResult},
+    database, Game, Language,
+};
+
+/// Jades, polychromes and primogems all cost 160 per pull.
+const PULL_COST: i64 = 160;
+/// Hard pity of the limited character banners.
+const HARD_PITY: i64 = 90;
+/// Daily missions of all three games reward 60.
+const DAILY: i64 = 60;
+const DAYS: u64 = 42;
+const MAX_DAYS: u64 = 365;
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "users/me/{game}/currency-forecast")),
+    paths(get_currency_forecast),
+    components(schemas(CurrencyForecast, ForecastDay))
+)]
+struct ApiDoc;
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_currency_forecast);
+}
+
+#[derive(Deserialize, IntoParams)]
+struct ForecastParams {
+    /// Currency earned per day, defaults to the daily missions
+    daily: Option<i64>,
+    /// Achievement currency earned per day, defaults to all of it right away
+    achievements_daily: Option<i64>,
+    /// Length of the forecast in days
+    days: Option<u64>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct CurrencyForecast {
+    /// Currency of all achievements that can still be completed
+    achievements_currency: i64,
+    achievements_pulls: i64,
+    daily: i64,
+    /// Pulls since the last 5 star or S rank on the limited character banner, needs a uid with imported history
+    pity: Option<i64>,
+    /// First day with enough pulls to reach hard pity
+    hard_pity_date: Option<NaiveDate>,
+    forecast: Vec<ForecastDay>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct ForecastDay {
+    date: NaiveDate,
+    currency: i64,
+    pulls: i64,
+}
+
+struct Reward {
+    id: i32,
+    currency: i32,
+    set: Option<i32>,
+}
+
+/// Only one achievement of a set can be completed.
+fn remaining_currency(rewards: &[Reward], completed: &HashSet<i32>) -> i64 {
+    let mut sets: HashSet<_> = rewards
+        .iter()
+        .filter(|r| completed.contains(&r.id))
+        .filter_map(|r| r.set)
+        .collect();
+
+    let mut currency = 0;
+
+    for reward in rewards.iter().filter(|r| !completed.contains(&r.id)) {
+        if let Some(set) = reward.set {
+            if !sets.insert(set) {
+                continue;
+            }
+        }
+
+        currency += reward.currency as i64;
+    }
+
+    currency
+}
+
+/// `top` is the rarity that resets pity, Zenless stores its S ranks as 4.
+fn pity(rarities: impl Iterator<Item = Option<i32>>, top: i32) -> i64 {
+    let mut pity = 0;
+
+    for rarity in rarities {
+        pity += 1;
+
+        if rarity == Some(top) {
+            pity = 0;
+        }
+    }
+
+    pity
+}
+
+#[utoipa::path(
+    tag = "users/me/{game}/currency-forecast",
+    get,
+    path = "/api/users/me/{game}/currency-forecast",
+    params(ProfileParams, ForecastParams),
+    responses(
+        (status = 200, description = "CurrencyForecast", body = CurrencyForecast),
+        (status = 400, description = "Not logged in"),
+        (status = 403, description = "Uid not connected"),
+    )
+)]
+#[get("/api/users/me/{game}/currency-forecast")]
+async fn get_currency_forecast(
+    session: Session,
+    game: web::Path<Game>,
+    profile_params: web::Query<ProfileParams>,
+    forecast_params: web::Query<ForecastParams>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    let uid = match *game {
+        Game::Hsr => profile_params.hsr(&username, &pool).await?,
+        Game::Zzz => profile_params.zzz(&username, &pool).await?,
+        Game::Gi => profile_params.gi(&username, &pool).await?,
+    };
+
+    let Some(uid) = uid else {
+        return Ok(HttpResponse::Forbidden().finish());
+    };
+
+    let language = Language::En;
+
+    let (rewards, completed, pity): (Vec<_>, HashSet<_>, _) = match *game {
+        Game::Hsr => (
+            database::achievements::get_all(language, &pool)
+                .await?
+                .into_iter()
+                .filter(|a| !a.impossible)
+                .map(|a| Reward {
+                    id: a.id,
+                    currency: a.jades,
+                    set: a.set,
+                })
+                .collect(),
+            database::users_achievements_completed::get_by_username(&username, uid, &pool)
+                .await?
+                .into_iter()
+                .map(|c| c.id)
+                .collect(),
+            if uid != 0 {
+                let warps = database::warps::special::get_by_uid(uid, language, &pool).await?;
+
+                (!warps.is_empty()).then(|| pity(warps.into_iter().map(|w| w.rarity), 5))
+            } else {
+                None
+            },
+        ),
+        Game::Zzz => (
+            database::zzz::achievements::get_all(language, &pool)
+                .await?
+                .into_iter()
+                .filter(|a| !a.impossible)
+                .map(|a| Reward {
+                    id: a.id,
+                    currency: a.polychromes,
+                    set: a.set,
+                })
+                .collect(),
+            database::zzz::users_achievements_completed::get_by_username(&username, uid, &pool)
+                .await?
+                .into_iter()
+                .map(|c| c.id)
+                .collect(),
+            if uid != 0 {
+                let signals =
+                    database::zzz::signals::special::get_by_uid(uid, language, &pool).await?;
+
+                (!signals.is_empty()).then(|| pity(signals.into_iter().map(|s| s.rarity), 4))
+            } else {
+                None
+            },
+        ),
+        Game::Gi => (
+            database::gi::achievements::get_all(language, &pool)
+                .await?
+                .into_iter()
+                .filter(|a| !a.impossible)
+                .map(|a| Reward {
+                    id: a.id,
+                    currency: a.primogems,
+                    set: a.set,
+                })
+                .collect(),
+            database::gi::users_achievements_completed::get_by_username(&username, uid, &pool)
+                .await?
+                .into_iter()
+                .map(|c| c.id)
+                .collect(),
+            if uid != 0 {
+                let wishes =
+                    database::gi::wishes::character::get_by_uid(uid, language, &pool).await?;
+
+                (!wishes.is_empty()).then(|| pity(wishes.into_iter().map(|w| w.rarity), 5))
+            } else {
+                None
+            },
+        ),
+    };
+
+    let achievements_currency = remaining_currency(&rewards, &completed);
+    let daily = forecast_params.daily.unwrap_or(DAILY).max(0);
+    let days = forecast_params.days.unwrap_or(DAYS).min(MAX_DAYS);
+
+    let pulls_to_hard_pity = (HARD_PITY - pity.unwrap_or_default()).max(0);
+
+    let today = Utc::now().date_naive();
+
+    let mut hard_pity_date = None;
+    let mut forecast = Vec::new();
+
+    for day in 0..=days {
+        let achievements = match forecast_params.achievements_daily {
+            Some(achievements_daily) => {
+                (achievements_daily.max(0) * day as i64).min(achievements_currency)
+            }
+            None => achievements_currency,
+        };
+
+        let currency = achievements + daily * day as i64;
+        let pulls = currency / PULL_COST;
+        let date = today + Days::new(day);
+
+        if hard_pity_date.is_none() && pulls >= pulls_to_hard_pity {
+            hard_pity_date = Some(date);
+        }
+
+        forecast.push(ForecastDay {
+            date,
+            currency,
+            pulls,
+        });
+    }
+
+    let currency_forecast = CurrencyForecast {
+        achievements_currency,
+        achievements_pulls: achievements_currency / PULL_COST,
+        daily,
+        pity,
+        hard_pity_date,
+        forecast,
+    };
+
+    Ok(HttpResponse::Ok().json(currency_forecast))
+}
@@ -0,0 +1,73 @@
+mod id;
+
+use std::collections::HashSet;
+
+use actix_session::Session;
+use actix_web::{put, web, HttpResponse, Responder};
+use sqlx::PgPool;
+use utoipa::OpenApi;
+
+use crate::{api::ApiResult, database};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "users/me/gi/achievement-lists/{id}/achievements")),
+    paths(put_gi_user_achievement_list_achievements)
+)]
+struct ApiDoc;
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    let mut openapi = ApiDoc::openapi();
+    openapi.merge(id::openapi());
+    openapi
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(put_gi_user_achievement_list_achievements)
+        .configure(id::configure);
+}
+
+#[utoipa::path(
+    tag = "users/me/gi/achievement-lists/{id}/achievements",
+    put,
+    path = "/api/users/me/gi/achievement-lists/{id}/achievements",
+    request_body(content = Vec<i32>, description = "Achievement ids in list order"),
+    responses(
+        (status = 200, description = "Replaced the achievements of the list"),
+        (status = 400, description = "Not logged in or unknown achievements"),
+        (status = 404, description = "No such list"),
+    )
+)]
+#[put("/api/users/me/gi/achievement-lists/{id}/achievements")]
+async fn put_gi_user_achievement_list_achievements(
+    session: Session,
+    id: web::Path<i32>,
+    ids: web::Json<Vec<i32>>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    if database::gi::users_achievement_lists::get_one(&username, *id, &pool)
+        .await?
+        .is_none()
+    {
+        return Ok(HttpResponse::NotFound().finish());
+    }
+
+    let mut seen = HashSet::new();
+    let ids: Vec<_> = ids.iter().copied().filter(|id| seen.insert(*id)).collect();
+
+    if database::gi::achievements::get_existing_ids(&ids, &pool)
+        .await?
+        .len()
+        != ids.len()
+    {
+        return Ok(HttpResponse::BadRequest().finish());
+    }
+
+    database::gi::users_achievement_lists_entries::set_all(*id, &ids, &pool).await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
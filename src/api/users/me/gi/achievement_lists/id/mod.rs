@@ -0,0 +1,90 @@
+mod achievements;
+
+use actix_session::Session;
+use actix_web::{delete, put, web, HttpResponse, Responder};
+use sqlx::PgPool;
+use utoipa::OpenApi;
+
+use super::AchievementListUpdate;
+use crate::{api::ApiResult, database};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "users/me/gi/achievement-lists/{id}")),
+    paths(put_gi_user_achievement_list, delete_gi_user_achievement_list)
+)]
+struct ApiDoc;
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    let mut openapi = ApiDoc::openapi();
+    openapi.merge(achievements::openapi());
+    openapi
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(put_gi_user_achievement_list)
+        .service(delete_gi_user_achievement_list)
+        .configure(achievements::configure);
+}
+
+#[utoipa::path(
+    tag = "users/me/gi/achievement-lists/{id}",
+    put,
+    path = "/api/users/me/gi/achievement-lists/{id}",
+    request_body = AchievementListUpdate,
+    responses(
+        (status = 200, description = "Renamed"),
+        (status = 400, description = "Not logged in or invalid name"),
+        (status = 404, description = "No such list"),
+    )
+)]
+#[put("/api/users/me/gi/achievement-lists/{id}")]
+async fn put_gi_user_achievement_list(
+    session: Session,
+    id: web::Path<i32>,
+    list_update: web::Json<AchievementListUpdate>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    let Some(name) = list_update.name() else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    if database::gi::users_achievement_lists::get_one(&username, *id, &pool)
+        .await?
+        .is_none()
+    {
+        return Ok(HttpResponse::NotFound().finish());
+    }
+
+    database::gi::users_achievement_lists::update_name(&username, *id, name, &pool).await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[utoipa::path(
+    tag = "users/me/gi/achievement-lists/{id}",
+    delete,
+    path = "/api/users/me/gi/achievement-lists/{id}",
+    responses(
+        (status = 200, description = "Deleted"),
+        (status = 400, description = "Not logged in"),
+    )
+)]
+#[delete("/api/users/me/gi/achievement-lists/{id}")]
+async fn delete_gi_user_achievement_list(
+    session: Session,
+    id: web::Path<i32>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    database::gi::users_achievement_lists::delete(&username, *id, &pool).await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
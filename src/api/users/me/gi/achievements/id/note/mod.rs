@@ -0,0 +1,128 @@
+use actix_session::Session;
+use actix_web::{delete, get, put, web, HttpResponse, Responder};
+use serde::Deserialize;
+use sqlx::PgPool;
+use utoipa::{OpenApi, ToSchema};
+
+use crate::{
+    api::{users::me::AchievementNote, ApiResult},
+    database,
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "users/me/gi/achievements/{id}/note")),
+    paths(get_gi_user_achievement_note, put_gi_user_achievement_note, delete_gi_user_achievement_note),
+    components(schemas(NoteUpdate))
+)]
+struct ApiDoc;
+
+#[derive(Deserialize, ToSchema)]
+struct NoteUpdate {
+    note: String,
+}
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_gi_user_achievement_note)
+        .service(put_gi_user_achievement_note)
+        .service(delete_gi_user_achievement_note);
+}
+
+#[utoipa::path(
+    tag = "users/me/gi/achievements/{id}/note",
+    get,
+    path = "/api/users/me/gi/achievements/{id}/note",
+    responses(
+        (status = 200, description = "AchievementNote", body = AchievementNote),
+        (status = 400, description = "Not logged in"),
+        (status = 404, description = "No note"),
+    )
+)]
+#[get("/api/users/me/gi/achievements/{id}/note")]
+async fn get_gi_user_achievement_note(
+    session: Session,
+    id: web::Path<i32>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    let Some(db_note) =
+        database::gi::users_achievements_notes::get_one(&username, *id, &pool).await?
+    else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+
+    Ok(HttpResponse::Ok().json(AchievementNote::from(db_note)))
+}
+
+#[utoipa::path(
+    tag = "users/me/gi/achievements/{id}/note",
+    put,
+    path = "/api/users/me/gi/achievements/{id}/note",
+    request_body = NoteUpdate,
+    responses(
+        (status = 200, description = "Updated note"),
+        (status = 400, description = "Not logged in or note too long"),
+    )
+)]
+#[put("/api/users/me/gi/achievements/{id}/note")]
+async fn put_gi_user_achievement_note(
+    session: Session,
+    id: web::Path<i32>,
+    note_update: web::Json<NoteUpdate>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    let note = note_update.note.trim().to_string();
+
+    if note.chars().count() > AchievementNote::MAX_LENGTH {
+        return Ok(HttpResponse::BadRequest().finish());
+    }
+
+    if note.is_empty() {
+        database::gi::users_achievements_notes::delete(&username, *id, &pool).await?;
+    } else {
+        let db_note = database::gi::users_achievements_notes::DbUserAchievementNote {
+            username,
+            id: *id,
+            note,
+        };
+
+        database::gi::users_achievements_notes::set(&db_note, &pool).await?;
+    }
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[utoipa::path(
+    tag = "users/me/gi/achievements/{id}/note",
+    delete,
+    path = "/api/users/me/gi/achievements/{id}/note",
+    responses(
+        (status = 200, description = "Deleted note"),
+        (status = 400, description = "Not logged in"),
+    )
+)]
+#[delete("/api/users/me/gi/achievements/{id}/note")]
+async fn delete_gi_user_achievement_note(
+    session: Session,
+    id: web::Path<i32>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    database::gi::users_achievements_notes::delete(&username, *id, &pool).await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
@@ -1,5 +1,9 @@
 mod completed;
 mod favorites;
+mod id;
+mod notes;
+mod rarest;
+mod recommendations;
 
 use actix_web::web;
 use utoipa::OpenApi;
@@ -12,10 +16,18 @@ pub fn openapi() -> utoipa::openapi::OpenApi {
     let mut openapi = ApiDoc::openapi();
     openapi.merge(completed::openapi());
     openapi.merge(favorites::openapi());
+    openapi.merge(id::openapi());
+    openapi.merge(notes::openapi());
+    openapi.merge(rarest::openapi());
+    openapi.merge(recommendations::openapi());
     openapi
 }
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.configure(completed::configure)
-        .configure(favorites::configure);
+        .configure(favorites::configure)
+        .configure(id::configure)
+        .configure(notes::configure)
+        .configure(rarest::configure)
+        .configure(recommendations::configure);
 }
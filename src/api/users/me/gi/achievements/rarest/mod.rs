@@ -0,0 +1,92 @@
+use actix_session::Session;
+use actix_web::{get, web, HttpResponse, Responder};
+use sqlx::PgPool;
+use utoipa::OpenApi;
+
+use crate::{
+    api::{
+        users::me::{
+            achievements::rarest::{rarest, RareAchievement, RarestParams, THRESHOLD},
+            ProfileParams,
+        },
+        ApiResult, LanguageParams,
+    },
+    database,
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "users/me/gi/achievements/rarest")),
+    paths(get_gi_user_achievements_rarest)
+)]
+struct ApiDoc;
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_gi_user_achievements_rarest);
+}
+
+#[utoipa::path(
+    tag = "users/me/gi/achievements/rarest",
+    get,
+    path = "/api/users/me/gi/achievements/rarest",
+    params(LanguageParams, ProfileParams, RarestParams),
+    responses(
+        (status = 200, description = "Rarest", body = Rarest),
+        (status = 400, description = "Not logged in"),
+        (status = 403, description = "Uid not connected"),
+    )
+)]
+#[get("/api/users/me/gi/achievements/rarest")]
+async fn get_gi_user_achievements_rarest(
+    session: Session,
+    language_params: web::Query<LanguageParams>,
+    profile_params: web::Query<ProfileParams>,
+    rarest_params: web::Query<RarestParams>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    let Some(uid) = profile_params.gi(&username, &pool).await? else {
+        return Ok(HttpResponse::Forbidden().finish());
+    };
+
+    let completed =
+        database::gi::users_achievements_completed::get_by_username(&username, uid, &pool)
+            .await?
+            .into_iter()
+            .map(|c| c.id)
+            .collect();
+
+    let users = database::gi::users_achievements_completed::count_users(THRESHOLD, &pool).await?;
+    let users_below = database::gi::users_achievements_completed::count_users_below(
+        THRESHOLD, &username, uid, &pool,
+    )
+    .await?;
+
+    let achievements = database::gi::achievements::get_all(language_params.lang, &pool)
+        .await?
+        .into_iter()
+        .map(|a| RareAchievement {
+            id: a.id,
+            name: a.name,
+            series_name: a.series_name,
+            percent: a.percent,
+        })
+        .collect();
+
+    let rarest = rarest(
+        achievements,
+        &completed,
+        users,
+        users_below,
+        rarest_params.limit.unwrap_or(10),
+    );
+
+    Ok(HttpResponse::Ok().json(rarest))
+}
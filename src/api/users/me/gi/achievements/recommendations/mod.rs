@@ -0,0 +1,107 @@
+use actix_session::Session;
+use actix_web::{get, web, HttpResponse, Responder};
+use sqlx::PgPool;
+use utoipa::OpenApi;
+
+use crate::{
+    api::{
+        users::me::{
+            achievements::recommendations::{
+                recommend, Candidate, OwnedCharacter, RecommendationsParams,
+            },
+            ProfileParams,
+        },
+        ApiResult, LanguageParams,
+    },
+    database,
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "users/me/gi/achievements/recommendations")),
+    paths(get_gi_user_achievements_recommendations)
+)]
+struct ApiDoc;
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_gi_user_achievements_recommendations);
+}
+
+#[utoipa::path(
+    tag = "users/me/gi/achievements/recommendations",
+    get,
+    path = "/api/users/me/gi/achievements/recommendations",
+    params(LanguageParams, ProfileParams, RecommendationsParams),
+    responses(
+        (status = 200, description = "[Recommendation]", body = Vec<Recommendation>),
+        (status = 400, description = "Not logged in"),
+        (status = 403, description = "Uid not connected"),
+    )
+)]
+#[get("/api/users/me/gi/achievements/recommendations")]
+async fn get_gi_user_achievements_recommendations(
+    session: Session,
+    language_params: web::Query<LanguageParams>,
+    profile_params: web::Query<ProfileParams>,
+    recommendations_params: web::Query<RecommendationsParams>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    let Some(uid) = profile_params.gi(&username, &pool).await? else {
+        return Ok(HttpResponse::Forbidden().finish());
+    };
+
+    let completed =
+        database::gi::users_achievements_completed::get_by_username(&username, uid, &pool)
+            .await?
+            .into_iter()
+            .map(|c| c.id)
+            .collect();
+
+    let characters: Vec<_> =
+        database::gi::characters::get_all_owned_by_username(&username, language_params.lang, &pool)
+            .await?
+            .into_iter()
+            .map(|c| OwnedCharacter {
+                id: c.id,
+                name: c.name,
+                owned: c.owned.unwrap_or_default(),
+            })
+            .collect();
+
+    let candidates = database::gi::achievements::get_all(language_params.lang, &pool)
+        .await?
+        .into_iter()
+        .filter(|a| !a.impossible)
+        .map(|a| Candidate {
+            id: a.id,
+            name: a.name,
+            description: a.description,
+            comment: a.comment,
+            series_name: a.series_name,
+            currency: a.primogems,
+            percent: a.percent,
+            difficulty: a.difficulty,
+            timegated: a.timegated.is_some(),
+            missable: a.missable,
+            gacha: a.gacha,
+            set: a.set,
+        })
+        .collect();
+
+    let recommendations = recommend(
+        candidates,
+        &completed,
+        &characters,
+        recommendations_params.limit.unwrap_or(20),
+    );
+
+    Ok(HttpResponse::Ok().json(recommendations))
+}
@@ -1,3 +1,4 @@
+mod achievement_lists;
 mod achievements;
 mod uids;
 
@@ -10,12 +11,14 @@ struct ApiDoc;
 
 pub fn openapi() -> utoipa::openapi::OpenApi {
     let mut openapi = ApiDoc::openapi();
+    openapi.merge(achievement_lists::openapi());
     openapi.merge(achievements::openapi());
     openapi.merge(uids::openapi());
     openapi
 }
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
-    cfg.configure(achievements::configure)
+    cfg.configure(achievement_lists::configure)
+        .configure(achievements::configure)
         .configure(uids::configure);
 }
@@ -56,10 +56,11 @@ async fn import(
     };
 
     if let Some(achievements) = &import_data.achievements {
-        database::users_achievements_completed::delete_by_username(&username, &pool).await?;
+        database::users_achievements_completed::delete_by_username(&username, 0, &pool).await?;
         let mut achievement_completed =
             database::users_achievements_completed::DbUserAchievementCompleted {
                 username: username.clone(),
+                uid: 0,
                 id: 0,
             };
         for &achievement in achievements {
@@ -95,10 +96,11 @@ async fn import_file(
     let import_data: ImportData = serde_json::from_reader(BufReader::new(&file.file.file))?;
 
     if let Some(achievements) = &import_data.achievements {
-        database::users_achievements_completed::delete_by_username(&username, &pool).await?;
+        database::users_achievements_completed::delete_by_username(&username, 0, &pool).await?;
         let mut achievement_completed =
             database::users_achievements_completed::DbUserAchievementCompleted {
                 username: username.clone(),
+                uid: 0,
                 id: 0,
             };
         for &achievement in achievements {
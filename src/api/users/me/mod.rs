@@ -1,17 +1,20 @@
-mod achievements;
+mod achievement_lists;
+pub mod achievements;
 mod email;
+mod game;
 mod gi;
 mod import;
 mod password;
+mod trailblazer;
 mod uids;
 mod username;
 mod zzz;
 
 use actix_session::Session;
 use actix_web::{get, web, HttpResponse, Responder};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
-use utoipa::{OpenApi, ToSchema};
+use utoipa::{IntoParams, OpenApi, ToSchema};
 
 use crate::{api::ApiResult, database};
 
@@ -21,17 +24,21 @@ use crate::{api::ApiResult, database};
     paths(get_me),
     components(schemas(
         User,
+        AchievementNote,
     ))
 )]
 struct ApiDoc;
 
 pub fn openapi() -> utoipa::openapi::OpenApi {
     let mut openapi = ApiDoc::openapi();
+    openapi.merge(achievement_lists::openapi());
     openapi.merge(achievements::openapi());
     openapi.merge(email::openapi());
+    openapi.merge(game::openapi());
     openapi.merge(gi::openapi());
     openapi.merge(import::openapi());
     openapi.merge(password::openapi());
+    openapi.merge(trailblazer::openapi());
     openapi.merge(uids::openapi());
     openapi.merge(username::openapi());
     openapi.merge(zzz::openapi());
@@ -40,11 +47,14 @@ pub fn openapi() -> utoipa::openapi::OpenApi {
 
 pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(get_me)
+        .configure(achievement_lists::configure)
         .configure(achievements::configure)
         .configure(email::configure)
+        .configure(game::configure)
         .configure(gi::configure)
         .configure(import::configure)
         .configure(password::configure)
+        .configure(trailblazer::configure)
         .configure(uids::configure)
         .configure(username::configure)
         .configure(zzz::configure);
@@ -60,6 +70,91 @@ pub struct User {
     gi_uids: Vec<i32>,
     achievements: Vec<i32>,
     zzz_achievements: Vec<i32>,
+    achievement_notes: Vec<AchievementNote>,
+    zzz_achievement_notes: Vec<AchievementNote>,
+    gi_achievement_notes: Vec<AchievementNote>,
+}
+
+/// Scopes completions and favorites to one of the user's connected game accounts.
+#[derive(Deserialize, IntoParams)]
+pub struct ProfileParams {
+    pub uid: Option<i32>,
+}
+
+impl ProfileParams {
+    /// The default profile is 0, None if the uid isn't connected to the user.
+    pub async fn hsr(&self, username: &str, pool: &PgPool) -> anyhow::Result<Option<i32>> {
+        let Some(uid) = self.uid else {
+            return Ok(Some(0));
+        };
+
+        Ok(database::connections::get_by_username(username, pool)
+            .await?
+            .iter()
+            .any(|c| c.uid == uid)
+            .then_some(uid))
+    }
+
+    pub async fn zzz(&self, username: &str, pool: &PgPool) -> anyhow::Result<Option<i32>> {
+        let Some(uid) = self.uid else {
+            return Ok(Some(0));
+        };
+
+        Ok(database::zzz::connections::get_by_username(username, pool)
+            .await?
+            .iter()
+            .any(|c| c.uid == uid)
+            .then_some(uid))
+    }
+
+    pub async fn gi(&self, username: &str, pool: &PgPool) -> anyhow::Result<Option<i32>> {
+        let Some(uid) = self.uid else {
+            return Ok(Some(0));
+        };
+
+        Ok(database::gi::connections::get_by_username(username, pool)
+            .await?
+            .iter()
+            .any(|c| c.uid == uid)
+            .then_some(uid))
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct AchievementNote {
+    id: i32,
+    note: String,
+}
+
+impl AchievementNote {
+    pub const MAX_LENGTH: usize = 1000;
+}
+
+impl From<database::users_achievements_notes::DbUserAchievementNote> for AchievementNote {
+    fn from(db_note: database::users_achievements_notes::DbUserAchievementNote) -> Self {
+        AchievementNote {
+            id: db_note.id,
+            note: db_note.note,
+        }
+    }
+}
+
+impl From<database::zzz::users_achievements_notes::DbUserAchievementNote> for AchievementNote {
+    fn from(db_note: database::zzz::users_achievements_notes::DbUserAchievementNote) -> Self {
+        AchievementNote {
+            id: db_note.id,
+            note: db_note.note,
+        }
+    }
+}
+
+impl From<database::gi::users_achievements_notes::DbUserAchievementNote> for AchievementNote {
+    fn from(db_note: database::gi::users_achievements_notes::DbUserAchievementNote) -> Self {
+        AchievementNote {
+            id: db_note.id,
+            note: db_note.note,
+        }
+    }
 }
 
 #[utoipa::path(
@@ -103,19 +198,40 @@ async fn get_me(session: Session, pool: web::Data<PgPool>) -> ApiResult<impl Res
         .map(|c| c.uid)
         .collect();
 
-    let achievements = database::users_achievements_completed::get_by_username(&username, &pool)
+    let achievements = database::users_achievements_completed::get_by_username(&username, 0, &pool)
         .await?
         .into_iter()
         .map(|b| b.id)
         .collect();
 
     let zzz_achievements =
-        database::zzz::users_achievements_completed::get_by_username(&username, &pool)
+        database::zzz::users_achievements_completed::get_by_username(&username, 0, &pool)
             .await?
             .into_iter()
             .map(|b| b.id)
             .collect();
 
+    let achievement_notes =
+        database::users_achievements_notes::get_by_username(&username, None, &pool)
+            .await?
+            .into_iter()
+            .map(AchievementNote::from)
+            .collect();
+
+    let zzz_achievement_notes =
+        database::zzz::users_achievements_notes::get_by_username(&username, None, &pool)
+            .await?
+            .into_iter()
+            .map(AchievementNote::from)
+            .collect();
+
+    let gi_achievement_notes =
+        database::gi::users_achievements_notes::get_by_username(&username, None, &pool)
+            .await?
+            .into_iter()
+            .map(AchievementNote::from)
+            .collect();
+
     let user = User {
         username,
         admin,
@@ -125,6 +241,9 @@ async fn get_me(session: Session, pool: web::Data<PgPool>) -> ApiResult<impl Res
         gi_uids,
         achievements,
         zzz_achievements,
+        achievement_notes,
+        zzz_achievement_notes,
+        gi_achievement_notes,
     };
 
     Ok(HttpResponse::Ok().json(user))
@@ -0,0 +1,137 @@
+use actix_session::Session;
+use actix_web::{delete, get, put, web, HttpResponse, Responder};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use utoipa::{OpenApi, ToSchema};
+
+use crate::{api::ApiResult, database, Gender};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "users/me/trailblazer")),
+    paths(get_trailblazer, put_trailblazer, delete_trailblazer),
+    components(schemas(Trailblazer, Gender))
+)]
+struct ApiDoc;
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_trailblazer)
+        .service(put_trailblazer)
+        .service(delete_trailblazer);
+}
+
+/// Used to render gendered hsr texts and the {NICKNAME} placeholder.
+#[derive(Serialize, Deserialize, ToSchema)]
+struct Trailblazer {
+    gender: Gender,
+    nickname: Option<String>,
+}
+
+impl Trailblazer {
+    const MAX_LENGTH: usize = 32;
+}
+
+impl From<database::users_trailblazer::DbUserTrailblazer> for Trailblazer {
+    fn from(db_trailblazer: database::users_trailblazer::DbUserTrailblazer) -> Self {
+        Trailblazer {
+            gender: db_trailblazer.gender.parse().unwrap(),
+            nickname: db_trailblazer.nickname,
+        }
+    }
+}
+
+#[utoipa::path(
+    tag = "users/me/trailblazer",
+    get,
+    path = "/api/users/me/trailblazer",
+    responses(
+        (status = 200, description = "Trailblazer", body = Trailblazer),
+        (status = 400, description = "Not logged in"),
+        (status = 404, description = "No preference"),
+    )
+)]
+#[get("/api/users/me/trailblazer")]
+async fn get_trailblazer(session: Session, pool: web::Data<PgPool>) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    let Some(db_trailblazer) =
+        database::users_trailblazer::get_one_by_username(&username, &pool).await?
+    else {
+        return Ok(HttpResponse::NotFound().finish());
+    };
+
+    Ok(HttpResponse::Ok().json(Trailblazer::from(db_trailblazer)))
+}
+
+#[utoipa::path(
+    tag = "users/me/trailblazer",
+    put,
+    path = "/api/users/me/trailblazer",
+    request_body = Trailblazer,
+    responses(
+        (status = 200, description = "Updated preference"),
+        (status = 400, description = "Not logged in or nickname too long"),
+    )
+)]
+#[put("/api/users/me/trailblazer")]
+async fn put_trailblazer(
+    session: Session,
+    trailblazer: web::Json<Trailblazer>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    let nickname = trailblazer
+        .nickname
+        .as_ref()
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty());
+
+    if nickname
+        .as_ref()
+        .is_some_and(|n| n.chars().count() > Trailblazer::MAX_LENGTH)
+    {
+        return Ok(HttpResponse::BadRequest().finish());
+    }
+
+    let db_trailblazer = database::users_trailblazer::DbUserTrailblazer {
+        username,
+        gender: trailblazer.gender.to_string(),
+        nickname,
+    };
+
+    database::users_trailblazer::set(&db_trailblazer, &pool).await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[utoipa::path(
+    tag = "users/me/trailblazer",
+    delete,
+    path = "/api/users/me/trailblazer",
+    responses(
+        (status = 200, description = "Deleted preference"),
+        (status = 400, description = "Not logged in"),
+    )
+)]
+#[delete("/api/users/me/trailblazer")]
+async fn delete_trailblazer(
+    session: Session,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    database::users_trailblazer::delete_by_username(&username, &pool).await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
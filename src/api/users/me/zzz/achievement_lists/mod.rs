@@ -0,0 +1,152 @@
+mod id;
+
+use std::collections::HashMap;
+
+use actix_session::Session;
+use actix_web::{get, post, put, web, HttpResponse, Responder};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use utoipa::{OpenApi, ToSchema};
+
+use crate::{api::ApiResult, database};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "users/me/zzz/achievement-lists")),
+    paths(get_zzz_user_achievement_lists, post_zzz_user_achievement_lists, put_zzz_user_achievement_lists),
+    components(schemas(AchievementList, AchievementListUpdate))
+)]
+struct ApiDoc;
+
+#[derive(Serialize, ToSchema)]
+struct AchievementList {
+    id: i32,
+    name: String,
+    achievements: Vec<i32>,
+}
+
+#[derive(Deserialize, ToSchema)]
+struct AchievementListUpdate {
+    name: String,
+}
+
+impl AchievementListUpdate {
+    const MAX_LENGTH: usize = 64;
+
+    fn name(&self) -> Option<&str> {
+        let name = self.name.trim();
+
+        (!name.is_empty() && name.chars().count() <= Self::MAX_LENGTH).then_some(name)
+    }
+}
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    let mut openapi = ApiDoc::openapi();
+    openapi.merge(id::openapi());
+    openapi
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_zzz_user_achievement_lists)
+        .service(post_zzz_user_achievement_lists)
+        .service(put_zzz_user_achievement_lists)
+        .configure(id::configure);
+}
+
+#[utoipa::path(
+    tag = "users/me/zzz/achievement-lists",
+    get,
+    path = "/api/users/me/zzz/achievement-lists",
+    responses(
+        (status = 200, description = "[AchievementList]", body = Vec<AchievementList>),
+        (status = 400, description = "Not logged in"),
+    )
+)]
+#[get("/api/users/me/zzz/achievement-lists")]
+async fn get_zzz_user_achievement_lists(
+    session: Session,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    let mut entries: HashMap<i32, Vec<i32>> = HashMap::new();
+    for entry in
+        database::zzz::users_achievement_lists_entries::get_by_username(&username, &pool).await?
+    {
+        entries.entry(entry.list).or_default().push(entry.id);
+    }
+
+    let lists: Vec<_> = database::zzz::users_achievement_lists::get_by_username(&username, &pool)
+        .await?
+        .into_iter()
+        .map(|l| AchievementList {
+            id: l.id,
+            name: l.name,
+            achievements: entries.remove(&l.id).unwrap_or_default(),
+        })
+        .collect();
+
+    Ok(HttpResponse::Ok().json(lists))
+}
+
+#[utoipa::path(
+    tag = "users/me/zzz/achievement-lists",
+    post,
+    path = "/api/users/me/zzz/achievement-lists",
+    request_body = AchievementListUpdate,
+    responses(
+        (status = 200, description = "AchievementList", body = AchievementList),
+        (status = 400, description = "Not logged in or invalid name"),
+    )
+)]
+#[post("/api/users/me/zzz/achievement-lists")]
+async fn post_zzz_user_achievement_lists(
+    session: Session,
+    list_update: web::Json<AchievementListUpdate>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    let Some(name) = list_update.name() else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    let db_list = database::zzz::users_achievement_lists::add(&username, name, &pool).await?;
+
+    let list = AchievementList {
+        id: db_list.id,
+        name: db_list.name,
+        achievements: Vec::new(),
+    };
+
+    Ok(HttpResponse::Ok().json(list))
+}
+
+#[utoipa::path(
+    tag = "users/me/zzz/achievement-lists",
+    put,
+    path = "/api/users/me/zzz/achievement-lists",
+    request_body(content = Vec<i32>, description = "List ids in the new order"),
+    responses(
+        (status = 200, description = "Reordered"),
+        (status = 400, description = "Not logged in"),
+    )
+)]
+#[put("/api/users/me/zzz/achievement-lists")]
+async fn put_zzz_user_achievement_lists(
+    session: Session,
+    ids: web::Json<Vec<i32>>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    database::zzz::users_achievement_lists::update_positions(&username, &ids, &pool).await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
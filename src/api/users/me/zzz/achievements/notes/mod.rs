@@ -0,0 +1,63 @@
+use actix_session::Session;
+use actix_web::{get, web, HttpResponse, Responder};
+use serde::Deserialize;
+use sqlx::PgPool;
+use utoipa::{IntoParams, OpenApi};
+
+use crate::{
+    api::{users::me::AchievementNote, ApiResult},
+    database,
+};
+
+#[derive(OpenApi)]
+#[openapi(
+    tags((name = "users/me/zzz/achievements/notes")),
+    paths(get_zzz_user_achievements_notes)
+)]
+struct ApiDoc;
+
+#[derive(Deserialize, IntoParams)]
+struct NotesParams {
+    query: Option<String>,
+}
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_zzz_user_achievements_notes);
+}
+
+#[utoipa::path(
+    tag = "users/me/zzz/achievements/notes",
+    get,
+    path = "/api/users/me/zzz/achievements/notes",
+    params(NotesParams),
+    responses(
+        (status = 200, description = "[AchievementNote]", body = Vec<AchievementNote>),
+        (status = 400, description = "Not logged in"),
+    )
+)]
+#[get("/api/users/me/zzz/achievements/notes")]
+async fn get_zzz_user_achievements_notes(
+    session: Session,
+    notes_params: web::Query<NotesParams>,
+    pool: web::Data<PgPool>,
+) -> ApiResult<impl Responder> {
+    let Ok(Some(username)) = session.get::<String>("username") else {
+        return Ok(HttpResponse::BadRequest().finish());
+    };
+
+    let notes: Vec<_> = database::zzz::users_achievements_notes::get_by_username(
+        &username,
+        notes_params.query.as_deref(),
+        &pool,
+    )
+    .await?
+    .into_iter()
+    .map(AchievementNote::from)
+    .collect();
+
+    Ok(HttpResponse::Ok().json(notes))
+}
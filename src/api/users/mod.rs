@@ -1,5 +1,5 @@
 mod auth;
-mod me;
+pub mod me;
 
 use actix_web::web;
 use utoipa::OpenApi;
@@ -1,24 +1,26 @@
 mod uid;
 
-use std::{collections::HashMap, sync::Arc, time::Duration};
-
 use actix_session::Session;
-use actix_web::{post, rt, web, HttpResponse, Responder};
-use chrono::NaiveDateTime;
-use futures::lock::Mutex;
+use actix_web::{post, web, HttpResponse, Responder};
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
-use strum::IntoEnumIterator;
-use url::Url;
 use utoipa::{OpenApi, ToSchema};
 
-use crate::{api::ApiResult, database, mihomo, GachaType, Language};
+use crate::{
+    api::{
+        import_jobs::{self, ImportInfoStatus},
+        ApiResult,
+    },
+    database,
+    gacha_import::{self, Hsr},
+    GachaType,
+};
 
 #[derive(OpenApi)]
 #[openapi(
     tags((name = "warps-import")),
     paths(post_warps_import),
-    components(schemas(WarpsImportParams, WarpsImport, WarpsImportInfo, Status))
+    components(schemas(WarpsImportParams, WarpsImport, WarpsImportInfo, ImportInfoStatus))
 )]
 struct ApiDoc;
 
@@ -28,65 +30,59 @@ pub fn openapi() -> utoipa::openapi::OpenApi {
     openapi
 }
 
-lazy_static::lazy_static! {
-    static ref DATA: web::Data<WarpsImportInfos> = web::Data::new(WarpsImportInfos::default());
-}
-
 pub fn configure(cfg: &mut web::ServiceConfig) {
-    cfg.app_data(DATA.clone())
-        .service(post_warps_import)
-        .configure(uid::configure);
-}
-
-#[derive(Deserialize)]
-struct GachaLog {
-    data: Data,
+    cfg.service(post_warps_import).configure(uid::configure);
 }
 
-#[derive(Deserialize)]
-struct Data {
-    list: Vec<Entry>,
-    region_time_zone: i64,
-}
-
-#[derive(Deserialize)]
-struct Entry {
-    id: String,
-    uid: String,
-    item_type: String,
-    item_id: String,
-    time: String,
+#[derive(Deserialize, ToSchema)]
+struct WarpsImportParams {
+    url: String,
 }
 
-type WarpsImportInfos = Mutex<HashMap<i32, Arc<Mutex<WarpsImportInfo>>>>;
-
-#[derive(Serialize, ToSchema, Clone)]
-#[serde(rename_all = "snake_case")]
-enum Status {
-    Pending,
-    Calculating,
-    Finished,
-    Error(String),
+#[derive(Serialize, ToSchema)]
+struct WarpsImport {
+    uid: i32,
+    /// Follow `/api/import-jobs/{id}/events` or poll `/api/import-jobs/{id}` for the progress
+    job: i32,
 }
 
-#[derive(Serialize, ToSchema, Clone)]
+/// Progress of the latest import, the shape from before imports were jobs.
+#[derive(Serialize, ToSchema)]
 struct WarpsImportInfo {
     gacha_type: GachaType,
     standard: usize,
     departure: usize,
     special: usize,
     lc: usize,
-    status: Status,
-}
-
-#[derive(Deserialize, ToSchema)]
-struct WarpsImportParams {
-    url: String,
-}
+    status: ImportInfoStatus,
+}
+
+impl WarpsImportInfo {
+    async fn new(
+        db_import_job: database::import_jobs::DbImportJob,
+        pool: &PgPool,
+    ) -> anyhow::Result<Self> {
+        let counts = import_jobs::counts(db_import_job.id, pool).await?;
+        let count = |gacha_type: GachaType| {
+            counts
+                .get(&gacha_type.to_string())
+                .map_or(0, |&count| count as usize)
+        };
 
-#[derive(Serialize, ToSchema)]
-struct WarpsImport {
-    uid: i32,
+        Ok(WarpsImportInfo {
+            gacha_type: db_import_job
+                .gacha_type
+                .as_deref()
+                .map(str::parse)
+                .transpose()?
+                .unwrap_or(GachaType::Standard),
+            standard: count(GachaType::Standard),
+            departure: count(GachaType::Departure),
+            special: count(GachaType::Special),
+            lc: count(GachaType::Lc),
+            status: ImportInfoStatus::new(&db_import_job)?,
+        })
+    }
 }
 
 #[utoipa::path(
@@ -102,469 +98,15 @@ struct WarpsImport {
 async fn post_warps_import(
     session: Session,
     params: web::Json<WarpsImportParams>,
-    warps_import_infos: web::Data<WarpsImportInfos>,
     pool: web::Data<PgPool>,
 ) -> ApiResult<impl Responder> {
-    let url = Url::parse(&params.url)?;
-
-    let query = url.query_pairs().filter(|(name, _)| {
-        matches!(
-            name.to_string().as_str(),
-            "authkey" | "authkey_ver" | "sign_type"
-        )
-    });
-
-    let mut url = Url::parse(
-        "https://public-operation-hkrpg-sg.hoyoverse.com/common/gacha_record/api/getGachaLog",
-    )?;
-
-    url.query_pairs_mut()
-        .extend_pairs(query)
-        .extend_pairs(&[("lang", "en"), ("game_biz", "hkrpg_global"), ("size", "20")])
-        .finish();
-
-    let mut uid = 0;
-
-    for gacha_type in [1, 2, 11, 12] {
-        let gacha_log: GachaLog = reqwest::get(format!("{url}&gacha_type={gacha_type}&end_id=0"))
-            .await?
-            .json()
-            .await?;
-
-        if let Some(entry) = gacha_log.data.list.first() {
-            uid = entry.uid.parse()?;
-            break;
-        }
-    }
-
-    if uid == 0 {
-        let info = Arc::new(Mutex::new(WarpsImportInfo {
-            gacha_type: GachaType::Standard,
-            standard: 0,
-            departure: 0,
-            special: 0,
-            lc: 0,
-            status: Status::Error("No data".to_string()),
-        }));
-
-        warps_import_infos.lock().await.insert(uid, info.clone());
-
-        return Ok(HttpResponse::Ok().json(WarpsImport { uid }));
-    }
-
-    // Wacky way to update the database in case the uid isn't in there
-    if !database::mihomo::exists(uid, &pool).await?
-        && mihomo::get(uid, Language::En, &pool).await.is_err()
-    {
-        let region = match uid.to_string().chars().next() {
-            Some('6') => "na",
-            Some('7') => "eu",
-            Some('8') | Some('9') => "asia",
-            _ => "cn",
-        }
-        .to_string();
-
-        let db_mihomo = database::mihomo::DbMihomo {
-            uid,
-            region,
-            ..Default::default()
-        };
-
-        database::mihomo::set(&db_mihomo, &pool).await?;
-    }
-
-    if let Ok(Some(username)) = session.get::<String>("username") {
-        let connection = database::connections::DbConnection {
-            uid,
-            username,
-            verified: true,
-            private: false,
-        };
-
-        database::connections::set(&connection, &pool).await?;
-    }
-
-    if warps_import_infos.lock().await.contains_key(&uid) {
-        return Ok(HttpResponse::Ok().json(WarpsImport { uid }));
-    }
-
-    let info = Arc::new(Mutex::new(WarpsImportInfo {
-        gacha_type: GachaType::Standard,
-        standard: 0,
-        departure: 0,
-        special: 0,
-        lc: 0,
-        status: Status::Pending,
-    }));
-
-    warps_import_infos.lock().await.insert(uid, info.clone());
-
-    rt::spawn(async move {
-        let mut error = Ok(());
-
-        for gacha_type in GachaType::iter() {
-            info.lock().await.gacha_type = gacha_type;
-
-            if let Err(e) = import_warps(&url, gacha_type, &info, &pool).await {
-                error = Err(e);
-
-                break;
-            }
-        }
-
-        if let Err(e) = error {
-            info.lock().await.status = Status::Error(e.to_string());
-        } else if let Err(e) = calculate_stats(uid, &info, &pool).await {
-            info.lock().await.status = Status::Error(e.to_string());
-        } else {
-            info.lock().await.status = Status::Finished;
-        }
-
-        rt::spawn(async move {
-            rt::time::sleep(Duration::from_secs(60)).await;
-
-            warps_import_infos.lock().await.remove(&uid);
-        });
-    });
-
-    Ok(HttpResponse::Ok().json(WarpsImport { uid }))
-}
-
-async fn import_warps(
-    url: &Url,
-    gacha_type: GachaType,
-    info: &Arc<Mutex<WarpsImportInfo>>,
-    pool: &PgPool,
-) -> ApiResult<()> {
-    let mut url = url.clone();
-    let mut end_id = "0".to_string();
-
-    url.query_pairs_mut()
-        .extend_pairs(&[(
-            "gacha_type",
-            match gacha_type {
-                GachaType::Standard => "1",
-                GachaType::Departure => "2",
-                GachaType::Special => "11",
-                GachaType::Lc => "12",
-            },
-        )])
-        .finish();
-
-    let mut set_all = database::warps::SetAll::default();
-
-    loop {
-        let mut i = 0;
-        let gacha_log = loop {
-            let response = reqwest::get(format!("{url}&end_id={end_id}")).await?;
-            if let Ok(gacha_log) = response.json::<GachaLog>().await {
-                break gacha_log;
-            }
-
-            if i > 2 {
-                return Err(anyhow::anyhow!("Unsure").into());
-            }
-
-            rt::time::sleep(Duration::from_secs(1)).await;
-
-            i += 1;
-        };
-
-        if gacha_log.data.list.is_empty() {
-            break;
-        }
-
-        let timestamp_offset = chrono::Duration::hours(gacha_log.data.region_time_zone);
-
-        for entry in gacha_log.data.list {
-            end_id.clone_from(&entry.id);
-
-            let id = entry.id.parse()?;
-            let uid: i32 = entry.uid.parse()?;
-
-            let exists = match gacha_type {
-                GachaType::Departure => database::warps::departure::exists(id, uid, pool).await?,
-                GachaType::Standard => database::warps::standard::exists(id, uid, pool).await?,
-                GachaType::Special => database::warps::special::exists(id, uid, pool).await?,
-                GachaType::Lc => database::warps::lc::exists(id, uid, pool).await?,
-            };
-
-            if exists {
-                continue;
-            }
-
-            let item: i32 = entry.item_id.parse()?;
-
-            let mut character =
-                (entry.item_type == "Character" || entry.item_type == "角色").then_some(item);
-            let mut light_cone =
-                (entry.item_type == "Light Cone" || entry.item_type == "光錐").then_some(item);
-
-            if character.is_none() && light_cone.is_none() {
-                if item >= 20000 {
-                    light_cone = Some(item);
-                } else if item <= 10000 {
-                    character = Some(item);
-                } else {
-                    return Err(anyhow::anyhow!("{} is weird...", entry.item_type).into());
-                }
-            }
-
-            let timestamp = NaiveDateTime::parse_from_str(&entry.time, "%Y-%m-%d %H:%M:%S")?
-                .and_utc()
-                - timestamp_offset;
-
-            set_all.id.push(id);
-            set_all.uid.push(uid);
-            set_all.character.push(character);
-            set_all.light_cone.push(light_cone);
-            set_all.timestamp.push(timestamp);
-            set_all.official.push(true);
-
-            match gacha_type {
-                GachaType::Standard => info.lock().await.standard += 1,
-                GachaType::Departure => info.lock().await.departure += 1,
-                GachaType::Special => info.lock().await.special += 1,
-                GachaType::Lc => info.lock().await.lc += 1,
-            }
-        }
-    }
-
-    match gacha_type {
-        GachaType::Departure => database::warps::departure::set_all(&set_all, pool).await?,
-        GachaType::Standard => database::warps::standard::set_all(&set_all, pool).await?,
-        GachaType::Special => database::warps::special::set_all(&set_all, pool).await?,
-        GachaType::Lc => database::warps::lc::set_all(&set_all, pool).await?,
-    };
-
-    Ok(())
-}
-
-async fn calculate_stats(
-    uid: i32,
-    info: &Arc<Mutex<WarpsImportInfo>>,
-    pool: &PgPool,
-) -> anyhow::Result<()> {
-    info.lock().await.status = Status::Calculating;
-
-    info.lock().await.gacha_type = GachaType::Standard;
-    calculate_stats_standard(uid, pool).await?;
-    info.lock().await.gacha_type = GachaType::Special;
-    calculate_stats_special(uid, pool).await?;
-    info.lock().await.gacha_type = GachaType::Lc;
-    calculate_stats_lc(uid, pool).await?;
-
-    Ok(())
-}
-
-async fn calculate_stats_standard(uid: i32, pool: &PgPool) -> anyhow::Result<()> {
-    let warps = database::warps::standard::get_infos_by_uid(uid, pool).await?;
-
-    let mut pull_4 = 0;
-    let mut sum_4 = 0;
-    let mut count_4 = 0;
-
-    let mut pull_5 = 0;
-    let mut sum_5 = 0;
-    let mut count_5 = 0;
-
-    for warp in &warps {
-        pull_4 += 1;
-        pull_5 += 1;
-
-        match warp.rarity.unwrap() {
-            4 => {
-                count_4 += 1;
-                sum_4 += pull_4;
-                pull_4 = 0;
-            }
-            5 => {
-                count_5 += 1;
-                sum_5 += pull_5;
-                pull_5 = 0;
-            }
-            _ => {}
-        }
-    }
-
-    let luck_4 = sum_4 as f64 / count_4 as f64;
-    let luck_5 = sum_5 as f64 / count_5 as f64;
-
-    let stat = database::warps_stats::standard::DbWarpsStatStandard {
-        uid,
-        luck_4,
-        luck_5,
-    };
-    database::warps_stats::standard::set(&stat, pool).await?;
-
-    Ok(())
-}
-
-async fn calculate_stats_special(uid: i32, pool: &PgPool) -> anyhow::Result<()> {
-    let warps = database::warps::special::get_infos_by_uid(uid, pool).await?;
-
-    let mut pull_4 = 0;
-    let mut sum_4 = 0;
-    let mut count_4 = 0;
-
-    let mut pull_5 = 0;
-    let mut sum_5 = 0;
-    let mut count_5 = 0;
-
-    let mut guarantee = false;
-
-    let mut sum_win = 0;
-    let mut count_win = 0;
-
-    let mut win_streak = 0;
-    let mut max_win_streak = 0;
-
-    let mut loss_streak = 0;
-    let mut max_loss_streak = 0;
-
-    for warp in &warps {
-        pull_4 += 1;
-        pull_5 += 1;
-
-        match warp.rarity.unwrap() {
-            4 => {
-                count_4 += 1;
-                sum_4 += pull_4;
-                pull_4 = 0;
-            }
-            5 => {
-                count_5 += 1;
-                sum_5 += pull_5;
-                pull_5 = 0;
-
-                if guarantee {
-                    guarantee = false;
-                } else {
-                    count_win += 1;
-
-                    if [1209, 1004, 1101, 1211, 1104, 1107, 1003].contains(&warp.character.unwrap())
-                    {
-                        win_streak = 0;
-
-                        loss_streak += 1;
-                        max_loss_streak = max_loss_streak.max(loss_streak);
-
-                        guarantee = true;
-                    } else {
-                        sum_win += 1;
-
-                        loss_streak = 0;
-
-                        win_streak += 1;
-                        max_win_streak = max_win_streak.max(win_streak);
-                    }
-                }
-            }
-            _ => {}
-        }
-    }
-
-    let win_streak = max_win_streak;
-    let loss_streak = max_loss_streak;
-
-    let luck_4 = sum_4 as f64 / count_4 as f64;
-    let luck_5 = sum_5 as f64 / count_5 as f64;
-    let win_rate = sum_win as f64 / count_win as f64;
-
-    let stat = database::warps_stats::special::DbWarpsStatSpecial {
-        uid,
-        luck_4,
-        luck_5,
-        win_rate,
-        win_streak,
-        loss_streak,
-    };
-    database::warps_stats::special::set(&stat, pool).await?;
-
-    Ok(())
-}
-
-async fn calculate_stats_lc(uid: i32, pool: &PgPool) -> anyhow::Result<()> {
-    let warps = database::warps::lc::get_infos_by_uid(uid, pool).await?;
-
-    let mut pull_4 = 0;
-    let mut sum_4 = 0;
-    let mut count_4 = 0;
-
-    let mut pull_5 = 0;
-    let mut sum_5 = 0;
-    let mut count_5 = 0;
-
-    let mut guarantee = false;
-
-    let mut sum_win = 0;
-    let mut count_win = 0;
-
-    let mut win_streak = 0;
-    let mut max_win_streak = 0;
-
-    let mut loss_streak = 0;
-    let mut max_loss_streak = 0;
-
-    for warp in &warps {
-        pull_4 += 1;
-        pull_5 += 1;
-
-        match warp.rarity.unwrap() {
-            4 => {
-                count_4 += 1;
-                sum_4 += pull_4;
-                pull_4 = 0;
-            }
-            5 => {
-                count_5 += 1;
-                sum_5 += pull_5;
-                pull_5 = 0;
-
-                if guarantee {
-                    guarantee = false;
-                } else {
-                    count_win += 1;
-
-                    if [23000, 23002, 23003, 23004, 23005, 23012, 23013]
-                        .contains(&warp.light_cone.unwrap())
-                    {
-                        win_streak = 0;
-
-                        loss_streak += 1;
-                        max_loss_streak = max_loss_streak.max(loss_streak);
-
-                        guarantee = true;
-                    } else {
-                        sum_win += 1;
-
-                        loss_streak = 0;
-
-                        win_streak += 1;
-                        max_win_streak = max_win_streak.max(win_streak);
-                    }
-                }
-            }
-            _ => {}
-        }
-    }
+    let url = gacha_import::url::<Hsr>(&params.url)?;
 
-    let win_streak = max_win_streak;
-    let loss_streak = max_loss_streak;
+    let uid = gacha_import::uid::<Hsr>(&url).await?;
 
-    let luck_4 = sum_4 as f64 / count_4 as f64;
-    let luck_5 = sum_5 as f64 / count_5 as f64;
-    let win_rate = sum_win as f64 / count_win as f64;
+    let username = session.get::<String>("username").ok().flatten();
 
-    let stat = database::warps_stats::lc::DbWarpsStatLc {
-        uid,
-        luck_4,
-        luck_5,
-        win_rate,
-        win_streak,
-        loss_streak,
-    };
-    database::warps_stats::lc::set(&stat, pool).await?;
+    let job = gacha_import::import::<Hsr>(uid, url, username, &pool).await?;
 
-    Ok(())
+    Ok(HttpResponse::Ok().json(WarpsImport { uid, job }))
 }
@@ -1,7 +1,9 @@
 use actix_web::{get, web, HttpResponse, Responder};
+use sqlx::PgPool;
 use utoipa::OpenApi;
 
-use crate::api::{warps_import::WarpsImportInfos, ApiResult};
+use super::WarpsImportInfo;
+use crate::{api::ApiResult, database, Game};
 
 #[derive(OpenApi)]
 #[openapi(
@@ -23,19 +25,22 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
     get,
     path = "/api/warps-import/{uid}",
     responses(
-        (status = 200, description = "WarpsImportInfo", body = WarpsImportInfo)
+        (status = 200, description = "WarpsImportInfo of the latest import", body = WarpsImportInfo),
+        (status = 400, description = "No import yet"),
     )
 )]
 #[get("/api/warps-import/{uid}")]
 async fn get_warps_import(
     uid: web::Path<i32>,
-    warps_import_infos: web::Data<WarpsImportInfos>,
+    pool: web::Data<PgPool>,
 ) -> ApiResult<impl Responder> {
-    let Some(info) = warps_import_infos.lock().await.get(&*uid).cloned() else {
+    let Some(db_import_job) =
+        database::import_jobs::get_one_latest_by_game_and_uid(Game::Hsr, *uid, &pool).await?
+    else {
         return Ok(HttpResponse::BadRequest().finish());
     };
 
-    let info = info.lock().await.clone();
+    let info = WarpsImportInfo::new(db_import_job, &pool).await?;
 
     Ok(HttpResponse::Ok().json(info))
 }
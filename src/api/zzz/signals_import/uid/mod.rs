@@ -1,7 +1,9 @@
 use actix_web::{get, web, HttpResponse, Responder};
+use sqlx::PgPool;
 use utoipa::OpenApi;
 
-use crate::api::{zzz::signals_import::SignalsImportInfos, ApiResult};
+use super::SignalsImportInfo;
+use crate::{api::ApiResult, database, Game};
 
 #[derive(OpenApi)]
 #[openapi(
@@ -23,19 +25,22 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
     get,
     path = "/api/zzz/signals-import/{uid}",
     responses(
-        (status = 200, description = "SignalsImportInfo", body = SignalsImportInfo)
+        (status = 200, description = "SignalsImportInfo of the latest import", body = SignalsImportInfo),
+        (status = 400, description = "No import yet"),
     )
 )]
 #[get("/api/zzz/signals-import/{uid}")]
 async fn get_zzz_signals_import(
     uid: web::Path<i32>,
-    signals_import_infos: web::Data<SignalsImportInfos>,
+    pool: web::Data<PgPool>,
 ) -> ApiResult<impl Responder> {
-    let Some(info) = signals_import_infos.lock().await.get(&*uid).cloned() else {
+    let Some(db_import_job) =
+        database::import_jobs::get_one_latest_by_game_and_uid(Game::Zzz, *uid, &pool).await?
+    else {
         return Ok(HttpResponse::BadRequest().finish());
     };
 
-    let info = info.lock().await.clone();
+    let info = SignalsImportInfo::new(db_import_job, &pool).await?;
 
     Ok(HttpResponse::Ok().json(info))
 }
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 
 use crate::Language;
 
@@ -25,6 +25,30 @@ pub struct DbAchievement {
     pub percent: f64,
 }
 
+/// English texts of every achievement, including ones without a percent yet.
+pub struct DbAchievementSnapshot {
+    pub id: i32,
+    pub series: i32,
+    pub series_name: String,
+    pub name: String,
+    pub description: String,
+    pub jades: i32,
+}
+
+#[derive(Clone)]
+pub struct DbCuratedAchievement {
+    pub id: i32,
+    pub version: Option<String>,
+    pub comment: Option<String>,
+    pub reference: Option<String>,
+    pub difficulty: Option<String>,
+    pub video: Option<String>,
+    pub gacha: bool,
+    pub timegated: bool,
+    pub missable: bool,
+    pub impossible: bool,
+}
+
 pub async fn select_all(username: &str, pool: &PgPool) -> Result<()> {
     sqlx::query_file!("sql/achievements/select_all.sql", username)
         .execute(pool)
@@ -33,12 +57,14 @@ pub async fn select_all(username: &str, pool: &PgPool) -> Result<()> {
     Ok(())
 }
 
+/// New achievements get the version, existing ones keep theirs.
 pub async fn set_all(
     id: &[i32],
     series: &[i32],
     jades: &[i32],
     hidden: &[bool],
     priority: &[i32],
+    version: Option<&str>,
     pool: &PgPool,
 ) -> Result<()> {
     sqlx::query_file!(
@@ -48,6 +74,7 @@ pub async fn set_all(
         jades,
         hidden,
         priority,
+        version,
     )
     .execute(pool)
     .await?;
@@ -65,6 +92,17 @@ pub async fn get_all(language: Language, pool: &PgPool) -> Result<Vec<DbAchievem
     )
 }
 
+pub async fn get_existing_ids(ids: &[i32], pool: &PgPool) -> Result<Vec<i32>> {
+    Ok(
+        sqlx::query_file!("sql/achievements/get_existing_ids.sql", ids)
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|r| r.id)
+            .collect(),
+    )
+}
+
 pub async fn get_all_ids_shown(pool: &PgPool) -> Result<Vec<i32>> {
     Ok(sqlx::query_file!("sql/achievements/get_all_ids_shown.sql")
         .fetch_all(pool)
@@ -98,6 +136,70 @@ pub async fn get_one_by_id(id: i32, language: Language, pool: &PgPool) -> Result
     .await?)
 }
 
+pub async fn get_all_curated(
+    language: Language,
+    pool: &PgPool,
+) -> Result<Vec<DbCuratedAchievement>> {
+    let language = language.to_string();
+
+    Ok(sqlx::query_file_as!(
+        DbCuratedAchievement,
+        "sql/achievements/get_all_curated.sql",
+        language,
+    )
+    .fetch_all(pool)
+    .await?)
+}
+
+/// Only the english entries update the language independent fields.
+pub async fn update_all_curated(
+    achievements: &[(Language, DbCuratedAchievement)],
+    pool: &PgPool,
+) -> Result<()> {
+    let id: Vec<_> = achievements.iter().map(|(_, a)| a.id).collect();
+    let language: Vec<_> = achievements.iter().map(|(l, _)| l.to_string()).collect();
+    let version: Vec<_> = achievements
+        .iter()
+        .map(|(_, a)| a.version.clone())
+        .collect();
+    let comment: Vec<_> = achievements
+        .iter()
+        .map(|(_, a)| a.comment.clone())
+        .collect();
+    let reference: Vec<_> = achievements
+        .iter()
+        .map(|(_, a)| a.reference.clone())
+        .collect();
+    let difficulty: Vec<_> = achievements
+        .iter()
+        .map(|(_, a)| a.difficulty.clone())
+        .collect();
+    let video: Vec<_> = achievements.iter().map(|(_, a)| a.video.clone()).collect();
+    let gacha: Vec<_> = achievements.iter().map(|(_, a)| a.gacha).collect();
+    let timegated: Vec<_> = achievements.iter().map(|(_, a)| a.timegated).collect();
+    let missable: Vec<_> = achievements.iter().map(|(_, a)| a.missable).collect();
+    let impossible: Vec<_> = achievements.iter().map(|(_, a)| a.impossible).collect();
+
+    sqlx::query_file!(
+        "sql/achievements/update_all_curated.sql",
+        &id,
+        &language,
+        &version as &[Option<String>],
+        &comment as &[Option<String>],
+        &reference as &[Option<String>],
+        &difficulty as &[Option<String>],
+        &video as &[Option<String>],
+        &gacha,
+        &timegated,
+        &missable,
+        &impossible,
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
 pub async fn update_version_by_id(id: i32, version: &str, pool: &PgPool) -> Result<()> {
     sqlx::query_file!("sql/achievements/update_version_by_id.sql", id, version)
         .execute(pool)
@@ -106,18 +208,42 @@ pub async fn update_version_by_id(id: i32, version: &str, pool: &PgPool) -> Resu
     Ok(())
 }
 
-pub async fn update_comment_by_id(id: i32, comment: &str, pool: &PgPool) -> Result<()> {
-    sqlx::query_file!("sql/achievements/update_comment_by_id.sql", id, comment)
-        .execute(pool)
-        .await?;
+pub async fn update_comment_by_id(
+    id: i32,
+    language: Language,
+    comment: &str,
+    connection: &mut PgConnection,
+) -> Result<()> {
+    let language = language.to_string();
+
+    sqlx::query_file!(
+        "sql/achievements/update_comment_by_id.sql",
+        id,
+        language,
+        comment,
+    )
+    .execute(connection)
+    .await?;
 
     Ok(())
 }
 
-pub async fn update_reference_by_id(id: i32, reference: &str, pool: &PgPool) -> Result<()> {
-    sqlx::query_file!("sql/achievements/update_reference_by_id.sql", id, reference)
-        .execute(pool)
-        .await?;
+pub async fn update_reference_by_id(
+    id: i32,
+    language: Language,
+    reference: &str,
+    connection: &mut PgConnection,
+) -> Result<()> {
+    let language = language.to_string();
+
+    sqlx::query_file!(
+        "sql/achievements/update_reference_by_id.sql",
+        id,
+        language,
+        reference,
+    )
+    .execute(connection)
+    .await?;
 
     Ok(())
 }
@@ -142,22 +268,6 @@ pub async fn update_gacha_by_id(id: i32, gacha: bool, pool: &PgPool) -> Result<(
     Ok(())
 }
 
-pub async fn update_timegated_by_id(id: i32, timegated: bool, pool: &PgPool) -> Result<()> {
-    sqlx::query_file!("sql/achievements/update_timegated_by_id.sql", id, timegated)
-        .execute(pool)
-        .await?;
-
-    Ok(())
-}
-
-pub async fn update_missable_by_id(id: i32, missable: bool, pool: &PgPool) -> Result<()> {
-    sqlx::query_file!("sql/achievements/update_missable_by_id.sql", id, missable)
-        .execute(pool)
-        .await?;
-
-    Ok(())
-}
-
 pub async fn update_impossible_by_id(id: i32, impossible: bool, pool: &PgPool) -> Result<()> {
     sqlx::query_file!(
         "sql/achievements/update_impossible_by_id.sql",
@@ -170,10 +280,22 @@ pub async fn update_impossible_by_id(id: i32, impossible: bool, pool: &PgPool) -
     Ok(())
 }
 
-pub async fn update_video_by_id(id: i32, video: &str, pool: &PgPool) -> Result<()> {
-    sqlx::query_file!("sql/achievements/update_video_by_id.sql", id, video)
-        .execute(pool)
-        .await?;
+pub async fn update_video_by_id(
+    id: i32,
+    language: Language,
+    video: &str,
+    connection: &mut PgConnection,
+) -> Result<()> {
+    let language = language.to_string();
+
+    sqlx::query_file!(
+        "sql/achievements/update_video_by_id.sql",
+        id,
+        language,
+        video,
+    )
+    .execute(connection)
+    .await?;
 
     Ok(())
 }
@@ -186,16 +308,20 @@ pub async fn delete_version_by_id(id: i32, pool: &PgPool) -> Result<()> {
     Ok(())
 }
 
-pub async fn delete_comment_by_id(id: i32, pool: &PgPool) -> Result<()> {
-    sqlx::query_file!("sql/achievements/delete_comment_by_id.sql", id)
+pub async fn delete_comment_by_id(id: i32, language: Language, pool: &PgPool) -> Result<()> {
+    let language = language.to_string();
+
+    sqlx::query_file!("sql/achievements/delete_comment_by_id.sql", id, language)
         .execute(pool)
         .await?;
 
     Ok(())
 }
 
-pub async fn delete_reference_by_id(id: i32, pool: &PgPool) -> Result<()> {
-    sqlx::query_file!("sql/achievements/delete_reference_by_id.sql", id)
+pub async fn delete_reference_by_id(id: i32, language: Language, pool: &PgPool) -> Result<()> {
+    let language = language.to_string();
+
+    sqlx::query_file!("sql/achievements/delete_reference_by_id.sql", id, language)
         .execute(pool)
         .await?;
 
@@ -210,10 +336,20 @@ pub async fn delete_difficulty_by_id(id: i32, pool: &PgPool) -> Result<()> {
     Ok(())
 }
 
-pub async fn delete_video_by_id(id: i32, pool: &PgPool) -> Result<()> {
-    sqlx::query_file!("sql/achievements/delete_video_by_id.sql", id)
+pub async fn delete_video_by_id(id: i32, language: Language, pool: &PgPool) -> Result<()> {
+    let language = language.to_string();
+
+    sqlx::query_file!("sql/achievements/delete_video_by_id.sql", id, language)
         .execute(pool)
         .await?;
 
     Ok(())
 }
+
+pub async fn get_snapshot(pool: &PgPool) -> Result<Vec<DbAchievementSnapshot>> {
+    Ok(
+        sqlx::query_file_as!(DbAchievementSnapshot, "sql/achievements/get_snapshot.sql")
+            .fetch_all(pool)
+            .await?,
+    )
+}
@@ -0,0 +1,60 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+pub struct DbAchievementChange {
+    pub commit_hash: String,
+    pub timestamp: DateTime<Utc>,
+    pub kind: String,
+    pub id: i32,
+    pub change: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+pub async fn set_all(
+    commit_hash: &str,
+    kind: &[String],
+    id: &[i32],
+    change: &[String],
+    old_value: &[Option<String>],
+    new_value: &[Option<String>],
+    pool: &PgPool,
+) -> Result<()> {
+    sqlx::query_file!(
+        "sql/achievements_changelog/set_all.sql",
+        commit_hash,
+        kind,
+        id,
+        change,
+        old_value as &[Option<String>],
+        new_value as &[Option<String>],
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn get_all(pool: &PgPool) -> Result<Vec<DbAchievementChange>> {
+    Ok(sqlx::query_file_as!(
+        DbAchievementChange,
+        "sql/achievements_changelog/get_all.sql"
+    )
+    .fetch_all(pool)
+    .await?)
+}
+
+pub struct DbRemoved {
+    pub kind: String,
+    pub id: i32,
+}
+
+/// Everything whose latest change is a removal.
+pub async fn get_removed(pool: &PgPool) -> Result<Vec<DbRemoved>> {
+    Ok(
+        sqlx::query_file_as!(DbRemoved, "sql/achievements_changelog/get_removed.sql")
+            .fetch_all(pool)
+            .await?,
+    )
+}
@@ -0,0 +1,45 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+pub struct DbDifficultyVote {
+    pub username: String,
+    pub id: i32,
+    pub difficulty: String,
+}
+
+pub struct DbDifficultyDistribution {
+    pub easy: Option<i64>,
+    pub medium: Option<i64>,
+    pub hard: Option<i64>,
+}
+
+pub async fn set(vote: &DbDifficultyVote, pool: &PgPool) -> Result<()> {
+    sqlx::query_file!(
+        "sql/achievements_difficulty_votes/set.sql",
+        vote.username,
+        vote.id,
+        vote.difficulty,
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn delete(username: &str, id: i32, pool: &PgPool) -> Result<()> {
+    sqlx::query_file!("sql/achievements_difficulty_votes/delete.sql", username, id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_distribution_by_id(id: i32, pool: &PgPool) -> Result<DbDifficultyDistribution> {
+    Ok(sqlx::query_file_as!(
+        DbDifficultyDistribution,
+        "sql/achievements_difficulty_votes/get_distribution_by_id.sql",
+        id,
+    )
+    .fetch_one(pool)
+    .await?)
+}
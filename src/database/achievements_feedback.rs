@@ -0,0 +1,87 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool};
+
+pub struct DbAchievementFeedback {
+    pub id: i32,
+    pub achievement: i32,
+    pub username: String,
+    pub language: String,
+    pub field: String,
+    pub content: String,
+    pub status: String,
+    pub created_at: DateTime<Utc>,
+    pub moderated_by: Option<String>,
+    pub moderated_at: Option<DateTime<Utc>>,
+}
+
+pub async fn add(
+    achievement: i32,
+    username: &str,
+    language: &str,
+    field: &str,
+    content: &str,
+    pool: &PgPool,
+) -> Result<i32> {
+    Ok(sqlx::query_file!(
+        "sql/achievements_feedback/add.sql",
+        achievement,
+        username,
+        language,
+        field,
+        content,
+    )
+    .fetch_one(pool)
+    .await?
+    .id)
+}
+
+pub async fn get_by_status(status: &str, pool: &PgPool) -> Result<Vec<DbAchievementFeedback>> {
+    Ok(sqlx::query_file_as!(
+        DbAchievementFeedback,
+        "sql/achievements_feedback/get_by_status.sql",
+        status,
+    )
+    .fetch_all(pool)
+    .await?)
+}
+
+pub async fn get_one_by_id(id: i32, pool: &PgPool) -> Result<Option<DbAchievementFeedback>> {
+    Ok(sqlx::query_file_as!(
+        DbAchievementFeedback,
+        "sql/achievements_feedback/get_one_by_id.sql",
+        id,
+    )
+    .fetch_optional(pool)
+    .await?)
+}
+
+pub async fn count_pending_by_username(username: &str, pool: &PgPool) -> Result<i64> {
+    Ok(sqlx::query_file!(
+        "sql/achievements_feedback/count_pending_by_username.sql",
+        username,
+    )
+    .fetch_one(pool)
+    .await?
+    .count
+    .unwrap())
+}
+
+/// Only pending feedback can be moderated, returns whether it was.
+pub async fn update_status_by_id(
+    id: i32,
+    status: &str,
+    moderated_by: &str,
+    connection: &mut PgConnection,
+) -> Result<bool> {
+    Ok(sqlx::query_file!(
+        "sql/achievements_feedback/update_status_by_id.sql",
+        id,
+        status,
+        moderated_by,
+    )
+    .execute(connection)
+    .await?
+    .rows_affected()
+        == 1)
+}
@@ -1,7 +1,7 @@
 use anyhow::Result;
 use sqlx::PgPool;
 
-const THRESHOLD: i64 = 300;
+pub const THRESHOLD: i64 = 300;
 
 pub async fn update(pool: &PgPool) -> Result<()> {
     sqlx::query_file!("sql/achievements_percent/update.sql", THRESHOLD)
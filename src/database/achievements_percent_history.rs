@@ -0,0 +1,32 @@
+use anyhow::Result;
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use super::achievements_percent::THRESHOLD;
+
+pub struct DbAchievementPercentHistory {
+    pub date: NaiveDate,
+    pub region: String,
+    pub percent: f64,
+}
+
+/// Snapshots the current percentages for today, later runs on the same day overwrite it.
+///
+/// Regions are per profile, the default profile (uid 0) counts for all connected regions.
+pub async fn update(pool: &PgPool) -> Result<()> {
+    sqlx::query_file!("sql/achievements_percent_history/update.sql", THRESHOLD)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_by_id(id: i32, pool: &PgPool) -> Result<Vec<DbAchievementPercentHistory>> {
+    Ok(sqlx::query_file_as!(
+        DbAchievementPercentHistory,
+        "sql/achievements_percent_history/get_by_id.sql",
+        id,
+    )
+    .fetch_all(pool)
+    .await?)
+}
@@ -0,0 +1,58 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+pub struct DbBanner {
+    pub id: i32,
+    pub gacha_type: String,
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+    pub rate_up_5: Vec<i32>,
+    pub rate_up_4: Vec<i32>,
+}
+
+pub async fn set(banner: &DbBanner, pool: &PgPool) -> Result<()> {
+    sqlx::query_file!(
+        "sql/banners/set.sql",
+        banner.id,
+        banner.gacha_type,
+        banner.start_time,
+        banner.end_time,
+        &banner.rate_up_5,
+        &banner.rate_up_4,
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn delete_by_id(id: i32, pool: &PgPool) -> Result<()> {
+    sqlx::query_file!("sql/banners/delete_by_id.sql", id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_all(pool: &PgPool) -> Result<Vec<DbBanner>> {
+    Ok(sqlx::query_file_as!(DbBanner, "sql/banners/get_all.sql")
+        .fetch_all(pool)
+        .await?)
+}
+
+pub async fn get_by_gacha_type(gacha_type: &str, pool: &PgPool) -> Result<Vec<DbBanner>> {
+    Ok(
+        sqlx::query_file_as!(DbBanner, "sql/banners/get_by_gacha_type.sql", gacha_type)
+            .fetch_all(pool)
+            .await?,
+    )
+}
+
+pub async fn get_one_by_id(id: i32, pool: &PgPool) -> Result<Option<DbBanner>> {
+    Ok(
+        sqlx::query_file_as!(DbBanner, "sql/banners/get_one_by_id.sql", id)
+            .fetch_optional(pool)
+            .await?,
+    )
+}
@@ -36,6 +36,15 @@ pub async fn set_all(id: &[i32], rarity: &[i32], pool: &PgPool) -> Result<()> {
     Ok(())
 }
 
+pub async fn get_ids(pool: &PgPool) -> Result<Vec<i32>> {
+    Ok(sqlx::query_file!("sql/characters/get_ids.sql")
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .map(|r| r.id)
+        .collect())
+}
+
 pub async fn get_all(language: Language, pool: &PgPool) -> Result<Vec<DbCharacter>> {
     let language = language.to_string();
 
@@ -98,3 +107,26 @@ pub async fn get_by_id(id: i32, language: Language, pool: &PgPool) -> Result<DbC
     .fetch_one(pool)
     .await?)
 }
+
+pub struct DbOwnedCharacter {
+    pub id: i32,
+    pub name: String,
+    pub owned: Option<bool>,
+}
+
+pub async fn get_all_owned_by_username(
+    username: &str,
+    language: Language,
+    pool: &PgPool,
+) -> Result<Vec<DbOwnedCharacter>> {
+    let language = language.to_string();
+
+    Ok(sqlx::query_file_as!(
+        DbOwnedCharacter,
+        "sql/characters/get_all_owned_by_username.sql",
+        username,
+        language,
+    )
+    .fetch_all(pool)
+    .await?)
+}
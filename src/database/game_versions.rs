@@ -0,0 +1,49 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::Game;
+
+pub struct DbGameVersion {
+    pub game: String,
+    pub version: String,
+}
+
+pub async fn set(game_version: &DbGameVersion, pool: &PgPool) -> Result<()> {
+    sqlx::query_file!(
+        "sql/game_versions/set.sql",
+        game_version.game,
+        game_version.version,
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn delete(game: Game, pool: &PgPool) -> Result<()> {
+    let game = game.to_string();
+
+    sqlx::query_file!("sql/game_versions/delete.sql", game)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_all(pool: &PgPool) -> Result<Vec<DbGameVersion>> {
+    Ok(
+        sqlx::query_file_as!(DbGameVersion, "sql/game_versions/get_all.sql")
+            .fetch_all(pool)
+            .await?,
+    )
+}
+
+pub async fn get_one_by_game(game: Game, pool: &PgPool) -> Result<Option<DbGameVersion>> {
+    let game = game.to_string();
+
+    Ok(
+        sqlx::query_file_as!(DbGameVersion, "sql/game_versions/get_one_by_game.sql", game)
+            .fetch_optional(pool)
+            .await?,
+    )
+}
@@ -25,12 +25,24 @@ pub struct DbAchievement {
     pub percent: f64,
 }
 
+/// English texts of every achievement, including ones without a percent yet.
+pub struct DbAchievementSnapshot {
+    pub id: i32,
+    pub series: i32,
+    pub series_name: String,
+    pub name: String,
+    pub description: String,
+    pub primogems: i32,
+}
+
+/// New achievements get the version, existing ones keep theirs.
 pub async fn set_all(
     id: &[i32],
     series: &[i32],
     primogems: &[i32],
     hidden: &[bool],
     priority: &[i32],
+    version: Option<&str>,
     pool: &PgPool,
 ) -> Result<()> {
     sqlx::query_file!(
@@ -40,6 +52,7 @@ pub async fn set_all(
         primogems,
         hidden,
         priority,
+        version,
     )
     .execute(pool)
     .await?;
@@ -81,6 +94,17 @@ pub async fn get_all_related_ids(id: i32, set: i32, pool: &PgPool) -> Result<Vec
     )
 }
 
+pub async fn get_existing_ids(ids: &[i32], pool: &PgPool) -> Result<Vec<i32>> {
+    Ok(
+        sqlx::query_file!("sql/gi/achievements/get_existing_ids.sql", ids)
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|r| r.id)
+            .collect(),
+    )
+}
+
 pub async fn get_all_ids_shown(pool: &PgPool) -> Result<Vec<i32>> {
     Ok(
         sqlx::query_file!("sql/gi/achievements/get_all_ids_shown.sql")
@@ -100,10 +124,22 @@ pub async fn update_version_by_id(id: i32, version: &str, pool: &PgPool) -> Resu
     Ok(())
 }
 
-pub async fn update_comment_by_id(id: i32, comment: &str, pool: &PgPool) -> Result<()> {
-    sqlx::query_file!("sql/gi/achievements/update_comment_by_id.sql", id, comment)
-        .execute(pool)
-        .await?;
+pub async fn update_comment_by_id(
+    id: i32,
+    language: Language,
+    comment: &str,
+    pool: &PgPool,
+) -> Result<()> {
+    let language = language.to_string();
+
+    sqlx::query_file!(
+        "sql/gi/achievements/update_comment_by_id.sql",
+        id,
+        language,
+        comment,
+    )
+    .execute(pool)
+    .await?;
 
     Ok(())
 }
@@ -192,8 +228,10 @@ pub async fn update_impossible_by_id(id: i32, impossible: bool, pool: &PgPool) -
 //    Ok(())
 //}
 
-pub async fn delete_comment_by_id(id: i32, pool: &PgPool) -> Result<()> {
-    sqlx::query_file!("sql/gi/achievements/delete_comment_by_id.sql", id)
+pub async fn delete_comment_by_id(id: i32, language: Language, pool: &PgPool) -> Result<()> {
+    let language = language.to_string();
+
+    sqlx::query_file!("sql/gi/achievements/delete_comment_by_id.sql", id, language)
         .execute(pool)
         .await?;
 
@@ -223,3 +261,12 @@ pub async fn delete_difficulty_by_id(id: i32, pool: &PgPool) -> Result<()> {
 //
 //    Ok(())
 //}
+
+pub async fn get_snapshot(pool: &PgPool) -> Result<Vec<DbAchievementSnapshot>> {
+    Ok(sqlx::query_file_as!(
+        DbAchievementSnapshot,
+        "sql/gi/achievements/get_snapshot.sql"
+    )
+    .fetch_all(pool)
+    .await?)
+}
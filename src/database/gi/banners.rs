@@ -0,0 +1,50 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+pub use crate::database::banners::DbBanner;
+
+pub async fn set(banner: &DbBanner, pool: &PgPool) -> Result<()> {
+    sqlx::query_file!(
+        "sql/gi/banners/set.sql",
+        banner.id,
+        banner.gacha_type,
+        banner.start_time,
+        banner.end_time,
+        &banner.rate_up_5,
+        &banner.rate_up_4,
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn delete_by_id(id: i32, pool: &PgPool) -> Result<()> {
+    sqlx::query_file!("sql/gi/banners/delete_by_id.sql", id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_all(pool: &PgPool) -> Result<Vec<DbBanner>> {
+    Ok(sqlx::query_file_as!(DbBanner, "sql/gi/banners/get_all.sql")
+        .fetch_all(pool)
+        .await?)
+}
+
+pub async fn get_by_gacha_type(gacha_type: &str, pool: &PgPool) -> Result<Vec<DbBanner>> {
+    Ok(
+        sqlx::query_file_as!(DbBanner, "sql/gi/banners/get_by_gacha_type.sql", gacha_type)
+            .fetch_all(pool)
+            .await?,
+    )
+}
+
+pub async fn get_one_by_id(id: i32, pool: &PgPool) -> Result<Option<DbBanner>> {
+    Ok(
+        sqlx::query_file_as!(DbBanner, "sql/gi/banners/get_one_by_id.sql", id)
+            .fetch_optional(pool)
+            .await?,
+    )
+}
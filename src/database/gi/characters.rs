@@ -1,6 +1,8 @@
 use anyhow::Result;
 use sqlx::PgPool;
 
+use crate::Language;
+
 pub async fn set_all(id: &[i32], rarity: &[i32], pool: &PgPool) -> Result<()> {
     sqlx::query_file!("sql/gi/characters/set_all.sql", id, rarity)
         .execute(pool)
@@ -9,6 +11,15 @@ pub async fn set_all(id: &[i32], rarity: &[i32], pool: &PgPool) -> Result<()> {
     Ok(())
 }
 
+pub async fn get_ids(pool: &PgPool) -> Result<Vec<i32>> {
+    Ok(sqlx::query_file!("sql/gi/characters/get_ids.sql")
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .map(|r| r.id)
+        .collect())
+}
+
 pub async fn get_id_by_paimon_moe_id(id: &str, pool: &PgPool) -> Result<i32> {
     Ok(
         sqlx::query_file!("sql/gi/characters/get_id_by_paimon_moe_id.sql", id)
@@ -17,3 +28,26 @@ pub async fn get_id_by_paimon_moe_id(id: &str, pool: &PgPool) -> Result<i32> {
             .id,
     )
 }
+
+pub struct DbOwnedCharacter {
+    pub id: i32,
+    pub name: String,
+    pub owned: Option<bool>,
+}
+
+pub async fn get_all_owned_by_username(
+    username: &str,
+    language: Language,
+    pool: &PgPool,
+) -> Result<Vec<DbOwnedCharacter>> {
+    let language = language.to_string();
+
+    Ok(sqlx::query_file_as!(
+        DbOwnedCharacter,
+        "sql/gi/characters/get_all_owned_by_username.sql",
+        username,
+        language,
+    )
+    .fetch_all(pool)
+    .await?)
+}
@@ -1,14 +1,19 @@
 pub mod achievement_series;
 pub mod achievement_series_text;
 pub mod achievements;
+pub mod achievements_changelog;
 pub mod achievements_percent;
 pub mod achievements_text;
+pub mod banners;
 pub mod characters;
 pub mod characters_text;
 pub mod connections;
 pub mod profiles;
+pub mod users_achievement_lists;
+pub mod users_achievement_lists_entries;
 pub mod users_achievements_completed;
 pub mod users_achievements_favorites;
+pub mod users_achievements_notes;
 pub mod weapons;
 pub mod weapons_text;
 pub mod wishes;
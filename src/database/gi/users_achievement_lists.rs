@@ -0,0 +1,76 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+pub struct DbUserAchievementList {
+    pub id: i32,
+    pub name: String,
+}
+
+pub async fn add(username: &str, name: &str, pool: &PgPool) -> Result<DbUserAchievementList> {
+    Ok(sqlx::query_file_as!(
+        DbUserAchievementList,
+        "sql/gi/users/achievements/lists/add.sql",
+        username,
+        name,
+    )
+    .fetch_one(pool)
+    .await?)
+}
+
+pub async fn update_name(username: &str, id: i32, name: &str, pool: &PgPool) -> Result<()> {
+    sqlx::query_file!(
+        "sql/gi/users/achievements/lists/update_name.sql",
+        username,
+        id,
+        name
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn update_positions(username: &str, ids: &[i32], pool: &PgPool) -> Result<()> {
+    sqlx::query_file!(
+        "sql/gi/users/achievements/lists/update_positions.sql",
+        username,
+        ids
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn delete(username: &str, id: i32, pool: &PgPool) -> Result<()> {
+    sqlx::query_file!("sql/gi/users/achievements/lists/delete.sql", username, id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_one(
+    username: &str,
+    id: i32,
+    pool: &PgPool,
+) -> Result<Option<DbUserAchievementList>> {
+    Ok(sqlx::query_file_as!(
+        DbUserAchievementList,
+        "sql/gi/users/achievements/lists/get_one.sql",
+        username,
+        id,
+    )
+    .fetch_optional(pool)
+    .await?)
+}
+
+pub async fn get_by_username(username: &str, pool: &PgPool) -> Result<Vec<DbUserAchievementList>> {
+    Ok(sqlx::query_file_as!(
+        DbUserAchievementList,
+        "sql/gi/users/achievements/lists/get_by_username.sql",
+        username,
+    )
+    .fetch_all(pool)
+    .await?)
+}
@@ -5,6 +5,8 @@ use crate::database::gi::achievements::DbAchievement;
 
 pub struct DbUserAchievementCompleted {
     pub username: String,
+    /// 0 for the default profile
+    pub uid: i32,
     pub id: i32,
 }
 
@@ -24,6 +26,7 @@ pub async fn add(user_achievement: &DbUserAchievementCompleted, pool: &PgPool) -
     sqlx::query_file!(
         "sql/gi/users/achievements/completed/set.sql",
         user_achievement.username,
+        user_achievement.uid,
         user_achievement.id,
     )
     .execute(pool)
@@ -45,6 +48,7 @@ pub async fn add(user_achievement: &DbUserAchievementCompleted, pool: &PgPool) -
             sqlx::query_file!(
                 "sql/gi/users/achievements/completed/delete.sql",
                 user_achievement.username,
+                user_achievement.uid,
                 related,
             )
             .execute(pool)
@@ -59,6 +63,7 @@ pub async fn delete(user_achievement: &DbUserAchievementCompleted, pool: &PgPool
     sqlx::query_file!(
         "sql/gi/users/achievements/completed/delete.sql",
         user_achievement.username,
+        user_achievement.uid,
         user_achievement.id,
     )
     .execute(pool)
@@ -69,12 +74,14 @@ pub async fn delete(user_achievement: &DbUserAchievementCompleted, pool: &PgPool
 
 pub async fn get_by_username(
     username: &str,
+    uid: i32,
     pool: &PgPool,
 ) -> Result<Vec<DbUserAchievementCompleted>> {
     Ok(sqlx::query_file_as!(
         DbUserAchievementCompleted,
         "sql/gi/users/achievements/completed/get_by_username.sql",
-        username
+        username,
+        uid,
     )
     .fetch_all(pool)
     .await?)
@@ -90,3 +97,21 @@ pub async fn count_users(threshhold: i64, pool: &PgPool) -> Result<i64> {
     .count
     .unwrap())
 }
+
+/// Profiles above the threshold with less completions, `None` if the profile is below the threshold.
+pub async fn count_users_below(
+    threshhold: i64,
+    username: &str,
+    uid: i32,
+    pool: &PgPool,
+) -> Result<Option<i64>> {
+    Ok(sqlx::query_file!(
+        "sql/gi/users/achievements/completed/count_users_below.sql",
+        threshhold,
+        username,
+        uid,
+    )
+    .fetch_optional(pool)
+    .await?
+    .map(|r| r.below.unwrap()))
+}
@@ -5,6 +5,8 @@ use crate::database::gi::achievements::DbAchievement;
 
 pub struct DbUserAchievementFavorite {
     pub username: String,
+    /// 0 for the default profile
+    pub uid: i32,
     pub id: i32,
 }
 
@@ -12,6 +14,7 @@ pub async fn add(user_achievement: &DbUserAchievementFavorite, pool: &PgPool) ->
     sqlx::query_file!(
         "sql/gi/users/achievements/favorites/set.sql",
         user_achievement.username,
+        user_achievement.uid,
         user_achievement.id,
     )
     .execute(pool)
@@ -33,6 +36,7 @@ pub async fn add(user_achievement: &DbUserAchievementFavorite, pool: &PgPool) ->
             sqlx::query_file!(
                 "sql/gi/users/achievements/favorites/delete.sql",
                 user_achievement.username,
+                user_achievement.uid,
                 related,
             )
             .execute(pool)
@@ -47,6 +51,7 @@ pub async fn delete(user_achievement: &DbUserAchievementFavorite, pool: &PgPool)
     sqlx::query_file!(
         "sql/gi/users/achievements/favorites/delete.sql",
         user_achievement.username,
+        user_achievement.uid,
         user_achievement.id,
     )
     .execute(pool)
@@ -57,12 +62,14 @@ pub async fn delete(user_achievement: &DbUserAchievementFavorite, pool: &PgPool)
 
 pub async fn get_by_username(
     username: &str,
+    uid: i32,
     pool: &PgPool,
 ) -> Result<Vec<DbUserAchievementFavorite>> {
     Ok(sqlx::query_file_as!(
         DbUserAchievementFavorite,
         "sql/gi/users/achievements/favorites/get_by_username.sql",
         username,
+        uid,
     )
     .fetch_all(pool)
     .await?)
@@ -9,6 +9,15 @@ pub async fn set_all(id: &[i32], rarity: &[i32], pool: &PgPool) -> Result<()> {
     Ok(())
 }
 
+pub async fn get_ids(pool: &PgPool) -> Result<Vec<i32>> {
+    Ok(sqlx::query_file!("sql/gi/weapons/get_ids.sql")
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .map(|r| r.id)
+        .collect())
+}
+
 pub async fn get_id_by_paimon_moe_id(id: &str, pool: &PgPool) -> Result<i32> {
     Ok(
         sqlx::query_file!("sql/gi/weapons/get_id_by_paimon_moe_id.sql", id)
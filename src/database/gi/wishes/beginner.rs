@@ -1,11 +1,11 @@
 use chrono::{DateTime, Utc};
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 
 use crate::Language;
 
 use super::{DbWish, SetAll};
 
-pub async fn set_all(set_all: &SetAll, pool: &PgPool) -> anyhow::Result<()> {
+pub async fn set_all(set_all: &SetAll, connection: &mut PgConnection) -> anyhow::Result<()> {
     sqlx::query_file!(
         "sql/gi/wishes/beginner/set_all.sql",
         &set_all.id,
@@ -15,7 +15,7 @@ pub async fn set_all(set_all: &SetAll, pool: &PgPool) -> anyhow::Result<()> {
         &set_all.timestamp as &[DateTime<Utc>],
         &set_all.official,
     )
-    .execute(pool)
+    .execute(connection)
     .await?;
 
     Ok(())
@@ -38,6 +38,28 @@ pub async fn get_by_uid(
     .await?)
 }
 
+pub async fn get_existing_ids(uid: i32, ids: &[i64], pool: &PgPool) -> anyhow::Result<Vec<i64>> {
+    Ok(
+        sqlx::query_file!("sql/gi/wishes/beginner/get_existing_ids.sql", uid, ids)
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|r| r.id)
+            .collect(),
+    )
+}
+
+pub async fn get_official_ids(uid: i32, ids: &[i64], pool: &PgPool) -> anyhow::Result<Vec<i64>> {
+    Ok(
+        sqlx::query_file!("sql/gi/wishes/beginner/get_official_ids.sql", uid, ids)
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|r| r.id)
+            .collect(),
+    )
+}
+
 pub async fn get_earliest_timestamp_by_uid(
     uid: i32,
     pool: &PgPool,
@@ -0,0 +1,125 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use crate::Game;
+
+pub struct DbImportJob {
+    pub id: i32,
+    pub game: String,
+    pub uid: i32,
+    pub url: Option<String>,
+    pub status: String,
+    pub gacha_type: Option<String>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+/// `None` if the uid already has an active job.
+pub async fn add(game: Game, uid: i32, url: Option<&str>, pool: &PgPool) -> Result<Option<i32>> {
+    let game = game.to_string();
+
+    Ok(sqlx::query_file!("sql/import_jobs/add.sql", game, uid, url)
+        .fetch_optional(pool)
+        .await?
+        .map(|r| r.id))
+}
+
+pub async fn get_one_by_id(id: i32, pool: &PgPool) -> Result<Option<DbImportJob>> {
+    Ok(
+        sqlx::query_file_as!(DbImportJob, "sql/import_jobs/get_one_by_id.sql", id)
+            .fetch_optional(pool)
+            .await?,
+    )
+}
+
+pub async fn get_one_active_by_game_and_uid(
+    game: Game,
+    uid: i32,
+    pool: &PgPool,
+) -> Result<Option<DbImportJob>> {
+    let game = game.to_string();
+
+    Ok(sqlx::query_file_as!(
+        DbImportJob,
+        "sql/import_jobs/get_one_active_by_game_and_uid.sql",
+        game,
+        uid,
+    )
+    .fetch_optional(pool)
+    .await?)
+}
+
+pub async fn get_one_latest_by_game_and_uid(
+    game: Game,
+    uid: i32,
+    pool: &PgPool,
+) -> Result<Option<DbImportJob>> {
+    let game = game.to_string();
+
+    Ok(sqlx::query_file_as!(
+        DbImportJob,
+        "sql/import_jobs/get_one_latest_by_game_and_uid.sql",
+        game,
+        uid,
+    )
+    .fetch_optional(pool)
+    .await?)
+}
+
+/// Takes the oldest pending job, or one whose worker stopped its heartbeat for 5 minutes.
+pub async fn claim(pool: &PgPool) -> Result<Option<DbImportJob>> {
+    Ok(
+        sqlx::query_file_as!(DbImportJob, "sql/import_jobs/claim.sql")
+            .fetch_optional(pool)
+            .await?,
+    )
+}
+
+pub async fn update_status_by_id(
+    id: i32,
+    status: &str,
+    gacha_type: Option<&str>,
+    pool: &PgPool,
+) -> Result<()> {
+    sqlx::query_file!(
+        "sql/import_jobs/update_status_by_id.sql",
+        id,
+        status,
+        gacha_type,
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Marks a running job as alive.
+pub async fn update_updated_at_by_id(id: i32, pool: &PgPool) -> Result<()> {
+    sqlx::query_file!("sql/import_jobs/update_updated_at_by_id.sql", id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn update_finished_by_id(
+    id: i32,
+    status: &str,
+    error: Option<&str>,
+    pool: &PgPool,
+) -> Result<()> {
+    sqlx::query_file!(
+        "sql/import_jobs/update_finished_by_id.sql",
+        id,
+        status,
+        error,
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
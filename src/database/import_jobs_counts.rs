@@ -0,0 +1,32 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+pub struct DbImportJobCount {
+    pub gacha_type: String,
+    pub count: i32,
+}
+
+/// Also marks the job as alive.
+pub async fn add(id: i32, gacha_type: &str, count: i32, pool: &PgPool) -> Result<()> {
+    sqlx::query_file!("sql/import_jobs_counts/add.sql", id, gacha_type, count)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_by_id(id: i32, pool: &PgPool) -> Result<Vec<DbImportJobCount>> {
+    Ok(
+        sqlx::query_file_as!(DbImportJobCount, "sql/import_jobs_counts/get_by_id.sql", id)
+            .fetch_all(pool)
+            .await?,
+    )
+}
+
+pub async fn reset_by_id(id: i32, pool: &PgPool) -> Result<()> {
+    sqlx::query_file!("sql/import_jobs_counts/reset_by_id.sql", id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
@@ -33,6 +33,15 @@ pub async fn set_all(id: &[i32], rarity: &[i32], pool: &PgPool) -> Result<()> {
     Ok(())
 }
 
+pub async fn get_ids(pool: &PgPool) -> Result<Vec<i32>> {
+    Ok(sqlx::query_file!("sql/light_cones/get_ids.sql")
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .map(|r| r.id)
+        .collect())
+}
+
 pub async fn get_all(language: Language, pool: &PgPool) -> Result<Vec<DbLightCone>> {
     let language = language.to_string();
 
@@ -47,6 +47,15 @@ pub async fn exists(uid: i32, pool: &PgPool) -> Result<bool> {
         .is_some())
 }
 
+pub async fn get_achievement_count_by_uid(uid: i32, pool: &PgPool) -> Result<Option<i32>> {
+    Ok(
+        sqlx::query_file!("sql/mihomo/get_achievement_count_by_uid.sql", uid)
+            .fetch_optional(pool)
+            .await?
+            .map(|r| r.achievement_count),
+    )
+}
+
 pub async fn get_all_uids(pool: &PgPool) -> Result<Vec<i32>> {
     Ok(sqlx::query_file!("sql/mihomo/get_all_uids.sql")
         .fetch_all(pool)
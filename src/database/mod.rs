@@ -2,20 +2,34 @@ pub mod achievement_scores;
 pub mod achievement_series;
 pub mod achievement_series_text;
 pub mod achievements;
+pub mod achievements_changelog;
+pub mod achievements_difficulty_votes;
+pub mod achievements_feedback;
 pub mod achievements_percent;
+pub mod achievements_percent_history;
 pub mod achievements_text;
 pub mod admins;
+pub mod banners;
 pub mod characters;
 pub mod characters_text;
 pub mod connections;
+pub mod game_versions;
 pub mod gi;
+pub mod import_jobs;
+pub mod import_jobs_counts;
 pub mod light_cones;
 pub mod light_cones_text;
 pub mod mihomo;
 pub mod sessions;
+pub mod trailblazer_text;
 pub mod users;
+pub mod users_achievement_lists;
+pub mod users_achievement_lists_entries;
 pub mod users_achievements_completed;
 pub mod users_achievements_favorites;
+pub mod users_achievements_notes;
+pub mod users_achievements_operations;
+pub mod users_trailblazer;
 pub mod warps;
 pub mod warps_stats;
 pub mod warps_stats_global;
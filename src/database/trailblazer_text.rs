@@ -0,0 +1,25 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+use crate::Language;
+
+pub async fn set_all(language: &[Language], name: &[String], pool: &PgPool) -> Result<()> {
+    let language = &language.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+    sqlx::query_file!("sql/trailblazer_text/set_all.sql", language, name)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_one_by_language(language: Language, pool: &PgPool) -> Result<Option<String>> {
+    let language = language.to_string();
+
+    Ok(
+        sqlx::query_file!("sql/trailblazer_text/get_one_by_language.sql", language)
+            .fetch_optional(pool)
+            .await?
+            .map(|r| r.name),
+    )
+}
@@ -1,48 +1,70 @@
 use anyhow::Result;
-use sqlx::PgPool;
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool};
 
 pub struct DbUserAchievementCompleted {
     pub username: String,
+    /// 0 for the default profile
+    pub uid: i32,
     pub id: i32,
 }
 
 pub async fn add(user_achievement: &DbUserAchievementCompleted, pool: &PgPool) -> Result<()> {
-    if sqlx::query!(
-        "SELECT impossible FROM achievements WHERE id = $1",
+    let mut transaction = pool.begin().await?;
+
+    add_at(user_achievement, Utc::now(), &mut transaction).await?;
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+/// Every accepted operation is recorded in the operation log with the given timestamp,
+/// even if the achievement already was completed.
+pub async fn add_at(
+    user_achievement: &DbUserAchievementCompleted,
+    timestamp: DateTime<Utc>,
+    connection: &mut PgConnection,
+) -> Result<()> {
+    let achievement = sqlx::query!(
+        "SELECT impossible, set FROM achievements WHERE id = $1",
         user_achievement.id
     )
-    .fetch_one(pool)
-    .await?
-    .impossible
-    {
+    .fetch_one(&mut *connection)
+    .await?;
+
+    if achievement.impossible {
         return Ok(());
     }
 
-    sqlx::query!(
-        "INSERT INTO users_achievements_completed(username, id) VALUES($1, $2) ON CONFLICT(username, id) DO NOTHING",
+    sqlx::query_file!(
+        "sql/users/achievements/completed/set.sql",
         user_achievement.username,
+        user_achievement.uid,
         user_achievement.id,
+        timestamp,
     )
-    .execute(pool)
+    .execute(&mut *connection)
     .await?;
 
-    if let Some(set) = sqlx::query!(
-        "SELECT set FROM achievements WHERE id = $1",
-        user_achievement.id,
-    )
-    .fetch_one(pool)
-    .await?
-    .set
-    {
-        for related in
-            super::achievements::get_all_related_ids(user_achievement.id, set, pool).await?
-        {
-            sqlx::query!(
-                "DELETE FROM users_achievements_completed WHERE username = $1 AND id = $2",
+    if let Some(set) = achievement.set {
+        let related = sqlx::query_file!(
+            "sql/achievements/get_all_related_ids.sql",
+            user_achievement.id,
+            set
+        )
+        .fetch_all(&mut *connection)
+        .await?;
+
+        for related in related {
+            sqlx::query_file!(
+                "sql/users/achievements/completed/delete.sql",
                 user_achievement.username,
-                related,
+                user_achievement.uid,
+                related.id,
+                timestamp,
             )
-            .execute(pool)
+            .execute(&mut *connection)
             .await?;
         }
     }
@@ -51,21 +73,32 @@ pub async fn add(user_achievement: &DbUserAchievementCompleted, pool: &PgPool) -
 }
 
 pub async fn delete(user_achievement: &DbUserAchievementCompleted, pool: &PgPool) -> Result<()> {
-    sqlx::query!(
-        "DELETE FROM users_achievements_completed WHERE username = $1 AND id = $2",
+    delete_at(user_achievement, Utc::now(), &mut *pool.acquire().await?).await
+}
+
+pub async fn delete_at(
+    user_achievement: &DbUserAchievementCompleted,
+    timestamp: DateTime<Utc>,
+    connection: &mut PgConnection,
+) -> Result<()> {
+    sqlx::query_file!(
+        "sql/users/achievements/completed/delete.sql",
         user_achievement.username,
+        user_achievement.uid,
         user_achievement.id,
+        timestamp,
     )
-    .execute(pool)
+    .execute(connection)
     .await?;
 
     Ok(())
 }
 
-pub async fn delete_by_username(username: &str, pool: &PgPool) -> Result<()> {
-    sqlx::query!(
-        "DELETE FROM users_achievements_completed WHERE username = $1",
+pub async fn delete_by_username(username: &str, uid: i32, pool: &PgPool) -> Result<()> {
+    sqlx::query_file!(
+        "sql/users/achievements/completed/delete_by_username.sql",
         username,
+        uid,
     )
     .execute(pool)
     .await?;
@@ -75,12 +108,14 @@ pub async fn delete_by_username(username: &str, pool: &PgPool) -> Result<()> {
 
 pub async fn get_by_username(
     username: &str,
+    uid: i32,
     pool: &PgPool,
 ) -> Result<Vec<DbUserAchievementCompleted>> {
     Ok(sqlx::query_as!(
         DbUserAchievementCompleted,
-        "SELECT * FROM users_achievements_completed WHERE username = $1",
-        username
+        "SELECT * FROM users_achievements_completed WHERE username = $1 AND uid = $2",
+        username,
+        uid,
     )
     .fetch_all(pool)
     .await?)
@@ -96,6 +131,19 @@ pub async fn user_count(pool: &PgPool) -> Result<i64> {
     )
 }
 
+/// Impossible achievements are excluded, as they can't count towards the in-game total.
+pub async fn count_by_username(username: &str, uid: i32, pool: &PgPool) -> Result<i64> {
+    Ok(sqlx::query_file!(
+        "sql/users/achievements/completed/count_by_username.sql",
+        username,
+        uid,
+    )
+    .fetch_one(pool)
+    .await?
+    .count
+    .unwrap())
+}
+
 pub async fn count_users(threshhold: i64, pool: &PgPool) -> Result<i64> {
     Ok(sqlx::query_file!(
         "sql/users/achievements/completed/count_users.sql",
@@ -106,3 +154,21 @@ pub async fn count_users(threshhold: i64, pool: &PgPool) -> Result<i64> {
     .count
     .unwrap())
 }
+
+/// Profiles above the threshold with less completions, `None` if the profile is below the threshold.
+pub async fn count_users_below(
+    threshhold: i64,
+    username: &str,
+    uid: i32,
+    pool: &PgPool,
+) -> Result<Option<i64>> {
+    Ok(sqlx::query_file!(
+        "sql/users/achievements/completed/count_users_below.sql",
+        threshhold,
+        username,
+        uid,
+    )
+    .fetch_optional(pool)
+    .await?
+    .map(|r| r.below.unwrap()))
+}
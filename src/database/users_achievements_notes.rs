@@ -0,0 +1,59 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+pub struct DbUserAchievementNote {
+    pub username: String,
+    pub id: i32,
+    pub note: String,
+}
+
+pub async fn set(user_achievement_note: &DbUserAchievementNote, pool: &PgPool) -> Result<()> {
+    sqlx::query_file!(
+        "sql/users/achievements/notes/set.sql",
+        user_achievement_note.username,
+        user_achievement_note.id,
+        user_achievement_note.note,
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn delete(username: &str, id: i32, pool: &PgPool) -> Result<()> {
+    sqlx::query_file!("sql/users/achievements/notes/delete.sql", username, id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_one(
+    username: &str,
+    id: i32,
+    pool: &PgPool,
+) -> Result<Option<DbUserAchievementNote>> {
+    Ok(sqlx::query_file_as!(
+        DbUserAchievementNote,
+        "sql/users/achievements/notes/get_one.sql",
+        username,
+        id,
+    )
+    .fetch_optional(pool)
+    .await?)
+}
+
+pub async fn get_by_username(
+    username: &str,
+    query: Option<&str>,
+    pool: &PgPool,
+) -> Result<Vec<DbUserAchievementNote>> {
+    Ok(sqlx::query_file_as!(
+        DbUserAchievementNote,
+        "sql/users/achievements/notes/get_by_username.sql",
+        username,
+        query,
+    )
+    .fetch_all(pool)
+    .await?)
+}
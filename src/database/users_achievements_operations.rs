@@ -0,0 +1,67 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sqlx::PgConnection;
+
+pub struct DbUserAchievementOperation {
+    pub id: i32,
+    pub completed: bool,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Serializes the syncs of a user's profile until the transaction ends.
+pub async fn lock(username: &str, uid: i32, connection: &mut PgConnection) -> Result<()> {
+    sqlx::query_file!("sql/users/achievements/operations/lock.sql", username, uid)
+        .fetch_one(connection)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_latest_timestamp(
+    username: &str,
+    uid: i32,
+    id: i32,
+    connection: &mut PgConnection,
+) -> Result<Option<DateTime<Utc>>> {
+    Ok(sqlx::query_file!(
+        "sql/users/achievements/operations/get_latest_timestamp.sql",
+        username,
+        uid,
+        id,
+    )
+    .fetch_one(connection)
+    .await?
+    .max)
+}
+
+/// Only the latest operation per achievement, up to and including `until`.
+pub async fn get_since_revision(
+    username: &str,
+    uid: i32,
+    revision: i64,
+    until: i64,
+    connection: &mut PgConnection,
+) -> Result<Vec<DbUserAchievementOperation>> {
+    Ok(sqlx::query_file_as!(
+        DbUserAchievementOperation,
+        "sql/users/achievements/operations/get_since_revision.sql",
+        username,
+        uid,
+        revision,
+        until,
+    )
+    .fetch_all(connection)
+    .await?)
+}
+
+pub async fn get_revision(username: &str, uid: i32, connection: &mut PgConnection) -> Result<i64> {
+    Ok(sqlx::query_file!(
+        "sql/users/achievements/operations/get_revision.sql",
+        username,
+        uid,
+    )
+    .fetch_one(connection)
+    .await?
+    .max
+    .unwrap_or_default())
+}
@@ -0,0 +1,42 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+pub struct DbUserTrailblazer {
+    pub username: String,
+    pub gender: String,
+    pub nickname: Option<String>,
+}
+
+pub async fn set(user_trailblazer: &DbUserTrailblazer, pool: &PgPool) -> Result<()> {
+    sqlx::query_file!(
+        "sql/users/trailblazer/set.sql",
+        user_trailblazer.username,
+        user_trailblazer.gender,
+        user_trailblazer.nickname,
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn get_one_by_username(
+    username: &str,
+    pool: &PgPool,
+) -> Result<Option<DbUserTrailblazer>> {
+    Ok(sqlx::query_file_as!(
+        DbUserTrailblazer,
+        "sql/users/trailblazer/get_one_by_username.sql",
+        username,
+    )
+    .fetch_optional(pool)
+    .await?)
+}
+
+pub async fn delete_by_username(username: &str, pool: &PgPool) -> Result<()> {
+    sqlx::query_file!("sql/users/trailblazer/delete_by_username.sql", username)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
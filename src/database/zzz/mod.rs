@@ -1,10 +1,12 @@
 pub mod achievement_series;
 pub mod achievement_series_text;
 pub mod achievements;
+pub mod achievements_changelog;
 pub mod achievements_percent;
 pub mod achievements_text;
 pub mod bangboos;
 pub mod bangboos_text;
+pub mod banners;
 pub mod characters;
 pub mod characters_text;
 pub mod connections;
@@ -12,7 +14,10 @@ pub mod signals;
 pub mod signals_stats;
 pub mod signals_stats_global;
 pub mod uids;
+pub mod users_achievement_lists;
+pub mod users_achievement_lists_entries;
 pub mod users_achievements_completed;
 pub mod users_achievements_favorites;
+pub mod users_achievements_notes;
 pub mod w_engines;
 pub mod w_engines_text;
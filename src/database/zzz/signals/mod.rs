@@ -20,6 +20,7 @@ pub struct DbSignalInfo {
     pub character: Option<i32>,
     pub w_engine: Option<i32>,
     pub rarity: Option<i32>,
+    pub timestamp: DateTime<Utc>,
 }
 
 #[derive(Default)]
@@ -42,6 +43,16 @@ pub async fn get_uids(pool: &PgPool) -> anyhow::Result<Vec<i32>> {
         .collect())
 }
 
+/// Including private uids, the stats of every uid with pulls are recalculated after a banner change.
+pub async fn get_all_uids(pool: &PgPool) -> anyhow::Result<Vec<i32>> {
+    Ok(sqlx::query_file!("sql/zzz/signals/get_all_uids.sql")
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .filter_map(|r| r.uid)
+        .collect())
+}
+
 pub async fn count_uids(pool: &PgPool) -> anyhow::Result<i64> {
     Ok(sqlx::query_file!("sql/zzz/signals/count_uids.sql")
         .fetch_one(pool)
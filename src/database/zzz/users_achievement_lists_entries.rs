@@ -0,0 +1,64 @@
+use anyhow::Result;
+use sqlx::PgPool;
+
+pub struct DbUserAchievementListEntry {
+    pub list: i32,
+    pub id: i32,
+}
+
+pub async fn add(list: i32, id: i32, pool: &PgPool) -> Result<()> {
+    sqlx::query_file!("sql/zzz/users/achievements/lists/entries/set.sql", list, id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Replaces the whole list at once, `ids` have to be unique.
+pub async fn set_all(list: i32, ids: &[i32], pool: &PgPool) -> Result<()> {
+    let mut transaction = pool.begin().await?;
+
+    sqlx::query_file!(
+        "sql/zzz/users/achievements/lists/entries/delete_by_list.sql",
+        list
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    sqlx::query_file!(
+        "sql/zzz/users/achievements/lists/entries/set_all.sql",
+        list,
+        ids
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+pub async fn delete(list: i32, id: i32, pool: &PgPool) -> Result<()> {
+    sqlx::query_file!(
+        "sql/zzz/users/achievements/lists/entries/delete.sql",
+        list,
+        id
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn get_by_username(
+    username: &str,
+    pool: &PgPool,
+) -> Result<Vec<DbUserAchievementListEntry>> {
+    Ok(sqlx::query_file_as!(
+        DbUserAchievementListEntry,
+        "sql/zzz/users/achievements/lists/entries/get_by_username.sql",
+        username,
+    )
+    .fetch_all(pool)
+    .await?)
+}
@@ -3,6 +3,8 @@ use sqlx::PgPool;
 
 pub struct DbUserAchievementCompleted {
     pub username: String,
+    /// 0 for the default profile
+    pub uid: i32,
     pub id: i32,
 }
 
@@ -19,8 +21,9 @@ pub async fn add(user_achievement: &DbUserAchievementCompleted, pool: &PgPool) -
     }
 
     sqlx::query!(
-        "INSERT INTO zzz_users_achievements_completed(username, id) VALUES($1, $2) ON CONFLICT(username, id) DO NOTHING",
+        "INSERT INTO zzz_users_achievements_completed(username, uid, id) VALUES($1, $2, $3) ON CONFLICT(username, uid, id) DO NOTHING",
         user_achievement.username,
+        user_achievement.uid,
         user_achievement.id,
     )
     .execute(pool)
@@ -38,8 +41,9 @@ pub async fn add(user_achievement: &DbUserAchievementCompleted, pool: &PgPool) -
             super::achievements::get_all_related_ids(user_achievement.id, set, pool).await?
         {
             sqlx::query!(
-                "DELETE FROM zzz_users_achievements_completed WHERE username = $1 AND id = $2",
+                "DELETE FROM zzz_users_achievements_completed WHERE username = $1 AND uid = $2 AND id = $3",
                 user_achievement.username,
+                user_achievement.uid,
                 related,
             )
             .execute(pool)
@@ -52,8 +56,9 @@ pub async fn add(user_achievement: &DbUserAchievementCompleted, pool: &PgPool) -
 
 pub async fn delete(user_achievement: &DbUserAchievementCompleted, pool: &PgPool) -> Result<()> {
     sqlx::query!(
-        "DELETE FROM zzz_users_achievements_completed WHERE username = $1 AND id = $2",
+        "DELETE FROM zzz_users_achievements_completed WHERE username = $1 AND uid = $2 AND id = $3",
         user_achievement.username,
+        user_achievement.uid,
         user_achievement.id,
     )
     .execute(pool)
@@ -64,12 +69,14 @@ pub async fn delete(user_achievement: &DbUserAchievementCompleted, pool: &PgPool
 
 pub async fn get_by_username(
     username: &str,
+    uid: i32,
     pool: &PgPool,
 ) -> Result<Vec<DbUserAchievementCompleted>> {
     Ok(sqlx::query_as!(
         DbUserAchievementCompleted,
-        "SELECT * FROM zzz_users_achievements_completed WHERE username = $1",
-        username
+        "SELECT * FROM zzz_users_achievements_completed WHERE username = $1 AND uid = $2",
+        username,
+        uid,
     )
     .fetch_all(pool)
     .await?)
@@ -85,3 +92,21 @@ pub async fn count_users(threshhold: i64, pool: &PgPool) -> Result<i64> {
     .count
     .unwrap())
 }
+
+/// Profiles above the threshold with less completions, `None` if the profile is below the threshold.
+pub async fn count_users_below(
+    threshhold: i64,
+    username: &str,
+    uid: i32,
+    pool: &PgPool,
+) -> Result<Option<i64>> {
+    Ok(sqlx::query_file!(
+        "sql/zzz/users/achievements/completed/count_users_below.sql",
+        threshhold,
+        username,
+        uid,
+    )
+    .fetch_optional(pool)
+    .await?
+    .map(|r| r.below.unwrap()))
+}
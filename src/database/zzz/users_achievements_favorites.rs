@@ -3,13 +3,16 @@ use sqlx::PgPool;
 
 pub struct DbUserAchievementFavorite {
     pub username: String,
+    /// 0 for the default profile
+    pub uid: i32,
     pub id: i32,
 }
 
 pub async fn add(user_achievement: &DbUserAchievementFavorite, pool: &PgPool) -> Result<()> {
     sqlx::query!(
-        "INSERT INTO zzz_users_achievements_favorites(username, id) VALUES($1, $2) ON CONFLICT(username, id) DO NOTHING",
+        "INSERT INTO zzz_users_achievements_favorites(username, uid, id) VALUES($1, $2, $3) ON CONFLICT(username, uid, id) DO NOTHING",
         user_achievement.username,
+        user_achievement.uid,
         user_achievement.id,
     )
     .execute(pool)
@@ -27,8 +30,9 @@ pub async fn add(user_achievement: &DbUserAchievementFavorite, pool: &PgPool) ->
             super::achievements::get_all_related_ids(user_achievement.id, set, pool).await?
         {
             sqlx::query!(
-                "DELETE FROM zzz_users_achievements_favorites WHERE username = $1 AND id = $2",
+                "DELETE FROM zzz_users_achievements_favorites WHERE username = $1 AND uid = $2 AND id = $3",
                 user_achievement.username,
+                user_achievement.uid,
                 related,
             )
             .execute(pool)
@@ -41,8 +45,9 @@ pub async fn add(user_achievement: &DbUserAchievementFavorite, pool: &PgPool) ->
 
 pub async fn delete(user_achievement: &DbUserAchievementFavorite, pool: &PgPool) -> Result<()> {
     sqlx::query!(
-        "DELETE FROM zzz_users_achievements_favorites WHERE username = $1 AND id = $2",
+        "DELETE FROM zzz_users_achievements_favorites WHERE username = $1 AND uid = $2 AND id = $3",
         user_achievement.username,
+        user_achievement.uid,
         user_achievement.id,
     )
     .execute(pool)
@@ -53,12 +58,14 @@ pub async fn delete(user_achievement: &DbUserAchievementFavorite, pool: &PgPool)
 
 pub async fn get_by_username(
     username: &str,
+    uid: i32,
     pool: &PgPool,
 ) -> Result<Vec<DbUserAchievementFavorite>> {
     Ok(sqlx::query_as!(
         DbUserAchievementFavorite,
-        "SELECT * FROM zzz_users_achievements_favorites WHERE username = $1",
-        username
+        "SELECT * FROM zzz_users_achievements_favorites WHERE username = $1 AND uid = $2",
+        username,
+        uid,
     )
     .fetch_all(pool)
     .await?)
@@ -0,0 +1,324 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use reqwest::header;
+use sqlx::{PgConnection, PgPool};
+
+use crate::{database, Game, GiGachaType};
+
+use super::{stats, Gacha, Pull, RateUp, CLIENT};
+
+const STANDARD_CHARACTERS: &[i32] = &[
+    10000042, 10000016, 10000003, 10000035, 10000069, 10000079, 10000041,
+];
+const STANDARD_WEAPONS: &[i32] = &[
+    15502, 11501, 14502, 13505, 14501, 15501, 12501, 13502, 12502,
+];
+
+pub struct Gi;
+
+pub struct Items {
+    characters: HashSet<i32>,
+    weapons: HashSet<i32>,
+}
+
+impl Gacha for Gi {
+    type GachaType = GiGachaType;
+    type SetAll = database::gi::wishes::SetAll;
+    type Items = Items;
+
+    const GAME: Game = Game::Gi;
+    const URL: &'static str =
+        "https://public-operation-hk4e-sg.hoyoverse.com/gacha_info/api/getGachaLog";
+    const QUERY: &'static [(&'static str, &'static str)] = &[("lang", "en"), ("size", "20")];
+
+    fn gacha_type_id(gacha_type: GiGachaType) -> &'static str {
+        match gacha_type {
+            GiGachaType::Beginner => "100",
+            GiGachaType::Standard => "200",
+            GiGachaType::Character => "301",
+            GiGachaType::Weapon => "302",
+            GiGachaType::Chronicled => "500",
+        }
+    }
+
+    async fn set_uid(uid: i32, username: Option<String>, pool: &PgPool) -> anyhow::Result<()> {
+        let name = CLIENT
+            .get(format!("https://enka.network/api/uid/{uid}?info"))
+            .header(header::USER_AGENT, "stardb")
+            .send()
+            .await?
+            .json::<serde_json::Value>()
+            .await?["playerInfo"]["nickname"]
+            .as_str()
+            .unwrap()
+            .to_string();
+
+        database::gi::profiles::set(&database::gi::profiles::DbProfile { uid, name }, pool).await?;
+
+        if let Some(username) = username {
+            let connection = database::gi::connections::DbConnection {
+                uid,
+                username,
+                verified: true,
+                private: false,
+            };
+
+            database::gi::connections::set(&connection, pool).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn get_existing_ids(
+        gacha_type: GiGachaType,
+        uid: i32,
+        ids: &[i64],
+        pool: &PgPool,
+    ) -> anyhow::Result<Vec<i64>> {
+        match gacha_type {
+            GiGachaType::Beginner => {
+                database::gi::wishes::beginner::get_existing_ids(uid, ids, pool).await
+            }
+            GiGachaType::Standard => {
+                database::gi::wishes::standard::get_existing_ids(uid, ids, pool).await
+            }
+            GiGachaType::Character => {
+                database::gi::wishes::character::get_existing_ids(uid, ids, pool).await
+            }
+            GiGachaType::Weapon => {
+                database::gi::wishes::weapon::get_existing_ids(uid, ids, pool).await
+            }
+            GiGachaType::Chronicled => {
+                database::gi::wishes::chronicled::get_existing_ids(uid, ids, pool).await
+            }
+        }
+    }
+
+    async fn get_official_ids(
+        gacha_type: GiGachaType,
+        uid: i32,
+        ids: &[i64],
+        pool: &PgPool,
+    ) -> anyhow::Result<Vec<i64>> {
+        match gacha_type {
+            GiGachaType::Beginner => {
+                database::gi::wishes::beginner::get_official_ids(uid, ids, pool).await
+            }
+            GiGachaType::Standard => {
+                database::gi::wishes::standard::get_official_ids(uid, ids, pool).await
+            }
+            GiGachaType::Character => {
+                database::gi::wishes::character::get_official_ids(uid, ids, pool).await
+            }
+            GiGachaType::Weapon => {
+                database::gi::wishes::weapon::get_official_ids(uid, ids, pool).await
+            }
+            GiGachaType::Chronicled => {
+                database::gi::wishes::chronicled::get_official_ids(uid, ids, pool).await
+            }
+        }
+    }
+
+    async fn latest_timestamp(
+        gacha_type: GiGachaType,
+        uid: i32,
+        pool: &PgPool,
+    ) -> anyhow::Result<Option<DateTime<Utc>>> {
+        match gacha_type {
+            GiGachaType::Beginner => {
+                database::gi::wishes::beginner::get_latest_timestamp_by_uid(uid, pool).await
+            }
+            GiGachaType::Standard => {
+                database::gi::wishes::standard::get_latest_timestamp_by_uid(uid, pool).await
+            }
+            GiGachaType::Character => {
+                database::gi::wishes::character::get_latest_timestamp_by_uid(uid, pool).await
+            }
+            GiGachaType::Weapon => {
+                database::gi::wishes::weapon::get_latest_timestamp_by_uid(uid, pool).await
+            }
+            GiGachaType::Chronicled => {
+                database::gi::wishes::chronicled::get_latest_timestamp_by_uid(uid, pool).await
+            }
+        }
+    }
+
+    /// The log only has english names, no item ids. Files usually have them.
+    async fn get_items(pool: &PgPool) -> anyhow::Result<Items> {
+        Ok(Items {
+            characters: database::gi::characters::get_ids(pool)
+                .await?
+                .into_iter()
+                .collect(),
+            weapons: database::gi::weapons::get_ids(pool)
+                .await?
+                .into_iter()
+                .collect(),
+        })
+    }
+
+    async fn push(
+        set_all: &mut Self::SetAll,
+        pull: Pull,
+        items: &Items,
+        pool: &PgPool,
+    ) -> anyhow::Result<()> {
+        let item: i32 = if !pull.item_id.is_empty() {
+            pull.item_id.parse()?
+        } else if let Ok(id) = database::gi::characters_text::get_id_by_name(&pull.name, pool).await
+        {
+            id
+        } else {
+            database::gi::weapons_text::get_id_by_name(&pull.name, pool).await?
+        };
+
+        let mut character = (pull.item_type == "Character").then_some(item);
+        let mut weapon = (pull.item_type == "Weapon").then_some(item);
+
+        if character.is_none() && weapon.is_none() {
+            if item >= 10000000 {
+                character = Some(item);
+            } else {
+                weapon = Some(item);
+            }
+        }
+
+        let known = match (character, weapon) {
+            (Some(character), _) => items.characters.contains(&character),
+            (_, Some(weapon)) => items.weapons.contains(&weapon),
+            _ => false,
+        };
+
+        if !known {
+            return Err(anyhow::anyhow!("Unknown item {item}"));
+        }
+
+        set_all.id.push(pull.id);
+        set_all.uid.push(pull.uid);
+        set_all.character.push(character);
+        set_all.weapon.push(weapon);
+        set_all.timestamp.push(pull.timestamp);
+        set_all.official.push(pull.official);
+
+        Ok(())
+    }
+
+    async fn set_all(
+        gacha_type: GiGachaType,
+        set_all: &Self::SetAll,
+        connection: &mut PgConnection,
+    ) -> anyhow::Result<()> {
+        match gacha_type {
+            GiGachaType::Beginner => {
+                database::gi::wishes::beginner::set_all(set_all, connection).await
+            }
+            GiGachaType::Standard => {
+                database::gi::wishes::standard::set_all(set_all, connection).await
+            }
+            GiGachaType::Character => {
+                database::gi::wishes::character::set_all(set_all, connection).await
+            }
+            GiGachaType::Weapon => database::gi::wishes::weapon::set_all(set_all, connection).await,
+            GiGachaType::Chronicled => {
+                database::gi::wishes::chronicled::set_all(set_all, connection).await
+            }
+        }
+    }
+
+    async fn calculate_stats(
+        gacha_type: GiGachaType,
+        uid: i32,
+        pool: &PgPool,
+    ) -> anyhow::Result<()> {
+        match gacha_type {
+            GiGachaType::Beginner => {}
+            GiGachaType::Standard => {
+                let wishes: Vec<_> = database::gi::wishes::standard::get_infos_by_uid(uid, pool)
+                    .await?
+                    .into_iter()
+                    .map(|w| (w.rarity.unwrap(), w.character.or(w.weapon), w.timestamp))
+                    .collect();
+
+                let stats = stats(&wishes, (4, 5), None, false);
+
+                let stat = database::gi::wishes_stats::standard::DbWishesStatStandard {
+                    uid,
+                    luck_4: stats.luck_4,
+                    luck_5: stats.luck_5,
+                };
+                database::gi::wishes_stats::standard::set(&stat, pool).await?;
+            }
+            GiGachaType::Character => {
+                let wishes: Vec<_> = database::gi::wishes::character::get_infos_by_uid(uid, pool)
+                    .await?
+                    .into_iter()
+                    .map(|w| (w.rarity.unwrap(), w.character, w.timestamp))
+                    .collect();
+
+                let rate_up = RateUp::new(
+                    database::gi::banners::get_by_gacha_type(&gacha_type.to_string(), pool).await?,
+                    STANDARD_CHARACTERS,
+                );
+
+                let stats = stats(&wishes, (4, 5), Some(&rate_up), false);
+
+                let stat = database::gi::wishes_stats::character::DbWishesStatCharacter {
+                    uid,
+                    luck_4: stats.luck_4,
+                    luck_5: stats.luck_5,
+                    win_rate: stats.win_rate,
+                    win_streak: stats.win_streak,
+                    loss_streak: stats.loss_streak,
+                };
+                database::gi::wishes_stats::character::set(&stat, pool).await?;
+            }
+            GiGachaType::Weapon => {
+                let wishes: Vec<_> = database::gi::wishes::weapon::get_infos_by_uid(uid, pool)
+                    .await?
+                    .into_iter()
+                    .map(|w| (w.rarity.unwrap(), w.weapon, w.timestamp))
+                    .collect();
+
+                let rate_up = RateUp::new(
+                    database::gi::banners::get_by_gacha_type(&gacha_type.to_string(), pool).await?,
+                    STANDARD_WEAPONS,
+                );
+
+                let stats = stats(&wishes, (4, 5), Some(&rate_up), false);
+
+                let stat = database::gi::wishes_stats::weapon::DbWishesStatWeapon {
+                    uid,
+                    luck_4: stats.luck_4,
+                    luck_5: stats.luck_5,
+                    win_rate: stats.win_rate,
+                    win_streak: stats.win_streak,
+                    loss_streak: stats.loss_streak,
+                };
+                database::gi::wishes_stats::weapon::set(&stat, pool).await?;
+            }
+            GiGachaType::Chronicled => {
+                let wishes: Vec<_> = database::gi::wishes::chronicled::get_infos_by_uid(uid, pool)
+                    .await?
+                    .into_iter()
+                    .map(|w| (w.rarity.unwrap(), w.character.or(w.weapon), w.timestamp))
+                    .collect();
+
+                let stats = stats(&wishes, (4, 5), None, false);
+
+                let stat = database::gi::wishes_stats::chronicled::DbWishesStatChronicled {
+                    uid,
+                    luck_4: stats.luck_4,
+                    luck_5: stats.luck_5,
+                };
+                database::gi::wishes_stats::chronicled::set(&stat, pool).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn get_uids(pool: &PgPool) -> anyhow::Result<Vec<i32>> {
+        database::gi::wishes::get_all_uids(pool).await
+    }
+}
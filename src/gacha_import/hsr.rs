@@ -0,0 +1,255 @@
+use std::collections::HashSet;
+
+use sqlx::{PgConnection, PgPool};
+
+use crate::{database, mihomo, GachaType, Game, Language};
+
+use super::{stats, Gacha, Pull, RateUp};
+
+const STANDARD_CHARACTERS: &[i32] = &[1209, 1004, 1101, 1211, 1104, 1107, 1003];
+const STANDARD_LIGHT_CONES: &[i32] = &[23000, 23002, 23003, 23004, 23005, 23012, 23013];
+
+pub struct Hsr;
+
+pub struct Items {
+    characters: HashSet<i32>,
+    light_cones: HashSet<i32>,
+}
+
+impl Gacha for Hsr {
+    type GachaType = GachaType;
+    type SetAll = database::warps::SetAll;
+    type Items = Items;
+
+    const GAME: Game = Game::Hsr;
+    const URL: &'static str =
+        "https://public-operation-hkrpg-sg.hoyoverse.com/common/gacha_record/api/getGachaLog";
+    const QUERY: &'static [(&'static str, &'static str)] =
+        &[("lang", "en"), ("game_biz", "hkrpg_global"), ("size", "20")];
+
+    fn gacha_type_id(gacha_type: GachaType) -> &'static str {
+        match gacha_type {
+            GachaType::Standard => "1",
+            GachaType::Departure => "2",
+            GachaType::Special => "11",
+            GachaType::Lc => "12",
+        }
+    }
+
+    async fn set_uid(uid: i32, username: Option<String>, pool: &PgPool) -> anyhow::Result<()> {
+        // Wacky way to update the database in case the uid isn't in there
+        if !database::mihomo::exists(uid, pool).await?
+            && mihomo::get(uid, Language::En, pool).await.is_err()
+        {
+            let region = match uid.to_string().chars().next() {
+                Some('6') => "na",
+                Some('7') => "eu",
+                Some('8') | Some('9') => "asia",
+                _ => "cn",
+            }
+            .to_string();
+
+            let db_mihomo = database::mihomo::DbMihomo {
+                uid,
+                region,
+                ..Default::default()
+            };
+
+            database::mihomo::set(&db_mihomo, pool).await?;
+        }
+
+        if let Some(username) = username {
+            let connection = database::connections::DbConnection {
+                uid,
+                username,
+                verified: true,
+                private: false,
+            };
+
+            database::connections::set(&connection, pool).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn get_existing_ids(
+        gacha_type: GachaType,
+        uid: i32,
+        ids: &[i64],
+        pool: &PgPool,
+    ) -> anyhow::Result<Vec<i64>> {
+        match gacha_type {
+            GachaType::Departure => {
+                database::warps::departure::get_existing_ids(uid, ids, pool).await
+            }
+            GachaType::Standard => {
+                database::warps::standard::get_existing_ids(uid, ids, pool).await
+            }
+            GachaType::Special => database::warps::special::get_existing_ids(uid, ids, pool).await,
+            GachaType::Lc => database::warps::lc::get_existing_ids(uid, ids, pool).await,
+        }
+    }
+
+    async fn get_official_ids(
+        gacha_type: GachaType,
+        uid: i32,
+        ids: &[i64],
+        pool: &PgPool,
+    ) -> anyhow::Result<Vec<i64>> {
+        match gacha_type {
+            GachaType::Departure => {
+                database::warps::departure::get_official_ids(uid, ids, pool).await
+            }
+            GachaType::Standard => {
+                database::warps::standard::get_official_ids(uid, ids, pool).await
+            }
+            GachaType::Special => database::warps::special::get_official_ids(uid, ids, pool).await,
+            GachaType::Lc => database::warps::lc::get_official_ids(uid, ids, pool).await,
+        }
+    }
+
+    async fn get_items(pool: &PgPool) -> anyhow::Result<Items> {
+        Ok(Items {
+            characters: database::characters::get_ids(pool)
+                .await?
+                .into_iter()
+                .collect(),
+            light_cones: database::light_cones::get_ids(pool)
+                .await?
+                .into_iter()
+                .collect(),
+        })
+    }
+
+    async fn push(
+        set_all: &mut Self::SetAll,
+        pull: Pull,
+        items: &Items,
+        _: &PgPool,
+    ) -> anyhow::Result<()> {
+        let item: i32 = pull.item_id.parse()?;
+
+        let mut character =
+            (pull.item_type == "Character" || pull.item_type == "角色").then_some(item);
+        let mut light_cone =
+            (pull.item_type == "Light Cone" || pull.item_type == "光錐").then_some(item);
+
+        if character.is_none() && light_cone.is_none() {
+            if item >= 20000 {
+                light_cone = Some(item);
+            } else if item <= 10000 {
+                character = Some(item);
+            } else {
+                return Err(anyhow::anyhow!("{} is weird...", pull.item_type));
+            }
+        }
+
+        let known = match (character, light_cone) {
+            (Some(character), _) => items.characters.contains(&character),
+            (_, Some(light_cone)) => items.light_cones.contains(&light_cone),
+            _ => false,
+        };
+
+        if !known {
+            return Err(anyhow::anyhow!("Unknown item {item}"));
+        }
+
+        set_all.id.push(pull.id);
+        set_all.uid.push(pull.uid);
+        set_all.character.push(character);
+        set_all.light_cone.push(light_cone);
+        set_all.timestamp.push(pull.timestamp);
+        set_all.official.push(pull.official);
+
+        Ok(())
+    }
+
+    async fn set_all(
+        gacha_type: GachaType,
+        set_all: &Self::SetAll,
+        connection: &mut PgConnection,
+    ) -> anyhow::Result<()> {
+        match gacha_type {
+            GachaType::Departure => database::warps::departure::set_all(set_all, connection).await,
+            GachaType::Standard => database::warps::standard::set_all(set_all, connection).await,
+            GachaType::Special => database::warps::special::set_all(set_all, connection).await,
+            GachaType::Lc => database::warps::lc::set_all(set_all, connection).await,
+        }
+    }
+
+    async fn calculate_stats(gacha_type: GachaType, uid: i32, pool: &PgPool) -> anyhow::Result<()> {
+        match gacha_type {
+            GachaType::Departure => {}
+            GachaType::Standard => {
+                let warps: Vec<_> = database::warps::standard::get_infos_by_uid(uid, pool)
+                    .await?
+                    .into_iter()
+                    .map(|w| (w.rarity.unwrap(), w.character.or(w.light_cone), w.timestamp))
+                    .collect();
+
+                let stats = stats(&warps, (4, 5), None, false);
+
+                let stat = database::warps_stats::standard::DbWarpsStatStandard {
+                    uid,
+                    luck_4: stats.luck_4,
+                    luck_5: stats.luck_5,
+                };
+                database::warps_stats::standard::set(&stat, pool).await?;
+            }
+            GachaType::Special => {
+                let warps: Vec<_> = database::warps::special::get_infos_by_uid(uid, pool)
+                    .await?
+                    .into_iter()
+                    .map(|w| (w.rarity.unwrap(), w.character, w.timestamp))
+                    .collect();
+
+                let rate_up = RateUp::new(
+                    database::banners::get_by_gacha_type(&gacha_type.to_string(), pool).await?,
+                    STANDARD_CHARACTERS,
+                );
+
+                let stats = stats(&warps, (4, 5), Some(&rate_up), false);
+
+                let stat = database::warps_stats::special::DbWarpsStatSpecial {
+                    uid,
+                    luck_4: stats.luck_4,
+                    luck_5: stats.luck_5,
+                    win_rate: stats.win_rate,
+                    win_streak: stats.win_streak,
+                    loss_streak: stats.loss_streak,
+                };
+                database::warps_stats::special::set(&stat, pool).await?;
+            }
+            GachaType::Lc => {
+                let warps: Vec<_> = database::warps::lc::get_infos_by_uid(uid, pool)
+                    .await?
+                    .into_iter()
+                    .map(|w| (w.rarity.unwrap(), w.light_cone, w.timestamp))
+                    .collect();
+
+                let rate_up = RateUp::new(
+                    database::banners::get_by_gacha_type(&gacha_type.to_string(), pool).await?,
+                    STANDARD_LIGHT_CONES,
+                );
+
+                let stats = stats(&warps, (4, 5), Some(&rate_up), false);
+
+                let stat = database::warps_stats::lc::DbWarpsStatLc {
+                    uid,
+                    luck_4: stats.luck_4,
+                    luck_5: stats.luck_5,
+                    win_rate: stats.win_rate,
+                    win_streak: stats.win_streak,
+                    loss_streak: stats.loss_streak,
+                };
+                database::warps_stats::lc::set(&stat, pool).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn get_uids(pool: &PgPool) -> anyhow::Result<Vec<i32>> {
+        database::warps::get_all_uids(pool).await
+    }
+}
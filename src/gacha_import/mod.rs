@@ -0,0 +1,620 @@
+//! The official gacha log import shared by warps, signals and wishes.
+//!
+//! Imports are queued in `import_jobs` and run by a pool of workers, so any instance can pick them up.
+
+mod gi;
+mod hsr;
+mod zzz;
+
+use std::{collections::HashSet, env, fmt::Display, time::Duration};
+
+use actix_web::rt;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{PgConnection, PgPool};
+use strum::IntoEnumIterator;
+use url::Url;
+use utoipa::ToSchema;
+
+use crate::{database, Game};
+
+pub use gi::Gi;
+pub use hsr::Hsr;
+pub use zzz::Zzz;
+
+/// How many imports run at the same time per instance, can be set with `IMPORT_WORKERS`.
+const WORKERS: usize = 4;
+/// A job that takes longer than this is stuck, even if its heartbeat is still going.
+const TIMEOUT: Duration = Duration::from_secs(30 * 60);
+/// Well below the 5 minutes after which another worker takes over a job.
+const HEARTBEAT: Duration = Duration::from_secs(30);
+
+lazy_static::lazy_static! {
+    /// A request that hangs would keep its job running forever.
+    static ref CLIENT: reqwest::Client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(30))
+        .build()
+        .unwrap();
+}
+
+#[derive(strum::Display, strum::EnumString, Serialize, ToSchema, Clone, Copy)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Pending,
+    Running,
+    Calculating,
+    Finished,
+    Error,
+}
+
+#[derive(Deserialize)]
+struct GachaLog {
+    data: Data,
+}
+
+#[derive(Deserialize)]
+struct Data {
+    list: Vec<Entry>,
+    region_time_zone: Option<i64>,
+    #[serde(default)]
+    region: String,
+}
+
+impl Data {
+    /// Genshin only sends the region.
+    fn time_zone(&self) -> i64 {
+        self.region_time_zone.unwrap_or(match self.region.as_str() {
+            "os_usa" => -5,
+            "os_eu" => 1,
+            _ => 8,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct Entry {
+    id: String,
+    uid: String,
+    item_type: String,
+    #[serde(default)]
+    item_id: String,
+    #[serde(default)]
+    name: String,
+    time: String,
+}
+
+/// A pull that isn't in the database yet.
+pub struct Pull {
+    pub id: i64,
+    pub uid: i32,
+    pub item_type: String,
+    pub item_id: String,
+    pub name: String,
+    pub timestamp: DateTime<Utc>,
+    /// False for pulls from files
+    pub official: bool,
+}
+
+/// Luck and 50/50 stats of one banner.
+pub struct Stats {
+    pub luck_4: f64,
+    pub luck_5: f64,
+    pub win_rate: f64,
+    pub win_streak: i32,
+    pub loss_streak: i32,
+}
+
+/// Everything that differs between the games.
+pub(crate) trait Gacha {
+    type GachaType: Copy + Display + IntoEnumIterator;
+    type SetAll: Default;
+    /// The items that exist, a pull of anything else would fail the foreign keys.
+    type Items;
+
+    const GAME: Game;
+    const URL: &'static str;
+    const QUERY: &'static [(&'static str, &'static str)];
+
+    fn gacha_type_id(gacha_type: Self::GachaType) -> &'static str;
+
+    /// Makes sure the uid is known and connects it to the user.
+    async fn set_uid(uid: i32, username: Option<String>, pool: &PgPool) -> anyhow::Result<()>;
+
+    /// The ids that are already imported, checked once per page.
+    async fn get_existing_ids(
+        gacha_type: Self::GachaType,
+        uid: i32,
+        ids: &[i64],
+        pool: &PgPool,
+    ) -> anyhow::Result<Vec<i64>>;
+
+    /// The ids that came from the official log rather than a file.
+    async fn get_official_ids(
+        gacha_type: Self::GachaType,
+        uid: i32,
+        ids: &[i64],
+        pool: &PgPool,
+    ) -> anyhow::Result<Vec<i64>>;
+
+    /// Pulls older than this are already imported.
+    async fn latest_timestamp(
+        _gacha_type: Self::GachaType,
+        _uid: i32,
+        _pool: &PgPool,
+    ) -> anyhow::Result<Option<DateTime<Utc>>> {
+        Ok(None)
+    }
+
+    async fn get_items(pool: &PgPool) -> anyhow::Result<Self::Items>;
+
+    /// Classifies the item and adds the pull to the batch, unknown items are an error.
+    async fn push(
+        set_all: &mut Self::SetAll,
+        pull: Pull,
+        items: &Self::Items,
+        pool: &PgPool,
+    ) -> anyhow::Result<()>;
+
+    async fn set_all(
+        gacha_type: Self::GachaType,
+        set_all: &Self::SetAll,
+        connection: &mut PgConnection,
+    ) -> anyhow::Result<()>;
+
+    async fn calculate_stats(
+        gacha_type: Self::GachaType,
+        uid: i32,
+        pool: &PgPool,
+    ) -> anyhow::Result<()>;
+
+    /// Every uid with pulls, private ones included.
+    async fn get_uids(pool: &PgPool) -> anyhow::Result<Vec<i32>>;
+}
+
+/// Keeps the authkey of a gacha log url and points it to the game's endpoint.
+pub fn url<G: Gacha>(url: &str) -> anyhow::Result<Url> {
+    let url = Url::parse(url)?;
+
+    let query = url.query_pairs().filter(|(name, _)| {
+        matches!(
+            name.to_string().as_str(),
+            "authkey" | "authkey_ver" | "sign_type"
+        )
+    });
+
+    let mut gacha_url = Url::parse(G::URL)?;
+
+    gacha_url
+        .query_pairs_mut()
+        .extend_pairs(query)
+        .extend_pairs(G::QUERY)
+        .finish();
+
+    Ok(gacha_url)
+}
+
+/// The uid of the first pull of any banner, 0 if there are none.
+pub async fn uid<G: Gacha>(url: &Url) -> anyhow::Result<i32> {
+    for gacha_type in G::GachaType::iter() {
+        let gacha_log: GachaLog = CLIENT
+            .get(format!(
+                "{url}&gacha_type={}&end_id=0",
+                G::gacha_type_id(gacha_type)
+            ))
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        if let Some(entry) = gacha_log.data.list.first() {
+            return Ok(entry.uid.parse()?);
+        }
+    }
+
+    Ok(0)
+}
+
+/// Queues an import of all banners and returns the job id.
+///
+/// A job that is still running for the uid is reused.
+pub async fn import<G: Gacha>(
+    uid: i32,
+    url: Url,
+    username: Option<String>,
+    pool: &PgPool,
+) -> anyhow::Result<i32> {
+    if uid != 0 {
+        G::set_uid(uid, username, pool).await?;
+    }
+
+    let url = (uid != 0).then_some(url.as_str());
+
+    let id = loop {
+        if let Some(id) = database::import_jobs::add(G::GAME, uid, url, pool).await? {
+            break id;
+        }
+
+        // The active job could have finished in the meantime
+        if let Some(job) =
+            database::import_jobs::get_one_active_by_game_and_uid(G::GAME, uid, pool).await?
+        {
+            return Ok(job.id);
+        }
+    };
+
+    if uid == 0 {
+        database::import_jobs::update_finished_by_id(
+            id,
+            &Status::Error.to_string(),
+            Some("No data"),
+            pool,
+        )
+        .await?;
+
+        return Ok(id);
+    }
+
+    for gacha_type in G::GachaType::iter() {
+        database::import_jobs_counts::add(id, &gacha_type.to_string(), 0, pool).await?;
+    }
+
+    Ok(id)
+}
+
+/// Starts the workers that run the queued import jobs.
+pub async fn spawn(pool: PgPool) {
+    let workers = env::var("IMPORT_WORKERS")
+        .ok()
+        .and_then(|workers| workers.parse().ok())
+        .unwrap_or(WORKERS);
+
+    for _ in 0..workers {
+        let pool = pool.clone();
+
+        actix::Arbiter::new().spawn(async move {
+            loop {
+                match database::import_jobs::claim(&pool).await {
+                    Ok(Some(job)) => {
+                        if let Err(e) = run(&job, &pool).await {
+                            error!("Import job {} failed with {e}", job.id);
+                        }
+                    }
+                    Ok(None) => rt::time::sleep(Duration::from_secs(1)).await,
+                    Err(e) => {
+                        error!("Claiming an import job failed with {e}");
+
+                        rt::time::sleep(Duration::from_secs(10)).await;
+                    }
+                }
+            }
+        });
+    }
+}
+
+async fn run(job: &database::import_jobs::DbImportJob, pool: &PgPool) -> anyhow::Result<()> {
+    let heartbeat = rt::spawn({
+        let id = job.id;
+        let pool = pool.clone();
+
+        async move {
+            loop {
+                rt::time::sleep(HEARTBEAT).await;
+
+                if let Err(e) = database::import_jobs::update_updated_at_by_id(id, &pool).await {
+                    error!("Heartbeat of import job {id} failed with {e}");
+                }
+            }
+        }
+    });
+
+    let result = rt::time::timeout(TIMEOUT, async {
+        match job.game.parse()? {
+            Game::Hsr => run_gacha::<Hsr>(job, pool).await,
+            Game::Zzz => run_gacha::<Zzz>(job, pool).await,
+            Game::Gi => run_gacha::<Gi>(job, pool).await,
+        }
+    })
+    .await
+    .unwrap_or_else(|_| Err(anyhow::anyhow!("Timed out")));
+
+    heartbeat.abort();
+
+    match result {
+        Ok(()) => {
+            database::import_jobs::update_finished_by_id(
+                job.id,
+                &Status::Finished.to_string(),
+                None,
+                pool,
+            )
+            .await
+        }
+        Err(e) => {
+            database::import_jobs::update_finished_by_id(
+                job.id,
+                &Status::Error.to_string(),
+                Some(&e.to_string()),
+                pool,
+            )
+            .await
+        }
+    }
+}
+
+async fn run_gacha<G: Gacha>(
+    job: &database::import_jobs::DbImportJob,
+    pool: &PgPool,
+) -> anyhow::Result<()> {
+    let url = Url::parse(
+        job.url
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("No url"))?,
+    )?;
+
+    // A job taken over from a stopped worker starts over
+    database::import_jobs_counts::reset_by_id(job.id, pool).await?;
+
+    let items = G::get_items(pool).await?;
+
+    for gacha_type in G::GachaType::iter() {
+        database::import_jobs::update_status_by_id(
+            job.id,
+            &Status::Running.to_string(),
+            Some(&gacha_type.to_string()),
+            pool,
+        )
+        .await?;
+
+        import_gacha_type::<G>(job.id, job.uid, &url, gacha_type, &items, pool).await?;
+    }
+
+    for gacha_type in G::GachaType::iter() {
+        database::import_jobs::update_status_by_id(
+            job.id,
+            &Status::Calculating.to_string(),
+            Some(&gacha_type.to_string()),
+            pool,
+        )
+        .await?;
+
+        G::calculate_stats(gacha_type, job.uid, pool).await?;
+    }
+
+    Ok(())
+}
+
+async fn import_gacha_type<G: Gacha>(
+    job: i32,
+    uid: i32,
+    url: &Url,
+    gacha_type: G::GachaType,
+    items: &G::Items,
+    pool: &PgPool,
+) -> anyhow::Result<()> {
+    let mut url = url.clone();
+    let mut end_id = "0".to_string();
+
+    url.query_pairs_mut()
+        .extend_pairs(&[("gacha_type", G::gacha_type_id(gacha_type))])
+        .finish();
+
+    let mut set_all = G::SetAll::default();
+
+    let latest_timestamp = G::latest_timestamp(gacha_type, uid, pool).await?;
+
+    let mut done = false;
+
+    while !done {
+        let mut i = 0;
+        let gacha_log = loop {
+            let response = CLIENT.get(format!("{url}&end_id={end_id}")).send().await?;
+            if let Ok(gacha_log) = response.json::<GachaLog>().await {
+                break gacha_log;
+            }
+
+            if i > 2 {
+                return Err(anyhow::anyhow!("Unsure"));
+            }
+
+            rt::time::sleep(Duration::from_secs(1)).await;
+
+            i += 1;
+        };
+
+        if gacha_log.data.list.is_empty() {
+            break;
+        }
+
+        let timestamp_offset = chrono::Duration::hours(gacha_log.data.time_zone());
+
+        let ids = gacha_log
+            .data
+            .list
+            .iter()
+            .map(|entry| entry.id.parse())
+            .collect::<Result<Vec<i64>, _>>()?;
+
+        let existing: HashSet<_> = G::get_existing_ids(gacha_type, uid, &ids, pool)
+            .await?
+            .into_iter()
+            .collect();
+
+        // Everything older than a fully known page was imported before
+        if existing.len() == ids.len() {
+            break;
+        }
+
+        let mut count = 0;
+
+        for (entry, id) in gacha_log.data.list.into_iter().zip(ids) {
+            let timestamp = NaiveDateTime::parse_from_str(&entry.time, "%Y-%m-%d %H:%M:%S")?
+                .and_utc()
+                - timestamp_offset;
+
+            if latest_timestamp.is_some_and(|latest| timestamp <= latest) {
+                done = true;
+                break;
+            }
+
+            end_id.clone_from(&entry.id);
+
+            if existing.contains(&id) {
+                continue;
+            }
+
+            let pull = Pull {
+                id,
+                uid: entry.uid.parse()?,
+                item_type: entry.item_type,
+                item_id: entry.item_id,
+                name: entry.name,
+                timestamp,
+                official: true,
+            };
+
+            G::push(&mut set_all, pull, items, pool).await?;
+
+            count += 1;
+        }
+
+        database::import_jobs_counts::add(job, &gacha_type.to_string(), count, pool).await?;
+    }
+
+    G::set_all(gacha_type, &set_all, &mut *pool.acquire().await?).await
+}
+
+/// Recalculates the stats of all banners, used by the file imports.
+pub async fn calculate_stats<G: Gacha>(uid: i32, pool: &PgPool) -> anyhow::Result<()> {
+    for gacha_type in G::GachaType::iter() {
+        G::calculate_stats(gacha_type, uid, pool).await?;
+    }
+
+    Ok(())
+}
+
+/// Recalculates the stats of one gacha type for every uid, used after its banners changed.
+pub async fn recalculate_stats<G: Gacha>(gacha_type: &str, pool: &PgPool) -> anyhow::Result<()> {
+    let Some(gacha_type) = G::GachaType::iter().find(|g| g.to_string() == gacha_type) else {
+        return Ok(());
+    };
+
+    for uid in G::get_uids(pool).await? {
+        G::calculate_stats(gacha_type, uid, pool).await?;
+    }
+
+    Ok(())
+}
+
+/// Decides the 50/50 of a pull by the banners that were running when it was made.
+pub struct RateUp<'a> {
+    banners: Vec<database::banners::DbBanner>,
+    /// Only used for pulls that aren't covered by a known banner
+    standard: &'a [i32],
+}
+
+impl<'a> RateUp<'a> {
+    pub fn new(banners: Vec<database::banners::DbBanner>, standard: &'a [i32]) -> Self {
+        Self { banners, standard }
+    }
+
+    fn won(&self, item: i32, timestamp: DateTime<Utc>) -> bool {
+        let mut banners = self
+            .banners
+            .iter()
+            .filter(|b| b.start_time <= timestamp && timestamp < b.end_time)
+            .peekable();
+
+        if banners.peek().is_none() {
+            return !self.standard.contains(&item);
+        }
+
+        banners.any(|b| b.rate_up_5.contains(&item))
+    }
+}
+
+/// `rarities` are the two highest rarities, the 50/50 of the highest is decided by `rate_up`.
+///
+/// With `skip_first` the first highest rarity pull doesn't count, like the zzz standard selection.
+pub fn stats(
+    pulls: &[(i32, Option<i32>, DateTime<Utc>)],
+    rarities: (i32, i32),
+    rate_up: Option<&RateUp>,
+    skip_first: bool,
+) -> Stats {
+    let mut pull_4 = 0;
+    let mut sum_4 = 0;
+    let mut count_4 = 0;
+
+    let mut pull_5 = 0;
+    let mut sum_5 = 0;
+    let mut count_5 = 0;
+
+    let mut first = skip_first;
+
+    let mut guarantee = false;
+
+    let mut sum_win = 0;
+    let mut count_win = 0;
+
+    let mut win_streak = 0;
+    let mut max_win_streak = 0;
+
+    let mut loss_streak = 0;
+    let mut max_loss_streak = 0;
+
+    for &(rarity, item, timestamp) in pulls {
+        pull_4 += 1;
+        pull_5 += 1;
+
+        if rarity == rarities.0 {
+            count_4 += 1;
+            sum_4 += pull_4;
+            pull_4 = 0;
+        } else if rarity == rarities.1 {
+            if first {
+                first = false;
+                pull_5 = 0;
+                continue;
+            }
+
+            count_5 += 1;
+            sum_5 += pull_5;
+            pull_5 = 0;
+
+            let Some(rate_up) = rate_up else {
+                continue;
+            };
+
+            if guarantee {
+                guarantee = false;
+            } else {
+                count_win += 1;
+
+                if rate_up.won(item.unwrap(), timestamp) {
+                    sum_win += 1;
+
+                    loss_streak = 0;
+
+                    win_streak += 1;
+                    max_win_streak = max_win_streak.max(win_streak);
+                } else {
+                    win_streak = 0;
+
+                    loss_streak += 1;
+                    max_loss_streak = max_loss_streak.max(loss_streak);
+
+                    guarantee = true;
+                }
+            }
+        }
+    }
+
+    Stats {
+        luck_4: sum_4 as f64 / count_4 as f64,
+        luck_5: sum_5 as f64 / count_5 as f64,
+        win_rate: sum_win as f64 / count_win as f64,
+        win_streak: max_win_streak,
+        loss_streak: max_loss_streak,
+    }
+}
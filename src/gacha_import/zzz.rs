@@ -0,0 +1,291 @@
+use std::collections::HashSet;
+
+use sqlx::{PgConnection, PgPool};
+
+use crate::{database, Game, ZzzGachaType};
+
+use super::{stats, Gacha, Pull, RateUp};
+
+const STANDARD_CHARACTERS: &[i32] = &[1021, 1041, 1101, 1141, 1181, 1211];
+const STANDARD_W_ENGINES: &[i32] = &[14103, 14104, 14110, 14114, 14118, 14121];
+
+/// Banners without an S rank yet have a luck of 0.
+fn or_zero(luck: f64) -> f64 {
+    if luck.is_nan() {
+        0.0
+    } else {
+        luck
+    }
+}
+
+pub struct Zzz;
+
+pub struct Items {
+    characters: HashSet<i32>,
+    w_engines: HashSet<i32>,
+    bangboos: HashSet<i32>,
+}
+
+impl Gacha for Zzz {
+    type GachaType = ZzzGachaType;
+    type SetAll = database::zzz::signals::SetAll;
+    type Items = Items;
+
+    const GAME: Game = Game::Zzz;
+    const URL: &'static str =
+        "https://public-operation-nap-sg.hoyoverse.com/common/gacha_record/api/getGachaLog";
+    const QUERY: &'static [(&'static str, &'static str)] =
+        &[("lang", "en"), ("game_biz", "nap_global"), ("size", "20")];
+
+    fn gacha_type_id(gacha_type: ZzzGachaType) -> &'static str {
+        match gacha_type {
+            ZzzGachaType::Standard => "1001",
+            ZzzGachaType::Special => "2001",
+            ZzzGachaType::WEngine => "3001",
+            ZzzGachaType::Bangboo => "5001",
+        }
+    }
+
+    async fn set_uid(uid: i32, username: Option<String>, pool: &PgPool) -> anyhow::Result<()> {
+        database::zzz::uids::set(&database::zzz::uids::DbUid { uid }, pool).await?;
+
+        if let Some(username) = username {
+            let connection = database::zzz::connections::DbConnection {
+                uid,
+                username,
+                verified: true,
+                private: false,
+            };
+
+            database::zzz::connections::set(&connection, pool).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn get_existing_ids(
+        gacha_type: ZzzGachaType,
+        uid: i32,
+        ids: &[i64],
+        pool: &PgPool,
+    ) -> anyhow::Result<Vec<i64>> {
+        match gacha_type {
+            ZzzGachaType::Standard => {
+                database::zzz::signals::standard::get_existing_ids(uid, ids, pool).await
+            }
+            ZzzGachaType::Special => {
+                database::zzz::signals::special::get_existing_ids(uid, ids, pool).await
+            }
+            ZzzGachaType::WEngine => {
+                database::zzz::signals::w_engine::get_existing_ids(uid, ids, pool).await
+            }
+            ZzzGachaType::Bangboo => {
+                database::zzz::signals::bangboo::get_existing_ids(uid, ids, pool).await
+            }
+        }
+    }
+
+    async fn get_official_ids(
+        gacha_type: ZzzGachaType,
+        uid: i32,
+        ids: &[i64],
+        pool: &PgPool,
+    ) -> anyhow::Result<Vec<i64>> {
+        match gacha_type {
+            ZzzGachaType::Standard => {
+                database::zzz::signals::standard::get_official_ids(uid, ids, pool).await
+            }
+            ZzzGachaType::Special => {
+                database::zzz::signals::special::get_official_ids(uid, ids, pool).await
+            }
+            ZzzGachaType::WEngine => {
+                database::zzz::signals::w_engine::get_official_ids(uid, ids, pool).await
+            }
+            ZzzGachaType::Bangboo => {
+                database::zzz::signals::bangboo::get_official_ids(uid, ids, pool).await
+            }
+        }
+    }
+
+    async fn get_items(pool: &PgPool) -> anyhow::Result<Items> {
+        Ok(Items {
+            characters: database::zzz::characters::get_ids(pool)
+                .await?
+                .into_iter()
+                .collect(),
+            w_engines: database::zzz::w_engines::get_ids(pool)
+                .await?
+                .into_iter()
+                .collect(),
+            bangboos: database::zzz::bangboos::get_ids(pool)
+                .await?
+                .into_iter()
+                .collect(),
+        })
+    }
+
+    async fn push(
+        set_all: &mut Self::SetAll,
+        pull: Pull,
+        items: &Items,
+        _: &PgPool,
+    ) -> anyhow::Result<()> {
+        let item: i32 = pull.item_id.parse()?;
+
+        let mut character =
+            (pull.item_type == "Agents" || pull.item_type == "代理人").then_some(item);
+        let mut w_engine =
+            (pull.item_type == "W-Engines" || pull.item_type == "音擎").then_some(item);
+        let mut bangboo = (pull.item_type == "Bangboo" || pull.item_type == "邦布").then_some(item);
+
+        if character.is_none() && w_engine.is_none() && bangboo.is_none() {
+            if item >= 50000 {
+                bangboo = Some(item);
+            } else if item >= 12000 {
+                w_engine = Some(item);
+            } else {
+                character = Some(item);
+            }
+        }
+
+        let known = match (character, w_engine, bangboo) {
+            (Some(character), _, _) => items.characters.contains(&character),
+            (_, Some(w_engine), _) => items.w_engines.contains(&w_engine),
+            (_, _, Some(bangboo)) => items.bangboos.contains(&bangboo),
+            _ => false,
+        };
+
+        if !known {
+            return Err(anyhow::anyhow!("Unknown item {item}"));
+        }
+
+        set_all.id.push(pull.id);
+        set_all.uid.push(pull.uid);
+        set_all.character.push(character);
+        set_all.w_engine.push(w_engine);
+        set_all.bangboo.push(bangboo);
+        set_all.timestamp.push(pull.timestamp);
+        set_all.official.push(pull.official);
+
+        Ok(())
+    }
+
+    async fn set_all(
+        gacha_type: ZzzGachaType,
+        set_all: &Self::SetAll,
+        connection: &mut PgConnection,
+    ) -> anyhow::Result<()> {
+        match gacha_type {
+            ZzzGachaType::Standard => {
+                database::zzz::signals::standard::set_all(set_all, connection).await
+            }
+            ZzzGachaType::Special => {
+                database::zzz::signals::special::set_all(set_all, connection).await
+            }
+            ZzzGachaType::WEngine => {
+                database::zzz::signals::w_engine::set_all(set_all, connection).await
+            }
+            ZzzGachaType::Bangboo => {
+                database::zzz::signals::bangboo::set_all(set_all, connection).await
+            }
+        }
+    }
+
+    async fn calculate_stats(
+        gacha_type: ZzzGachaType,
+        uid: i32,
+        pool: &PgPool,
+    ) -> anyhow::Result<()> {
+        match gacha_type {
+            ZzzGachaType::Standard => {
+                let signals: Vec<_> = database::zzz::signals::standard::get_infos_by_uid(uid, pool)
+                    .await?
+                    .into_iter()
+                    .map(|s| (s.rarity.unwrap(), s.character.or(s.w_engine), s.timestamp))
+                    .collect();
+
+                // The first S rank is the selected agent
+                let stats = stats(&signals, (3, 4), None, true);
+
+                let stat = database::zzz::signals_stats::standard::DbSignalsStatStandard {
+                    uid,
+                    luck_a: or_zero(stats.luck_4),
+                    luck_s: or_zero(stats.luck_5),
+                };
+                database::zzz::signals_stats::standard::set(&stat, pool).await?;
+            }
+            ZzzGachaType::Special => {
+                let signals: Vec<_> = database::zzz::signals::special::get_infos_by_uid(uid, pool)
+                    .await?
+                    .into_iter()
+                    .map(|s| (s.rarity.unwrap(), s.character, s.timestamp))
+                    .collect();
+
+                let rate_up = RateUp::new(
+                    database::zzz::banners::get_by_gacha_type(&gacha_type.to_string(), pool)
+                        .await?,
+                    STANDARD_CHARACTERS,
+                );
+
+                let stats = stats(&signals, (3, 4), Some(&rate_up), false);
+
+                let stat = database::zzz::signals_stats::special::DbSignalsStatSpecial {
+                    uid,
+                    luck_a: or_zero(stats.luck_4),
+                    luck_s: or_zero(stats.luck_5),
+                    win_rate: or_zero(stats.win_rate),
+                    win_streak: stats.win_streak,
+                    loss_streak: stats.loss_streak,
+                };
+                database::zzz::signals_stats::special::set(&stat, pool).await?;
+            }
+            ZzzGachaType::WEngine => {
+                let signals: Vec<_> = database::zzz::signals::w_engine::get_infos_by_uid(uid, pool)
+                    .await?
+                    .into_iter()
+                    .map(|s| (s.rarity.unwrap(), s.w_engine, s.timestamp))
+                    .collect();
+
+                let rate_up = RateUp::new(
+                    database::zzz::banners::get_by_gacha_type(&gacha_type.to_string(), pool)
+                        .await?,
+                    STANDARD_W_ENGINES,
+                );
+
+                let stats = stats(&signals, (3, 4), Some(&rate_up), false);
+
+                let stat = database::zzz::signals_stats::w_engine::DbSignalsStatWEngine {
+                    uid,
+                    luck_a: or_zero(stats.luck_4),
+                    luck_s: or_zero(stats.luck_5),
+                    win_rate: or_zero(stats.win_rate),
+                    win_streak: stats.win_streak,
+                    loss_streak: stats.loss_streak,
+                };
+                database::zzz::signals_stats::w_engine::set(&stat, pool).await?;
+            }
+            ZzzGachaType::Bangboo => {
+                let signals: Vec<_> = database::zzz::signals::bangboo::get_infos_by_uid(uid, pool)
+                    .await?
+                    .into_iter()
+                    .map(|s| (s.rarity.unwrap(), None, s.timestamp))
+                    .collect();
+
+                let stats = stats(&signals, (3, 4), None, false);
+
+                let stat = database::zzz::signals_stats::bangboo::DbSignalsStatBangboo {
+                    uid,
+                    luck_a: or_zero(stats.luck_4),
+                    luck_s: or_zero(stats.luck_5),
+                };
+                database::zzz::signals_stats::bangboo::set(&stat, pool).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn get_uids(pool: &PgPool) -> anyhow::Result<Vec<i32>> {
+        database::zzz::signals::get_all_uids(pool).await
+    }
+}
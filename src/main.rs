@@ -3,6 +3,7 @@ extern crate tracing;
 
 mod api;
 mod database;
+mod gacha_import;
 mod mihomo;
 mod pg_session_store;
 mod update;
@@ -93,6 +94,25 @@ impl Language {
         }
         .to_string()
     }
+
+    pub fn uigf(&self) -> String {
+        match self {
+            Language::ZhCn => "zh-cn",
+            Language::ZhTw => "zh-tw",
+            Language::De => "de-de",
+            Language::En => "en-us",
+            Language::EsEs => "es-es",
+            Language::Fr => "fr-fr",
+            Language::Id => "id-id",
+            Language::Ja => "ja-jp",
+            Language::Ko => "ko-kr",
+            Language::PtPt => "pt-pt",
+            Language::Ru => "ru-ru",
+            Language::Th => "th-th",
+            Language::Vi => "vi-vn",
+        }
+        .to_string()
+    }
 }
 
 #[derive(
@@ -170,6 +190,78 @@ enum Difficulty {
     Hard,
 }
 
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+enum Gender {
+    Male,
+    Female,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+enum Game {
+    Hsr,
+    Zzz,
+    Gi,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+enum ChangelogKind {
+    Achievement,
+    Series,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+enum ChangelogChange {
+    Added,
+    Removed,
+    Name,
+    Description,
+    Reward,
+}
+
 #[actix_web::main]
 async fn main() -> anyhow::Result<()> {
     dotenv::dotenv()?;
@@ -203,6 +295,8 @@ async fn main() -> anyhow::Result<()> {
     update::signals_stats::spawn(pool.clone()).await;
     update::wishes_stats::spawn(pool.clone()).await;
 
+    gacha_import::spawn(pool.clone()).await;
+
     let pool_data = Data::new(pool.clone());
 
     let key = Key::from(&std::fs::read("session_key")?);
@@ -38,6 +38,7 @@ pub async fn spawn(pool: PgPool) {
 
 async fn update(pool: PgPool) -> Result<()> {
     database::achievements_percent::update(&pool).await?;
+    database::achievements_percent_history::update(&pool).await?;
 
     Ok(())
 }
@@ -1,10 +1,15 @@
 use sqlx::PgPool;
 
-use crate::database;
+use crate::{database, Game};
 
 use super::Configs;
 
-pub async fn update(configs: &Configs, pool: &PgPool) -> anyhow::Result<()> {
+/// Returns the ids of all achievements in the data.
+pub async fn update(configs: &Configs, pool: &PgPool) -> anyhow::Result<Vec<i32>> {
+    let version = database::game_versions::get_one_by_game(Game::Gi, pool)
+        .await?
+        .map(|v| v.version);
+
     let mut achievements_id = Vec::new();
     let mut achievements_series = Vec::new();
     let mut achievements_primogems = Vec::new();
@@ -49,9 +54,10 @@ pub async fn update(configs: &Configs, pool: &PgPool) -> anyhow::Result<()> {
         &achievements_primogems,
         &achievements_hidden,
         &achievements_priority,
+        version.as_deref(),
         pool,
     )
     .await?;
 
-    Ok(())
+    Ok(achievements_id)
 }
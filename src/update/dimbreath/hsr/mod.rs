@@ -17,6 +17,9 @@ use async_process::Command;
 use serde::Deserialize;
 use sqlx::PgPool;
 
+use super::{changelog, commit, AchievementSnapshot, Snapshot};
+use crate::{database, ChangelogKind};
+
 #[derive(Deserialize)]
 struct AchievementData {
     #[serde(rename = "AchievementID")]
@@ -236,12 +239,14 @@ async fn update(up_to_date: &mut bool, pool: PgPool) -> Result<()> {
 
     info!("Parsed all json");
 
+    let before = snapshot(&pool).await?;
+
     info!("Starting achievement series");
     achievement_series::update(&configs, &pool).await?;
     actix_web::rt::task::yield_now().await;
 
     info!("Starting achievements");
-    achievements::update(&configs, &pool).await?;
+    let ids = achievements::update(&configs, &pool).await?;
     actix_web::rt::task::yield_now().await;
 
     info!("Starting avatars");
@@ -256,7 +261,59 @@ async fn update(up_to_date: &mut bool, pool: PgPool) -> Result<()> {
     texts::update(&configs, &pool).await?;
     actix_web::rt::task::yield_now().await;
 
+    // The first run would list every achievement as added
+    if !before.achievements.is_empty() {
+        info!("Starting changelog");
+        let after = snapshot(&pool).await?;
+
+        let changelog = changelog(&before, &after, &ids);
+
+        if !changelog.is_empty() {
+            let commit_hash = commit(&Path::new("dimbreath").join("StarRailData")).await?;
+
+            database::achievements_changelog::set_all(
+                &commit_hash,
+                &changelog.kind,
+                &changelog.id,
+                &changelog.change,
+                &changelog.old_value,
+                &changelog.new_value,
+                &pool,
+            )
+            .await?;
+        }
+    }
+
     *up_to_date = true;
 
     Ok(())
 }
+
+async fn snapshot(pool: &PgPool) -> anyhow::Result<Snapshot> {
+    let mut snapshot = Snapshot::default();
+
+    for db_achievement in database::achievements::get_snapshot(pool).await? {
+        snapshot
+            .series
+            .insert(db_achievement.series, db_achievement.series_name);
+
+        snapshot.achievements.insert(
+            db_achievement.id,
+            AchievementSnapshot {
+                series: db_achievement.series,
+                name: db_achievement.name,
+                description: db_achievement.description,
+                reward: db_achievement.jades,
+            },
+        );
+    }
+
+    for removed in database::achievements_changelog::get_removed(pool).await? {
+        match removed.kind.parse()? {
+            ChangelogKind::Achievement => snapshot.removed_achievements.insert(removed.id),
+            ChangelogKind::Series => snapshot.removed_series.insert(removed.id),
+        };
+    }
+
+    Ok(snapshot)
+}
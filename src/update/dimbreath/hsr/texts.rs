@@ -28,6 +28,9 @@ pub async fn update(configs: &Configs, pool: &PgPool) -> anyhow::Result<()> {
     let mut light_cones_name = Vec::new();
     let mut light_cones_path = Vec::new();
 
+    let mut trailblazer_language = Vec::new();
+    let mut trailblazer_name = Vec::new();
+
     for (language_str, language) in [
         ("CHS", Language::ZhCn),
         ("CHT", Language::ZhTw),
@@ -56,8 +59,8 @@ pub async fn update(configs: &Configs, pool: &PgPool) -> anyhow::Result<()> {
         for achievement_series in &configs.achievement_series {
             let id = achievement_series.id;
 
+            // Gendered text is rendered at request time
             let name = html(&text_map[&achievement_series.title.hash.to_string()])?;
-            let name = gender(&name)?;
 
             achievement_series_id.push(id);
             achievement_series_language.push(language);
@@ -68,11 +71,10 @@ pub async fn update(configs: &Configs, pool: &PgPool) -> anyhow::Result<()> {
         for achievement_data in &configs.achievement_data {
             let id = achievement_data.id;
 
+            // Gendered text and {NICKNAME} are rendered at request time
             let name = html(&text_map[&achievement_data.title.hash.to_string()])?;
-            let name = gender(&name)?;
 
             let description = html(&text_map[&achievement_data.description.hash.to_string()])?;
-            let description = gender(&description)?;
             let description = layout(&description)?;
 
             // Idk what's happening here. Leave this as is
@@ -96,8 +98,7 @@ pub async fn update(configs: &Configs, pool: &PgPool) -> anyhow::Result<()> {
                         c.get(0).unwrap().as_str().to_string()
                     }
                 })
-                // -2090701432 = Trailblazer
-                .replace("{NICKNAME}", &text_map["-2090701432"]);
+                .to_string();
 
             if language == Language::En {
                 description = description.replace("{TEXTJOIN#54}", "Chris P. Bacon (Trotter)");
@@ -110,6 +111,10 @@ pub async fn update(configs: &Configs, pool: &PgPool) -> anyhow::Result<()> {
             achievements_description.push(description);
         }
 
+        // -2090701432 = Trailblazer
+        trailblazer_language.push(language);
+        trailblazer_name.push(text_map["-2090701432"].clone());
+
         info!("Starting {} avatars", language);
         for avatar_config in &configs.avatar_config {
             let element = text_map[&configs
@@ -195,6 +200,9 @@ pub async fn update(configs: &Configs, pool: &PgPool) -> anyhow::Result<()> {
     )
     .await?;
 
+    info!("Setting all trailblazer texts");
+    database::trailblazer_text::set_all(&trailblazer_language, &trailblazer_name, pool).await?;
+
     info!("Setting all character texts");
     database::characters_text::set_all(
         &characters_id,
@@ -1,3 +1,197 @@
 pub mod gi;
 pub mod hsr;
 pub mod zzz;
+
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+
+use async_process::Command;
+
+use crate::{ChangelogChange, ChangelogKind};
+
+/// English achievement data before and after an update.
+#[derive(Default)]
+struct Snapshot {
+    achievements: HashMap<i32, AchievementSnapshot>,
+    series: HashMap<i32, String>,
+    /// Whatever the changelog last logged as removed, so it's only logged once
+    removed_achievements: HashSet<i32>,
+    removed_series: HashSet<i32>,
+}
+
+struct AchievementSnapshot {
+    series: i32,
+    name: String,
+    description: String,
+    reward: i32,
+}
+
+#[derive(Default)]
+struct Changelog {
+    kind: Vec<String>,
+    id: Vec<i32>,
+    change: Vec<String>,
+    old_value: Vec<Option<String>>,
+    new_value: Vec<Option<String>>,
+}
+
+impl Changelog {
+    fn push(
+        &mut self,
+        kind: ChangelogKind,
+        id: i32,
+        change: ChangelogChange,
+        old_value: Option<String>,
+        new_value: Option<String>,
+    ) {
+        self.kind.push(kind.to_string());
+        self.id.push(id);
+        self.change.push(change.to_string());
+        self.old_value.push(old_value);
+        self.new_value.push(new_value);
+    }
+
+    fn is_empty(&self) -> bool {
+        self.id.is_empty()
+    }
+}
+
+/// `ids` are the achievements in the new data, the database keeps removed ones around.
+fn changelog(before: &Snapshot, after: &Snapshot, ids: &[i32]) -> Changelog {
+    let mut changelog = Changelog::default();
+
+    let ids: HashSet<_> = ids.iter().copied().collect();
+
+    let series: HashSet<_> = after
+        .achievements
+        .iter()
+        .filter(|(id, _)| ids.contains(id))
+        .map(|(_, a)| a.series)
+        .collect();
+
+    for &id in &series {
+        match (before.series.get(&id), after.series.get(&id)) {
+            (None, _) => changelog.push(
+                ChangelogKind::Series,
+                id,
+                ChangelogChange::Added,
+                None,
+                None,
+            ),
+            _ if before.removed_series.contains(&id) => changelog.push(
+                ChangelogKind::Series,
+                id,
+                ChangelogChange::Added,
+                None,
+                None,
+            ),
+            (Some(old), Some(new)) if old != new => changelog.push(
+                ChangelogKind::Series,
+                id,
+                ChangelogChange::Name,
+                Some(old.clone()),
+                Some(new.clone()),
+            ),
+            _ => {}
+        }
+    }
+
+    for &id in before.series.keys() {
+        if !series.contains(&id) && !before.removed_series.contains(&id) {
+            changelog.push(
+                ChangelogKind::Series,
+                id,
+                ChangelogChange::Removed,
+                None,
+                None,
+            );
+        }
+    }
+
+    for &id in &ids {
+        let Some(old) = before.achievements.get(&id) else {
+            changelog.push(
+                ChangelogKind::Achievement,
+                id,
+                ChangelogChange::Added,
+                None,
+                None,
+            );
+            continue;
+        };
+
+        if before.removed_achievements.contains(&id) {
+            changelog.push(
+                ChangelogKind::Achievement,
+                id,
+                ChangelogChange::Added,
+                None,
+                None,
+            );
+            continue;
+        }
+
+        let Some(new) = after.achievements.get(&id) else {
+            continue;
+        };
+
+        if old.name != new.name {
+            changelog.push(
+                ChangelogKind::Achievement,
+                id,
+                ChangelogChange::Name,
+                Some(old.name.clone()),
+                Some(new.name.clone()),
+            );
+        }
+
+        if old.description != new.description {
+            changelog.push(
+                ChangelogKind::Achievement,
+                id,
+                ChangelogChange::Description,
+                Some(old.description.clone()),
+                Some(new.description.clone()),
+            );
+        }
+
+        if old.reward != new.reward {
+            changelog.push(
+                ChangelogKind::Achievement,
+                id,
+                ChangelogChange::Reward,
+                Some(old.reward.to_string()),
+                Some(new.reward.to_string()),
+            );
+        }
+    }
+
+    for &id in before.achievements.keys() {
+        if !ids.contains(&id) && !before.removed_achievements.contains(&id) {
+            changelog.push(
+                ChangelogKind::Achievement,
+                id,
+                ChangelogChange::Removed,
+                None,
+                None,
+            );
+        }
+    }
+
+    changelog
+}
+
+async fn commit(repository: &Path) -> anyhow::Result<String> {
+    Ok(String::from_utf8(
+        Command::new("git")
+            .args(["rev-parse", "HEAD"])
+            .current_dir(repository)
+            .output()
+            .await?
+            .stdout,
+    )?
+    .trim()
+    .to_string())
+}